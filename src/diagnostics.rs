@@ -0,0 +1,49 @@
+use libxml::tree::Node;
+
+use crate::{error::Location, Result, S100Error};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseMode {
+    // Fails on the first invalid item.
+    Strict,
+    // Skips invalid items and unrecognized elements, reporting them as warnings.
+    Lenient,
+}
+
+pub(crate) struct Diagnostics {
+    mode: ParseMode,
+    warnings: Vec<S100Error>,
+}
+
+impl Diagnostics {
+    pub(crate) fn new(mode: ParseMode) -> Diagnostics {
+        Diagnostics {
+            mode,
+            warnings: Vec::new(),
+        }
+    }
+
+    // Returns the error in strict mode so that parsing stops, and records it otherwise.
+    pub(crate) fn report(&mut self, error: S100Error) -> Result<()> {
+        match self.mode {
+            ParseMode::Strict => Err(error),
+            ParseMode::Lenient => {
+                self.warnings.push(error);
+                Ok(())
+            }
+        }
+    }
+
+    // Unrecognized elements have always been skipped silently, so strict mode keeps doing that.
+    pub(crate) fn unexpected(&mut self, node: &Node) {
+        if self.mode == ParseMode::Lenient {
+            self.warnings.push(S100Error::UnexpectedElement {
+                location: Location::of(node),
+            });
+        }
+    }
+
+    pub(crate) fn into_warnings(self) -> Vec<S100Error> {
+        self.warnings
+    }
+}
@@ -1,5 +1,16 @@
-use libxml::{parser::XmlParseError, tree::Node};
-use std::{error::Error, fmt, io::Error as IoError};
+use libxml::{
+    bindings::xmlGetLineNo,
+    parser::XmlParseError,
+    tree::{Node, NodeType},
+};
+use std::{
+    error::Error,
+    fmt,
+    io::Error as IoError,
+    path::{Path, PathBuf},
+};
+
+use crate::{feature::catalog::ValidationIssue, SchemaViolation};
 
 pub type Result<T> = std::result::Result<T, S100Error>;
 
@@ -7,7 +18,91 @@ pub type Result<T> = std::result::Result<T, S100Error>;
 pub enum S100Error {
     Io(IoError),
     Xml(XmlParseError),
-    Parse(String),
+    MissingChild {
+        location: Location,
+        child: String,
+    },
+    MissingAttribute {
+        location: Location,
+        attribute: String,
+    },
+    InvalidValue {
+        location: Location,
+        value: String,
+    },
+    InvalidEnum {
+        location: Location,
+        name: String,
+        value: String,
+    },
+    UnexpectedElement {
+        location: Location,
+    },
+    InvalidAttributeValue {
+        attribute: String,
+        value: String,
+        reason: String,
+    },
+    UnsupportedConstraint {
+        attribute: String,
+        constraint: String,
+    },
+    UndefinedItem {
+        code: String,
+    },
+    UndefinedSuperType {
+        code: String,
+        super_type: String,
+    },
+    UndefinedAttribute {
+        code: String,
+        attribute: String,
+    },
+    CyclicSuperType {
+        code: String,
+        through: String,
+    },
+    AbstractType {
+        code: String,
+    },
+    InvalidName {
+        code: String,
+        name: String,
+    },
+    DuplicateName {
+        name: String,
+        first: String,
+        second: String,
+    },
+    MissingListedValues {
+        code: String,
+    },
+    DuplicateListedValue {
+        code: String,
+        value: u64,
+    },
+    NonFiniteRange {
+        code: String,
+    },
+    MissingField {
+        item: String,
+        field: String,
+    },
+    InvalidField {
+        item: String,
+        field: String,
+        reason: String,
+    },
+    InvalidCatalog(Vec<ValidationIssue>),
+    Write {
+        name: String,
+        reason: String,
+    },
+    SchemaLoad {
+        path: PathBuf,
+        violations: Vec<SchemaViolation>,
+    },
+    Schema(Vec<SchemaViolation>),
 }
 
 impl fmt::Display for S100Error {
@@ -15,7 +110,125 @@ impl fmt::Display for S100Error {
         match self {
             S100Error::Io(e) => write!(f, "an IO error occured: {}", e),
             S100Error::Xml(e) => write!(f, "an XML error occured: {}", e),
-            S100Error::Parse(s) => write!(f, "an error occured while parsing an S-100 file: {}", s),
+            S100Error::MissingChild { location, child } => {
+                write!(f, "{}: missing a child node called '{}'", location, child)
+            }
+            S100Error::MissingAttribute {
+                location,
+                attribute,
+            } => write!(
+                f,
+                "{}: missing an attribute called '{}'",
+                location, attribute
+            ),
+            S100Error::InvalidValue { location, value } => {
+                write!(f, "{}: invalid value '{}'", location, value)
+            }
+            S100Error::InvalidEnum {
+                location,
+                name,
+                value,
+            } => write!(f, "{}: '{}' is not a valid {}", location, value, name),
+            S100Error::UnexpectedElement { location } => {
+                write!(f, "{}: unexpected element", location)
+            }
+            S100Error::InvalidAttributeValue {
+                attribute,
+                value,
+                reason,
+            } => write!(
+                f,
+                "'{}' is not a valid value for '{}': {}",
+                value, attribute, reason
+            ),
+            S100Error::UnsupportedConstraint {
+                attribute,
+                constraint,
+            } => write!(
+                f,
+                "'{}' has a {} constraint that cannot be checked",
+                attribute, constraint
+            ),
+            S100Error::UndefinedItem { code } => {
+                write!(f, "'{}' is not defined in the catalog", code)
+            }
+            S100Error::UndefinedSuperType { code, super_type } => write!(
+                f,
+                "'{}' references an undefined superType '{}'",
+                code, super_type
+            ),
+            S100Error::UndefinedAttribute { code, attribute } => {
+                write!(f, "'{}' binds an undefined attribute '{}'", code, attribute)
+            }
+            S100Error::CyclicSuperType { code, through } => write!(
+                f,
+                "'{}' has a cyclic superType chain through '{}'",
+                code, through
+            ),
+            S100Error::AbstractType { code } => {
+                write!(f, "'{}' is abstract and cannot be instantiated", code)
+            }
+            S100Error::InvalidName { code, name } => {
+                write!(f, "'{}' does not make a valid name: '{}'", code, name)
+            }
+            S100Error::DuplicateName {
+                name,
+                first,
+                second,
+            } => write!(
+                f,
+                "'{}' and '{}' both generate the name '{}'",
+                first, second, name
+            ),
+            S100Error::MissingListedValues { code } => {
+                write!(f, "'{}' is an enumeration without listed values", code)
+            }
+            S100Error::DuplicateListedValue { code, value } => {
+                write!(f, "'{}' lists the code {} more than once", code, value)
+            }
+            S100Error::NonFiniteRange { code } => write!(
+                f,
+                "'{}' has a range bound that is not a finite number",
+                code
+            ),
+            S100Error::MissingField { item, field } => {
+                write!(f, "'{}' is missing a value for '{}'", item, field)
+            }
+            S100Error::InvalidField {
+                item,
+                field,
+                reason,
+            } => write!(
+                f,
+                "'{}' has an invalid value for '{}': {}",
+                item, field, reason
+            ),
+            S100Error::InvalidCatalog(issues) => {
+                let issues: Vec<String> = issues.iter().map(|val| val.to_string()).collect();
+                write!(f, "the catalog is not consistent: {}", issues.join("; "))
+            }
+            S100Error::Write { name, reason } => {
+                write!(f, "unable to write '{}': {}", name, reason)
+            }
+            S100Error::SchemaLoad { path, violations } => {
+                let violations: Vec<String> =
+                    violations.iter().map(|val| val.to_string()).collect();
+                write!(
+                    f,
+                    "unable to load the schema '{}': {}",
+                    path.display(),
+                    violations.join("; ")
+                )
+            }
+            S100Error::Schema(violations) => {
+                let violations: Vec<String> =
+                    violations.iter().map(|val| val.to_string()).collect();
+                write!(
+                    f,
+                    "the document does not conform to its schema: {}",
+                    violations.join("; ")
+                )
+            }
         }
     }
 }
@@ -25,7 +238,7 @@ impl Error for S100Error {
         match *self {
             S100Error::Io(ref e) => Some(e),
             S100Error::Xml(ref e) => Some(e),
-            S100Error::Parse(_) => None,
+            _ => None,
         }
     }
 }
@@ -44,43 +257,202 @@ impl From<XmlParseError> for S100Error {
 
 impl S100Error {
     pub(crate) fn invalid_child<T>(node: Node) -> Result<T> {
-        Err(S100Error::Parse(format!(
-            "'{}' contains an invalid child node called '{}'",
-            node.get_parent()
-                .expect("not expecting root node")
-                .get_name(),
-            node.get_name()
-        )))
+        Err(S100Error::UnexpectedElement {
+            location: Location::of(&node),
+        })
     }
 
-    pub(crate) fn invalid_enum<T>(node_name: &str, child_node_name: &str) -> Result<T> {
-        Err(S100Error::Parse(format!(
-            "'{}' received a node named '{}'",
-            child_node_name, node_name
-        )))
+    pub(crate) fn invalid_enum<T>(name: &str, value: &str) -> Result<T> {
+        Err(S100Error::InvalidEnum {
+            location: Location::default(),
+            name: name.to_string(),
+            value: value.to_string(),
+        })
     }
 
     pub(crate) fn invalid_value<T>(node: Node) -> Result<T> {
-        Err(S100Error::Parse(format!(
-            "'{}' received an invalid value: '{}'",
-            node.get_name(),
-            node.get_content()
-        )))
-    }
-
-    pub(crate) fn missing_attribute<T>(node: Node, child_name: &str) -> Result<T> {
-        Err(S100Error::Parse(format!(
-            "'{}' is missing an attribute called '{}'",
-            node.get_name(),
-            child_name
-        )))
-    }
-
-    pub(crate) fn missing_child<T>(node: Node, child_name: &str) -> Result<T> {
-        Err(S100Error::Parse(format!(
-            "'{}' is missing a child node called '{}'",
-            node.get_name(),
-            child_name
-        )))
+        Err(S100Error::InvalidValue {
+            location: Location::of(&node),
+            value: node.get_content(),
+        })
+    }
+
+    pub(crate) fn invalid_attribute_value<T>(node: Node, value: &str) -> Result<T> {
+        Err(S100Error::InvalidValue {
+            location: Location::of(&node),
+            value: value.to_string(),
+        })
+    }
+
+    // For documents without a root element there is no node to point at.
+    pub(crate) fn missing_root<T>(root: &str) -> Result<T> {
+        Err(S100Error::MissingChild {
+            location: Location::default(),
+            child: root.to_string(),
+        })
+    }
+
+    pub(crate) fn missing_attribute<T>(node: Node, attribute: &str) -> Result<T> {
+        Err(S100Error::MissingAttribute {
+            location: Location::of(&node),
+            attribute: attribute.to_string(),
+        })
+    }
+
+    pub(crate) fn missing_child<T>(node: Node, child: &str) -> Result<T> {
+        Err(S100Error::MissingChild {
+            location: Location::of(&node),
+            child: child.to_string(),
+        })
+    }
+
+    pub(crate) fn missing_field<T>(item_name: &str, field_name: &str) -> Result<T> {
+        Err(S100Error::MissingField {
+            item: item_name.to_string(),
+            field: field_name.to_string(),
+        })
+    }
+
+    pub(crate) fn invalid_field<T>(item_name: &str, field_name: &str, reason: &str) -> Result<T> {
+        Err(S100Error::InvalidField {
+            item: item_name.to_string(),
+            field: field_name.to_string(),
+            reason: reason.to_string(),
+        })
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            S100Error::MissingChild { location, .. }
+            | S100Error::MissingAttribute { location, .. }
+            | S100Error::InvalidValue { location, .. }
+            | S100Error::InvalidEnum { location, .. }
+            | S100Error::UnexpectedElement { location } => Some(location),
+            _ => None,
+        }
+    }
+
+    fn location_mut(&mut self) -> Option<&mut Location> {
+        match self {
+            S100Error::MissingChild { location, .. }
+            | S100Error::MissingAttribute { location, .. }
+            | S100Error::InvalidValue { location, .. }
+            | S100Error::InvalidEnum { location, .. }
+            | S100Error::UnexpectedElement { location } => Some(location),
+            _ => None,
+        }
+    }
+
+    // Locates errors raised without a node, such as those from `FromStr` implementations.
+    pub(crate) fn at(mut self, node: &Node) -> S100Error {
+        if let Some(location) = self.location_mut() {
+            if location.element_path.is_empty() {
+                let path = location.path.take();
+                *location = Location::of(node);
+                location.path = path;
+            }
+        }
+        self
+    }
+
+    pub(crate) fn with_path(mut self, path: &Path) -> S100Error {
+        if let Some(location) = self.location_mut() {
+            location.path = Some(path.to_path_buf());
+        }
+        self
+    }
+}
+
+// Where in a document an error was found. The element path is XPath-like, using the prefixes of
+// the document and a 1-based position when an element has siblings of the same name.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Location {
+    path: Option<PathBuf>,
+    line: Option<u32>,
+    element_path: String,
+}
+
+impl Location {
+    pub(crate) fn of(node: &Node) -> Location {
+        // xmlGetLineNo only reads the line recorded on the node by the parser.
+        let line = unsafe { xmlGetLineNo(node.node_ptr()) };
+        Location {
+            path: None,
+            line: if line > 0 { Some(line as u32) } else { None },
+            element_path: element_path(node),
+        }
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        match self.path.as_ref() {
+            Some(val) => Some(val.as_path()),
+            None => None,
+        }
+    }
+
+    pub fn line(&self) -> Option<u32> {
+        self.line
+    }
+
+    pub fn element_path(&self) -> &str {
+        self.element_path.as_str()
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.path.as_ref(), self.line) {
+            (Some(path), Some(line)) => write!(f, "{}:{}: ", path.display(), line)?,
+            (Some(path), None) => write!(f, "{}: ", path.display())?,
+            (None, Some(line)) => write!(f, "line {}: ", line)?,
+            (None, None) => {}
+        }
+        write!(f, "{}", self.element_path)
+    }
+}
+
+fn element_path(node: &Node) -> String {
+    let mut segments: Vec<String> = Vec::new();
+    let mut current = Some(node.clone());
+
+    while let Some(node) = current {
+        if node.get_type() != Some(NodeType::ElementNode) {
+            break;
+        }
+
+        let name = qualified_name(&node);
+        let parent = node.get_parent();
+        let siblings: Vec<Node> = match parent.as_ref() {
+            Some(val) if val.get_type() == Some(NodeType::ElementNode) => val
+                .get_child_elements()
+                .into_iter()
+                .filter(|val| qualified_name(val) == name)
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        if siblings.len() > 1 {
+            let position = siblings
+                .iter()
+                .position(|val| val.node_ptr() == node.node_ptr())
+                .unwrap_or(0);
+            segments.push(format!("{}[{}]", name, position + 1));
+        } else {
+            segments.push(name);
+        }
+
+        current = parent;
+    }
+
+    segments.reverse();
+    format!("/{}", segments.join("/"))
+}
+
+fn qualified_name(node: &Node) -> String {
+    match node.get_namespace() {
+        Some(val) if !val.get_prefix().is_empty() => {
+            format!("{}:{}", val.get_prefix(), node.get_name())
+        }
+        _ => node.get_name(),
     }
 }
@@ -0,0 +1,258 @@
+use libxml::tree::Node;
+
+use super::{
+    xml_writer::{add_element, add_reference, add_text, set_attribute, Namespaces},
+    Multiplicity, ATTRIBUTE_BINDING, FC_NAMESPACES, MULTIPLICITY, SUB_ATTRIBUTE_BINDING, XML_REF,
+};
+use crate::{namespace::element_name, Result, S100Error};
+
+const SEQUENTIAL: &str = "sequential";
+const PERMITTED_VALUES: &str = "permittedValues";
+const VALUE: &str = "value";
+const ATTRIBUTE: &str = "attribute";
+
+#[derive(Clone, Debug)]
+pub struct AttributeBinding {
+    multiplicity: Multiplicity,
+    permitted_values: Vec<u64>,
+    sequential: bool,
+    attribute: String,
+}
+
+impl AttributeBinding {
+    pub fn builder() -> AttributeBindingBuilder {
+        AttributeBindingBuilder {
+            multiplicity: None,
+            permitted_values: Vec::new(),
+            sequential: false,
+            attribute: String::new(),
+        }
+    }
+
+    pub(super) fn parse(node: Node) -> Result<AttributeBinding> {
+        let node_name = element_name(&node, FC_NAMESPACES);
+        if node_name != ATTRIBUTE_BINDING && node_name != SUB_ATTRIBUTE_BINDING {
+            return S100Error::invalid_child(node);
+        }
+
+        let sequential = match node.get_attribute(SEQUENTIAL) {
+            Some(val) => match val.as_str() {
+                "true" | "1" => true,
+                "false" | "0" => false,
+                _ => return S100Error::invalid_value(node),
+            },
+            None => false,
+        };
+        let mut multiplicity: Option<Multiplicity> = None;
+        let mut permitted_values: Vec<u64> = Vec::new();
+        let mut attribute: Option<String> = None;
+
+        for child_node in node.get_child_elements() {
+            match element_name(&child_node, FC_NAMESPACES).as_str() {
+                MULTIPLICITY => match Multiplicity::parse(child_node) {
+                    Ok(val) => multiplicity = Some(val),
+                    Err(e) => return Err(e),
+                },
+                PERMITTED_VALUES => {
+                    for value_node in child_node.get_child_elements() {
+                        if element_name(&value_node, FC_NAMESPACES) != VALUE {
+                            return S100Error::invalid_child(value_node);
+                        }
+                        match value_node.get_content().trim().parse() {
+                            Ok(val) => permitted_values.push(val),
+                            Err(_) => return S100Error::invalid_value(value_node),
+                        }
+                    }
+                }
+                ATTRIBUTE => {
+                    if let Some(val) = child_node.get_attribute(XML_REF) {
+                        attribute = Some(val);
+                    } else {
+                        return S100Error::missing_attribute(child_node, XML_REF);
+                    }
+                }
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        if multiplicity.is_none() {
+            return S100Error::missing_child(node, MULTIPLICITY);
+        }
+        if attribute.is_none() {
+            return S100Error::missing_child(node, ATTRIBUTE);
+        }
+
+        Ok(AttributeBinding {
+            multiplicity: multiplicity.unwrap(),
+            permitted_values,
+            sequential,
+            attribute: attribute.unwrap(),
+        })
+    }
+
+    // The element name differs between attributeBinding and subAttributeBinding.
+    pub(super) fn write(
+        &self,
+        parent: &mut Node,
+        namespaces: &Namespaces,
+        name: &str,
+    ) -> Result<()> {
+        let mut node = add_element(parent, &namespaces.fc, name)?;
+        set_attribute(&mut node, SEQUENTIAL, &self.sequential.to_string())?;
+        self.multiplicity.write(&mut node, namespaces)?;
+        if !self.permitted_values.is_empty() {
+            let mut values_node = add_element(&mut node, &namespaces.fc, PERMITTED_VALUES)?;
+            for val in &self.permitted_values {
+                add_text(&mut values_node, &namespaces.fc, VALUE, &val.to_string())?;
+            }
+        }
+        add_reference(&mut node, &namespaces.fc, ATTRIBUTE, &self.attribute)
+    }
+
+    pub fn multiplicity(&self) -> Multiplicity {
+        self.multiplicity
+    }
+
+    pub fn permitted_values(&self) -> &[u64] {
+        &self.permitted_values
+    }
+
+    pub fn sequential(&self) -> bool {
+        self.sequential
+    }
+
+    pub fn attribute(&self) -> &str {
+        self.attribute.as_str()
+    }
+}
+
+// Rejects attribute bindings that bind the same attribute more than once.
+pub(super) fn check_unique(item_name: &str, bindings: &[AttributeBinding]) -> Result<()> {
+    for (index, binding) in bindings.iter().enumerate() {
+        if bindings[..index]
+            .iter()
+            .any(|val| val.attribute == binding.attribute)
+        {
+            return S100Error::invalid_field(
+                item_name,
+                ATTRIBUTE,
+                &format!("'{}' is bound more than once", binding.attribute),
+            );
+        }
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug)]
+pub struct AttributeBindingBuilder {
+    multiplicity: Option<Multiplicity>,
+    permitted_values: Vec<u64>,
+    sequential: bool,
+    attribute: String,
+}
+
+impl AttributeBindingBuilder {
+    pub fn multiplicity(mut self, multiplicity: Multiplicity) -> AttributeBindingBuilder {
+        self.multiplicity = Some(multiplicity);
+        self
+    }
+
+    pub fn permitted_value(mut self, permitted_value: u64) -> AttributeBindingBuilder {
+        self.permitted_values.push(permitted_value);
+        self
+    }
+
+    pub fn sequential(mut self, sequential: bool) -> AttributeBindingBuilder {
+        self.sequential = sequential;
+        self
+    }
+
+    pub fn attribute(mut self, attribute: &str) -> AttributeBindingBuilder {
+        self.attribute = attribute.to_string();
+        self
+    }
+
+    pub fn build(self) -> Result<AttributeBinding> {
+        if self.multiplicity.is_none() {
+            return S100Error::missing_field(ATTRIBUTE_BINDING, MULTIPLICITY);
+        }
+        if self.attribute.is_empty() {
+            return S100Error::missing_field(ATTRIBUTE_BINDING, ATTRIBUTE);
+        }
+        for (index, val) in self.permitted_values.iter().enumerate() {
+            if self.permitted_values[..index].contains(val) {
+                return S100Error::invalid_field(
+                    ATTRIBUTE_BINDING,
+                    PERMITTED_VALUES,
+                    &format!("{} is permitted more than once", val),
+                );
+            }
+        }
+
+        Ok(AttributeBinding {
+            multiplicity: self.multiplicity.unwrap(),
+            permitted_values: self.permitted_values,
+            sequential: self.sequential,
+            attribute: self.attribute,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libxml::parser::Parser;
+
+    use super::{AttributeBinding, Multiplicity};
+
+    #[test]
+    fn deserialize() {
+        let xml = r#"
+            <S100FC:subAttributeBinding sequential="false" xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base">
+                <S100FC:multiplicity>
+                    <S100Base:lower>0</S100Base:lower>
+                    <S100Base:upper>1</S100Base:upper>
+                </S100FC:multiplicity>
+                <S100FC:permittedValues>
+                    <S100FC:value>1</S100FC:value>
+                    <S100FC:value>3</S100FC:value>
+                </S100FC:permittedValues>
+                <S100FC:attribute ref="nameUsage"/>
+            </S100FC:subAttributeBinding>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = AttributeBinding::parse(node).unwrap();
+
+        assert_eq!(target.multiplicity().lower(), 0);
+        assert_eq!(target.multiplicity().upper(), Some(1));
+        assert_eq!(target.permitted_values(), &[1, 3]);
+        assert!(!target.sequential());
+        assert_eq!(target.attribute(), "nameUsage");
+    }
+
+    #[test]
+    fn build() {
+        let target = AttributeBinding::builder()
+            .multiplicity(Multiplicity::new(0, Some(1)).unwrap())
+            .permitted_value(1)
+            .permitted_value(3)
+            .attribute("categoryOfCheckpoint")
+            .build()
+            .unwrap();
+        assert_eq!(target.permitted_values(), &[1, 3]);
+        assert!(!target.sequential());
+
+        let result = AttributeBinding::builder()
+            .attribute("categoryOfCheckpoint")
+            .build();
+        assert!(result.is_err());
+        let result = AttributeBinding::builder()
+            .multiplicity(Multiplicity::new(0, Some(1)).unwrap())
+            .permitted_value(1)
+            .permitted_value(1)
+            .attribute("categoryOfCheckpoint")
+            .build();
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,201 @@
+use libxml::tree::Node;
+
+use super::{
+    xml_writer::{add_element, add_text, Namespaces},
+    NumericRange, BASE_NAMESPACES, CONSTRAINTS, FC_NAMESPACES, NUMERIC_RANGE,
+};
+use crate::{namespace::element_name, Result, S100Error};
+
+const STRING_LENGTH: &str = "stringLength";
+const TEXT_PATTERN: &str = "textPattern";
+const PRECISION: &str = "precision";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttributeConstraints {
+    string_length: Option<u64>,
+    text_pattern: Option<String>,
+    range: Option<NumericRange>,
+    precision: Option<u32>,
+}
+
+impl AttributeConstraints {
+    pub fn builder() -> AttributeConstraintsBuilder {
+        AttributeConstraintsBuilder {
+            constraints: AttributeConstraints {
+                string_length: None,
+                text_pattern: None,
+                range: None,
+                precision: None,
+            },
+        }
+    }
+
+    pub(super) fn parse(node: Node) -> Result<AttributeConstraints> {
+        if element_name(&node, FC_NAMESPACES) != CONSTRAINTS {
+            return S100Error::invalid_child(node);
+        }
+
+        let mut string_length: Option<u64> = None;
+        let mut text_pattern: Option<String> = None;
+        let mut range: Option<NumericRange> = None;
+        let mut precision: Option<u32> = None;
+
+        for child_node in node.get_child_elements() {
+            match element_name(&child_node, BASE_NAMESPACES).as_str() {
+                STRING_LENGTH => match child_node.get_content().trim().parse() {
+                    Ok(val) => string_length = Some(val),
+                    Err(_) => return S100Error::invalid_value(child_node),
+                },
+                TEXT_PATTERN => text_pattern = Some(child_node.get_content()),
+                NUMERIC_RANGE => match NumericRange::parse(child_node) {
+                    Ok(val) => range = Some(val),
+                    Err(e) => return Err(e),
+                },
+                PRECISION => match child_node.get_content().trim().parse() {
+                    Ok(val) => precision = Some(val),
+                    Err(_) => return S100Error::invalid_value(child_node),
+                },
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        Ok(AttributeConstraints {
+            string_length,
+            text_pattern,
+            range,
+            precision,
+        })
+    }
+
+    pub(super) fn write(&self, parent: &mut Node, namespaces: &Namespaces) -> Result<()> {
+        let mut node = add_element(parent, &namespaces.fc, CONSTRAINTS)?;
+        if let Some(val) = self.string_length {
+            add_text(&mut node, &namespaces.base, STRING_LENGTH, &val.to_string())?;
+        }
+        if let Some(val) = &self.text_pattern {
+            add_text(&mut node, &namespaces.base, TEXT_PATTERN, val)?;
+        }
+        if let Some(val) = &self.range {
+            val.write(&mut node, namespaces)?;
+        }
+        if let Some(val) = self.precision {
+            add_text(&mut node, &namespaces.base, PRECISION, &val.to_string())?;
+        }
+        Ok(())
+    }
+
+    pub fn string_length(&self) -> Option<u64> {
+        self.string_length
+    }
+
+    pub fn text_pattern(&self) -> Option<&str> {
+        match self.text_pattern.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    pub fn range(&self) -> Option<&NumericRange> {
+        self.range.as_ref()
+    }
+
+    pub fn precision(&self) -> Option<u32> {
+        self.precision
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AttributeConstraintsBuilder {
+    constraints: AttributeConstraints,
+}
+
+impl AttributeConstraintsBuilder {
+    pub fn string_length(mut self, string_length: u64) -> AttributeConstraintsBuilder {
+        self.constraints.string_length = Some(string_length);
+        self
+    }
+
+    pub fn text_pattern(mut self, text_pattern: &str) -> AttributeConstraintsBuilder {
+        self.constraints.text_pattern = Some(text_pattern.to_string());
+        self
+    }
+
+    pub fn range(mut self, range: NumericRange) -> AttributeConstraintsBuilder {
+        self.constraints.range = Some(range);
+        self
+    }
+
+    pub fn precision(mut self, precision: u32) -> AttributeConstraintsBuilder {
+        self.constraints.precision = Some(precision);
+        self
+    }
+
+    pub fn build(self) -> Result<AttributeConstraints> {
+        let constraints = self.constraints;
+        if constraints.string_length.is_none()
+            && constraints.text_pattern.is_none()
+            && constraints.range.is_none()
+            && constraints.precision.is_none()
+        {
+            return S100Error::missing_field(CONSTRAINTS, "constraint");
+        }
+        if let Some(val) = &constraints.text_pattern {
+            if val.is_empty() {
+                return S100Error::missing_field(CONSTRAINTS, TEXT_PATTERN);
+            }
+        }
+        Ok(constraints)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libxml::parser::Parser;
+
+    use super::AttributeConstraints;
+    use crate::feature::catalog::IntervalType;
+
+    #[test]
+    fn deserialize() {
+        let xml = r#"
+            <S100FC:constraints xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base">
+                <S100Base:ACRange>
+                    <S100Base:lowerBound>0</S100Base:lowerBound>
+                    <S100Base:upperBound>360</S100Base:upperBound>
+                    <S100Base:closure>closedInterval</S100Base:closure>
+                </S100Base:ACRange>
+                <S100Base:precision>1</S100Base:precision>
+            </S100FC:constraints>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = AttributeConstraints::parse(node).unwrap();
+
+        assert_eq!(target.string_length(), None);
+        assert_eq!(target.text_pattern(), None);
+        assert_eq!(target.precision(), Some(1));
+
+        let range = target.range().unwrap();
+        assert_eq!(range.closure(), IntervalType::ClosedInterval);
+        assert!(range.contains(360.0));
+    }
+
+    #[test]
+    fn deserialize_text() {
+        let xml = r#"
+            <S100FC:constraints xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base">
+                <S100Base:stringLength>3</S100Base:stringLength>
+                <S100Base:textPattern>[A-Z]{3}</S100Base:textPattern>
+            </S100FC:constraints>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = AttributeConstraints::parse(node).unwrap();
+
+        assert_eq!(target.string_length(), Some(3));
+        assert_eq!(target.text_pattern(), Some("[A-Z]{3}"));
+        assert!(target.range().is_none());
+    }
+}
@@ -0,0 +1,453 @@
+use std::str::FromStr;
+
+use super::{AttributeValueType, IntervalType, Item, SimpleAttribute};
+use crate::{Result, S100Error};
+
+const TEXT_PATTERN: &str = "textPattern";
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttributeValue {
+    Boolean(bool),
+    Enumeration(u64),
+    Codelist(String),
+    Integer(i64),
+    Real(f64),
+    Text(String),
+    Date(String),
+    Time(String),
+    DateTime(String),
+    TruncatedDate(String),
+    Uri(String),
+    Url(String),
+    Urn(String),
+    IntervalType(IntervalType),
+    NumPlusUnit(f64, String),
+    // None stands for an infinite value.
+    UnlimitedInteger(Option<u64>),
+}
+
+impl AttributeValue {
+    pub(super) fn parse(attribute: &SimpleAttribute, input: &str) -> Result<AttributeValue> {
+        let value = match attribute.value_type() {
+            AttributeValueType::Boolean => match input.trim() {
+                "1" | "true" => AttributeValue::Boolean(true),
+                "0" | "false" => AttributeValue::Boolean(false),
+                _ => return invalid(attribute, input, "expected a boolean"),
+            },
+            AttributeValueType::Enumeration => match input.trim().parse::<u64>() {
+                Ok(code) => {
+                    if !attribute
+                        .listed_values()
+                        .iter()
+                        .any(|val| val.code() == code)
+                    {
+                        return invalid(attribute, input, "not one of the listed values");
+                    }
+                    AttributeValue::Enumeration(code)
+                }
+                Err(_) => return invalid(attribute, input, "expected a listed value code"),
+            },
+            AttributeValueType::Codelist => {
+                if input.trim().is_empty() {
+                    return invalid(attribute, input, "expected a codelist entry");
+                }
+                AttributeValue::Codelist(input.to_string())
+            }
+            AttributeValueType::Integer => match input.trim().parse::<i64>() {
+                Ok(val) => AttributeValue::Integer(val),
+                Err(_) => return invalid(attribute, input, "expected an integer"),
+            },
+            AttributeValueType::Real => match input.trim().parse::<f64>() {
+                Ok(val) if val.is_finite() => AttributeValue::Real(val),
+                _ => return invalid(attribute, input, "expected a real number"),
+            },
+            AttributeValueType::Text => AttributeValue::Text(input.to_string()),
+            AttributeValueType::Date => {
+                if !is_date(input.trim()) {
+                    return invalid(attribute, input, "expected a date");
+                }
+                AttributeValue::Date(input.trim().to_string())
+            }
+            AttributeValueType::Time => {
+                if !is_time(input.trim()) {
+                    return invalid(attribute, input, "expected a time");
+                }
+                AttributeValue::Time(input.trim().to_string())
+            }
+            AttributeValueType::DateTime => {
+                let is_date_time = match input.trim().split_once('T') {
+                    Some((date, time)) => is_date(date) && is_time(time),
+                    None => false,
+                };
+                if !is_date_time {
+                    return invalid(attribute, input, "expected a date and time");
+                }
+                AttributeValue::DateTime(input.trim().to_string())
+            }
+            AttributeValueType::TruncatedDate => {
+                if !is_truncated_date(input.trim()) {
+                    return invalid(attribute, input, "expected a truncated date");
+                }
+                AttributeValue::TruncatedDate(input.trim().to_string())
+            }
+            AttributeValueType::Uri => {
+                if !input.contains(':') {
+                    return invalid(attribute, input, "expected a URI");
+                }
+                AttributeValue::Uri(input.to_string())
+            }
+            AttributeValueType::Url => {
+                if !input.contains("://") {
+                    return invalid(attribute, input, "expected a URL");
+                }
+                AttributeValue::Url(input.to_string())
+            }
+            AttributeValueType::Urn => {
+                if !input.to_ascii_lowercase().starts_with("urn:") {
+                    return invalid(attribute, input, "expected a URN");
+                }
+                AttributeValue::Urn(input.to_string())
+            }
+            AttributeValueType::IntervalType => match IntervalType::from_str(input.trim()) {
+                Ok(val) => AttributeValue::IntervalType(val),
+                Err(_) => return invalid(attribute, input, "expected an interval type"),
+            },
+            AttributeValueType::NumPlusUnit => {
+                let (number, unit) = match input.trim().split_once(char::is_whitespace) {
+                    Some((number, unit)) => (number, unit.trim()),
+                    None => (input.trim(), ""),
+                };
+                match number.parse::<f64>() {
+                    Ok(val) if val.is_finite() && !unit.is_empty() => {
+                        AttributeValue::NumPlusUnit(val, unit.to_string())
+                    }
+                    _ => return invalid(attribute, input, "expected a number followed by a unit"),
+                }
+            }
+            AttributeValueType::UnlimitedInteger => match input.trim() {
+                "*" | "infinite" => AttributeValue::UnlimitedInteger(None),
+                val => match val.parse::<u64>() {
+                    Ok(val) => AttributeValue::UnlimitedInteger(Some(val)),
+                    Err(_) => return invalid(attribute, input, "expected a non-negative integer"),
+                },
+            },
+        };
+
+        match attribute.constraints() {
+            Some(constraints) => {
+                // The crate has no regular expression engine, so rather than accepting values it
+                // cannot check the pattern is reported as unsupported.
+                if constraints.text_pattern().is_some() {
+                    return Err(S100Error::UnsupportedConstraint {
+                        attribute: attribute.code().to_string(),
+                        constraint: TEXT_PATTERN.to_string(),
+                    });
+                }
+
+                if let Some(string_length) = constraints.string_length() {
+                    if let AttributeValue::Text(val) = &value {
+                        if val.chars().count() as u64 > string_length {
+                            return invalid(attribute, input, "exceeds the maximum string length");
+                        }
+                    }
+                }
+
+                let number = match &value {
+                    AttributeValue::Integer(val) => Some(*val as f64),
+                    AttributeValue::Real(val) => Some(*val),
+                    _ => None,
+                };
+                if let (Some(number), Some(range)) = (number, constraints.range()) {
+                    if !range.contains(number) {
+                        return invalid(attribute, input, "outside of the permitted range");
+                    }
+                }
+
+                if let (AttributeValue::Real(_), Some(precision)) =
+                    (&value, constraints.precision())
+                {
+                    let decimals = match input.trim().split_once('.') {
+                        Some((_, fraction)) => fraction.len(),
+                        None => 0,
+                    };
+                    if decimals > precision as usize {
+                        return invalid(attribute, input, "exceeds the permitted precision");
+                    }
+                }
+
+                Ok(value)
+            }
+            None => Ok(value),
+        }
+    }
+}
+
+fn invalid<T>(attribute: &SimpleAttribute, input: &str, reason: &str) -> Result<T> {
+    Err(S100Error::InvalidAttributeValue {
+        attribute: attribute.code().to_string(),
+        value: input.to_string(),
+        reason: reason.to_string(),
+    })
+}
+
+fn is_digits(input: &str) -> bool {
+    !input.is_empty() && input.chars().all(|c| c.is_ascii_digit())
+}
+
+fn in_range(input: &str, min: u32, max: u32) -> bool {
+    match input.parse::<u32>() {
+        Ok(val) => val >= min && val <= max,
+        Err(_) => false,
+    }
+}
+
+// Accepts both the ISO 8211 (YYYYMMDD) and the GML (YYYY-MM-DD) encodings.
+fn is_date(input: &str) -> bool {
+    if !input.is_ascii() {
+        return false;
+    }
+
+    let date: String = match input.len() {
+        8 => input.to_string(),
+        10 if &input[4..5] == "-" && &input[7..8] == "-" => input.replace('-', ""),
+        _ => return false,
+    };
+
+    date.len() == 8
+        && is_digits(&date)
+        && in_range(&date[4..6], 1, 12)
+        && in_range(&date[6..8], 1, 31)
+}
+
+// Accepts HHMMSS or HH:MM:SS, optionally followed by Z or a +/-HHMM offset.
+fn is_time(input: &str) -> bool {
+    if !input.is_ascii() {
+        return false;
+    }
+
+    let time = match input.find(['Z', '+', '-']) {
+        Some(position) => {
+            let zone = input[position..].replace(':', "");
+            let valid_zone = zone == "Z"
+                || (zone.len() == 5
+                    && is_digits(&zone[1..])
+                    && in_range(&zone[1..3], 0, 14)
+                    && in_range(&zone[3..5], 0, 59));
+            if !valid_zone {
+                return false;
+            }
+            &input[..position]
+        }
+        None => input,
+    };
+
+    let time: String = match time.len() {
+        6 => time.to_string(),
+        8 if &time[2..3] == ":" && &time[5..6] == ":" => time.replace(':', ""),
+        _ => return false,
+    };
+
+    time.len() == 6
+        && is_digits(&time)
+        && in_range(&time[0..2], 0, 23)
+        && in_range(&time[2..4], 0, 59)
+        && in_range(&time[4..6], 0, 60)
+}
+
+// A truncated date is YYYYMMDD where any of the year, month or day may be replaced by dashes.
+fn is_truncated_date(input: &str) -> bool {
+    if input.len() != 8 || !input.is_ascii() || input == "--------" {
+        return false;
+    }
+
+    let omitted = |part: &str| part.chars().all(|c| c == '-');
+    let year = &input[0..4];
+    let month = &input[4..6];
+    let day = &input[6..8];
+
+    (omitted(year) || is_digits(year))
+        && (omitted(month) || in_range(month, 1, 12) && is_digits(month))
+        && (omitted(day) || in_range(day, 1, 31) && is_digits(day))
+}
+
+#[cfg(test)]
+mod tests {
+    use libxml::parser::Parser;
+
+    use super::{AttributeValue, IntervalType, SimpleAttribute};
+    use crate::{diagnostics::Diagnostics, ParseMode, S100Error};
+
+    fn parse(xml: &str) -> SimpleAttribute {
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        SimpleAttribute::parse(node, &mut Diagnostics::new(ParseMode::Strict)).unwrap()
+    }
+
+    fn attribute(code: &str, value_type: &str) -> SimpleAttribute {
+        parse(&format!(
+            r#"
+            <S100FC:S100_FC_SimpleAttribute xmlns:S100FC="http://www.iho.int/S100FC">
+                <S100FC:name>{0}</S100FC:name>
+                <S100FC:definition>{0}</S100FC:definition>
+                <S100FC:code>{0}</S100FC:code>
+                <S100FC:valueType>{1}</S100FC:valueType>
+            </S100FC:S100_FC_SimpleAttribute>"#,
+            code, value_type
+        ))
+    }
+
+    #[test]
+    fn parse_value_enumeration() {
+        let target = parse(
+            r#"
+            <S100FC:S100_FC_SimpleAttribute xmlns:S100FC="http://www.iho.int/S100FC">
+                <S100FC:name>Category of checkpoint</S100FC:name>
+                <S100FC:definition>Classification of a checkpoint.</S100FC:definition>
+                <S100FC:code>categoryOfCheckpoint</S100FC:code>
+                <S100FC:valueType>enumeration</S100FC:valueType>
+                <S100FC:listedValues>
+                    <S100FC:listedValue>
+                        <S100FC:label>custom</S100FC:label>
+                        <S100FC:definition>A customs office.</S100FC:definition>
+                        <S100FC:code>1</S100FC:code>
+                    </S100FC:listedValue>
+                </S100FC:listedValues>
+            </S100FC:S100_FC_SimpleAttribute>"#,
+        );
+
+        assert_eq!(
+            target.parse_value("1").unwrap(),
+            AttributeValue::Enumeration(1)
+        );
+        assert!(target.parse_value("2").is_err());
+        assert!(target.parse_value("custom").is_err());
+    }
+
+    #[test]
+    fn parse_value_constraints() {
+        let target = parse(
+            r#"
+            <S100FC:S100_FC_SimpleAttribute xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base">
+                <S100FC:name>Orientation Value</S100FC:name>
+                <S100FC:definition>The angular distance measured from true north.</S100FC:definition>
+                <S100FC:code>orientationValue</S100FC:code>
+                <S100FC:valueType>real</S100FC:valueType>
+                <S100FC:constraints>
+                    <S100Base:ACRange>
+                        <S100Base:lowerBound>0</S100Base:lowerBound>
+                        <S100Base:upperBound>360</S100Base:upperBound>
+                        <S100Base:closure>geLtInterval</S100Base:closure>
+                    </S100Base:ACRange>
+                    <S100Base:precision>1</S100Base:precision>
+                </S100FC:constraints>
+            </S100FC:S100_FC_SimpleAttribute>"#,
+        );
+
+        assert_eq!(
+            target.parse_value("359.5").unwrap(),
+            AttributeValue::Real(359.5)
+        );
+        assert!(target.parse_value("360").is_err());
+        assert!(target.parse_value("12.25").is_err());
+        assert!(target.parse_value("north").is_err());
+    }
+
+    #[test]
+    fn parse_value_text_pattern() {
+        let target = parse(
+            r#"
+            <S100FC:S100_FC_SimpleAttribute xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base">
+                <S100FC:name>Call sign</S100FC:name>
+                <S100FC:definition>The designated call-sign of a radio station.</S100FC:definition>
+                <S100FC:code>callSign</S100FC:code>
+                <S100FC:valueType>text</S100FC:valueType>
+                <S100FC:constraints>
+                    <S100Base:textPattern>[A-Z0-9]+</S100Base:textPattern>
+                </S100FC:constraints>
+            </S100FC:S100_FC_SimpleAttribute>"#,
+        );
+
+        match target.parse_value("PBVS") {
+            Err(S100Error::UnsupportedConstraint {
+                attribute,
+                constraint,
+            }) => {
+                assert_eq!(attribute, "callSign");
+                assert_eq!(constraint, "textPattern");
+            }
+            val => panic!("unexpected result: {:?}", val),
+        }
+    }
+
+    #[test]
+    fn parse_value_types() {
+        let target = attribute("blocked", "boolean");
+        assert_eq!(
+            target.parse_value("true").unwrap(),
+            AttributeValue::Boolean(true)
+        );
+        assert_eq!(
+            target.parse_value(" 0\n").unwrap(),
+            AttributeValue::Boolean(false)
+        );
+        match target.parse_value("yes") {
+            Err(S100Error::InvalidAttributeValue {
+                attribute, value, ..
+            }) => {
+                assert_eq!(attribute, "blocked");
+                assert_eq!(value, "yes");
+            }
+            val => panic!("unexpected result: {:?}", val),
+        }
+
+        let target = attribute("dateStart", "date");
+        assert!(target.parse_value("20210203").is_ok());
+        assert!(target.parse_value("2021-02-03").is_ok());
+        assert!(target.parse_value("20211301").is_err());
+        assert_eq!(
+            target.parse_value(" 20210101\n").unwrap(),
+            AttributeValue::Date("20210101".to_string())
+        );
+
+        let target = attribute("timeOfDayStart", "time");
+        assert!(target.parse_value("231500Z").is_ok());
+        assert!(target.parse_value("23:15:00+01:00").is_ok());
+        assert!(target.parse_value("2515").is_err());
+
+        let target = attribute("dateTimeStart", "dateTime");
+        assert!(target.parse_value("20210203T231500").is_ok());
+        assert!(target.parse_value("20210203").is_err());
+
+        let target = attribute("periodicDateStart", "S100_TruncatedDate");
+        assert_eq!(
+            target.parse_value("----0401").unwrap(),
+            AttributeValue::TruncatedDate("----0401".to_string())
+        );
+        assert!(target.parse_value("--------").is_err());
+        assert!(target.parse_value("2021-4-1").is_err());
+
+        let target = attribute("closure", "S100_IntervalType");
+        assert_eq!(
+            target.parse_value("closedInterval").unwrap(),
+            AttributeValue::IntervalType(IntervalType::ClosedInterval)
+        );
+
+        let target = attribute("height", "S100_NumPlusUnit");
+        assert_eq!(
+            target.parse_value("12.5 m").unwrap(),
+            AttributeValue::NumPlusUnit(12.5, "m".to_string())
+        );
+        assert!(target.parse_value("12.5").is_err());
+
+        let target = attribute("maximumCount", "S100_UnlimitedInteger");
+        assert_eq!(
+            target.parse_value("*").unwrap(),
+            AttributeValue::UnlimitedInteger(None)
+        );
+
+        let target = attribute("sourceIndication", "URN");
+        assert!(target.parse_value("urn:mrn:iho:s101").is_ok());
+        assert!(target.parse_value("http://iho.int").is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
 
 use crate::S100Error;
 
@@ -16,6 +16,10 @@ pub enum AttributeValueType {
     Uri,
     Url,
     Urn,
+    Codelist,
+    IntervalType,
+    NumPlusUnit,
+    UnlimitedInteger,
 }
 
 impl FromStr for AttributeValueType {
@@ -31,11 +35,63 @@ impl FromStr for AttributeValueType {
             "date" => Ok(AttributeValueType::Date),
             "time" => Ok(AttributeValueType::Time),
             "dateTime" => Ok(AttributeValueType::DateTime),
-            "S100_TruncatedDate" => Ok(AttributeValueType::DateTime),
+            "S100_TruncatedDate" => Ok(AttributeValueType::TruncatedDate),
             "URI" => Ok(AttributeValueType::Uri),
             "URL" => Ok(AttributeValueType::Url),
             "URN" => Ok(AttributeValueType::Urn),
+            "S100_Codelist" | "S100_CodeList" => Ok(AttributeValueType::Codelist),
+            "S100_IntervalType" => Ok(AttributeValueType::IntervalType),
+            "S100_NumPlusUnit" => Ok(AttributeValueType::NumPlusUnit),
+            "S100_UnlimitedInteger" => Ok(AttributeValueType::UnlimitedInteger),
             _ => S100Error::invalid_enum("valueType", input),
         }
     }
 }
+
+impl fmt::Display for AttributeValueType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            AttributeValueType::Boolean => "boolean",
+            AttributeValueType::Enumeration => "enumeration",
+            AttributeValueType::Integer => "integer",
+            AttributeValueType::Real => "real",
+            AttributeValueType::Text => "text",
+            AttributeValueType::Date => "date",
+            AttributeValueType::Time => "time",
+            AttributeValueType::DateTime => "dateTime",
+            AttributeValueType::TruncatedDate => "S100_TruncatedDate",
+            AttributeValueType::Uri => "URI",
+            AttributeValueType::Url => "URL",
+            AttributeValueType::Urn => "URN",
+            AttributeValueType::Codelist => "S100_Codelist",
+            AttributeValueType::IntervalType => "S100_IntervalType",
+            AttributeValueType::NumPlusUnit => "S100_NumPlusUnit",
+            AttributeValueType::UnlimitedInteger => "S100_UnlimitedInteger",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::AttributeValueType;
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            AttributeValueType::from_str("S100_TruncatedDate").unwrap(),
+            AttributeValueType::TruncatedDate
+        );
+        assert_eq!(
+            AttributeValueType::from_str("S100_Codelist").unwrap(),
+            AttributeValueType::Codelist
+        );
+        assert_eq!(
+            AttributeValueType::from_str("S100_NumPlusUnit").unwrap(),
+            AttributeValueType::NumPlusUnit
+        );
+        assert!(AttributeValueType::from_str("S100_Unknown").is_err());
+    }
+}
@@ -0,0 +1,549 @@
+use std::collections::HashMap;
+
+use super::{
+    AttributeBinding, AttributeConstraints, FeatureBinding, FeatureCatalog, InformationBinding,
+    Item, ItemKind, ListedValue, Translation,
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Change {
+    kind: ChangeKind,
+    item_kind: ItemKind,
+    code: String,
+    property: Option<String>,
+    old_value: Option<String>,
+    new_value: Option<String>,
+}
+
+impl Change {
+    pub fn kind(&self) -> ChangeKind {
+        self.kind
+    }
+
+    pub fn item_kind(&self) -> ItemKind {
+        self.item_kind
+    }
+
+    pub fn code(&self) -> &str {
+        self.code.as_str()
+    }
+
+    // None when the item itself was added or removed, otherwise a path such as
+    // `attributeBinding[depthRangeMinimumValue].multiplicity`.
+    pub fn property(&self) -> Option<&str> {
+        match self.property.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    pub fn old_value(&self) -> Option<&str> {
+        match self.old_value.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    pub fn new_value(&self) -> Option<&str> {
+        match self.new_value.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+}
+
+pub(super) fn diff(old: &FeatureCatalog, new: &FeatureCatalog) -> Vec<Change> {
+    let mut changes: Vec<Change> = Vec::new();
+
+    diff_items(
+        &mut changes,
+        ItemKind::SimpleAttribute,
+        old.simple_attributes(),
+        new.simple_attributes(),
+        |diff, old, new| {
+            diff.optional("remarks", old.remarks(), new.remarks());
+            diff.value(
+                "valueType",
+                format!("{}", old.value_type()),
+                format!("{}", new.value_type()),
+            );
+            diff.optional(
+                "codelistType",
+                old.codelist_type().map(|val| format!("{}", val)),
+                new.codelist_type().map(|val| format!("{}", val)),
+            );
+            diff.optional(
+                "dictionaryReference",
+                old.dictionary_reference(),
+                new.dictionary_reference(),
+            );
+            diff.optional(
+                "quantitySpecification",
+                old.quality_specification().map(|val| format!("{}", val)),
+                new.quality_specification().map(|val| format!("{}", val)),
+            );
+            diff.optional(
+                "uom",
+                old.unit_of_measure().map(|val| val.name()),
+                new.unit_of_measure().map(|val| val.name()),
+            );
+            diff.constraints(old.constraints(), new.constraints());
+            diff.listed_values(old.listed_values(), new.listed_values());
+        },
+    );
+    diff_items(
+        &mut changes,
+        ItemKind::ComplexAttribute,
+        old.complex_attributes(),
+        new.complex_attributes(),
+        |diff, old, new| {
+            diff.optional("remarks", old.remarks(), new.remarks());
+            diff.attribute_bindings(
+                "subAttributeBinding",
+                old.sub_attribute_bindings(),
+                new.sub_attribute_bindings(),
+            );
+        },
+    );
+    diff_items(
+        &mut changes,
+        ItemKind::Role,
+        old.roles(),
+        new.roles(),
+        |diff, old, new| {
+            diff.optional("remarks", old.remarks(), new.remarks());
+        },
+    );
+    diff_items(
+        &mut changes,
+        ItemKind::InformationAssociation,
+        old.information_associations(),
+        new.information_associations(),
+        |diff, old, new| {
+            diff.optional("remarks", old.remarks(), new.remarks());
+            diff.value(
+                "isAbstract",
+                old.is_abstract().to_string(),
+                new.is_abstract().to_string(),
+            );
+            diff.optional("superType", old.super_type(), new.super_type());
+            diff.list("role", &old.roles(), &new.roles());
+        },
+    );
+    diff_items(
+        &mut changes,
+        ItemKind::FeatureAssociation,
+        old.feature_associations(),
+        new.feature_associations(),
+        |diff, old, new| {
+            diff.optional("remarks", old.remarks(), new.remarks());
+            diff.value(
+                "isAbstract",
+                old.is_abstract().to_string(),
+                new.is_abstract().to_string(),
+            );
+            diff.optional("superType", old.super_type(), new.super_type());
+            diff.list("role", &old.roles(), &new.roles());
+        },
+    );
+    diff_items(
+        &mut changes,
+        ItemKind::InformationType,
+        old.information_types(),
+        new.information_types(),
+        |diff, old, new| {
+            diff.optional("remarks", old.remarks(), new.remarks());
+            diff.value(
+                "isAbstract",
+                old.is_abstract().to_string(),
+                new.is_abstract().to_string(),
+            );
+            diff.optional("superType", old.super_type(), new.super_type());
+            diff.attribute_bindings(
+                "attributeBinding",
+                old.attribute_bindings(),
+                new.attribute_bindings(),
+            );
+            diff.information_bindings(old.information_bindings(), new.information_bindings());
+        },
+    );
+    diff_items(
+        &mut changes,
+        ItemKind::FeatureType,
+        old.feature_types(),
+        new.feature_types(),
+        |diff, old, new| {
+            diff.optional("remarks", old.remarks(), new.remarks());
+            diff.value(
+                "isAbstract",
+                old.is_abstract().to_string(),
+                new.is_abstract().to_string(),
+            );
+            diff.optional("superType", old.super_type(), new.super_type());
+            diff.value(
+                "featureUseType",
+                format!("{}", old.feature_use_type()),
+                format!("{}", new.feature_use_type()),
+            );
+            let old_primitives: Vec<String> = old
+                .permitted_primitives()
+                .iter()
+                .map(|val| format!("{}", val))
+                .collect();
+            let new_primitives: Vec<String> = new
+                .permitted_primitives()
+                .iter()
+                .map(|val| format!("{}", val))
+                .collect();
+            diff.list("permittedPrimitives", &old_primitives, &new_primitives);
+            diff.attribute_bindings(
+                "attributeBinding",
+                old.attribute_bindings(),
+                new.attribute_bindings(),
+            );
+            diff.information_bindings(old.information_bindings(), new.information_bindings());
+            diff.feature_bindings(old.feature_bindings(), new.feature_bindings());
+        },
+    );
+
+    changes
+}
+
+// Items are matched by code; removed and changed items are reported in the old catalogue's
+// order, followed by the added ones in the new catalogue's order.
+fn diff_items<T, F>(changes: &mut Vec<Change>, item_kind: ItemKind, old: &[T], new: &[T], diff: F)
+where
+    T: Item,
+    F: Fn(&mut ItemDiff, &T, &T),
+{
+    let new_items: HashMap<&str, &T> = new.iter().map(|val| (val.code(), val)).collect();
+    let old_items: HashMap<&str, &T> = old.iter().map(|val| (val.code(), val)).collect();
+
+    for old_item in old {
+        match new_items.get(old_item.code()) {
+            Some(new_item) => {
+                let mut item_diff = ItemDiff {
+                    changes,
+                    item_kind,
+                    code: old_item.code(),
+                };
+                item_diff.value("name", old_item.name(), new_item.name());
+                item_diff.value("definition", old_item.definition(), new_item.definition());
+                item_diff.optional("alias", old_item.alias(), new_item.alias());
+                item_diff.optional("language", old_item.language(), new_item.language());
+                item_diff.translations(
+                    "translation",
+                    old_item.translations(),
+                    new_item.translations(),
+                );
+                diff(&mut item_diff, old_item, new_item);
+            }
+            None => changes.push(Change {
+                kind: ChangeKind::Removed,
+                item_kind,
+                code: old_item.code().to_string(),
+                property: None,
+                old_value: Some(old_item.name().to_string()),
+                new_value: None,
+            }),
+        }
+    }
+
+    for new_item in new {
+        if !old_items.contains_key(new_item.code()) {
+            changes.push(Change {
+                kind: ChangeKind::Added,
+                item_kind,
+                code: new_item.code().to_string(),
+                property: None,
+                old_value: None,
+                new_value: Some(new_item.name().to_string()),
+            });
+        }
+    }
+}
+
+struct ItemDiff<'a> {
+    changes: &'a mut Vec<Change>,
+    item_kind: ItemKind,
+    code: &'a str,
+}
+
+impl<'a> ItemDiff<'a> {
+    fn push(
+        &mut self,
+        kind: ChangeKind,
+        property: String,
+        old_value: Option<String>,
+        new_value: Option<String>,
+    ) {
+        self.changes.push(Change {
+            kind,
+            item_kind: self.item_kind,
+            code: self.code.to_string(),
+            property: Some(property),
+            old_value,
+            new_value,
+        });
+    }
+
+    fn value<S: ToString>(&mut self, property: &str, old: S, new: S) {
+        let old = old.to_string();
+        let new = new.to_string();
+        if old != new {
+            self.push(
+                ChangeKind::Changed,
+                property.to_string(),
+                Some(old),
+                Some(new),
+            );
+        }
+    }
+
+    fn optional<S: ToString>(&mut self, property: &str, old: Option<S>, new: Option<S>) {
+        let old = old.map(|val| val.to_string());
+        let new = new.map(|val| val.to_string());
+        let kind = match (&old, &new) {
+            (None, Some(_)) => ChangeKind::Added,
+            (Some(_), None) => ChangeKind::Removed,
+            (Some(old), Some(new)) if old != new => ChangeKind::Changed,
+            _ => return,
+        };
+        self.push(kind, property.to_string(), old, new);
+    }
+
+    // Reports each entry that only appears on one side, keyed by its own value.
+    fn list<S: AsRef<str>>(&mut self, property: &str, old: &[S], new: &[S]) {
+        for val in old {
+            if !new.iter().any(|other| other.as_ref() == val.as_ref()) {
+                self.push(
+                    ChangeKind::Removed,
+                    format!("{}[{}]", property, val.as_ref()),
+                    Some(val.as_ref().to_string()),
+                    None,
+                );
+            }
+        }
+        for val in new {
+            if !old.iter().any(|other| other.as_ref() == val.as_ref()) {
+                self.push(
+                    ChangeKind::Added,
+                    format!("{}[{}]", property, val.as_ref()),
+                    None,
+                    Some(val.as_ref().to_string()),
+                );
+            }
+        }
+    }
+
+    fn keyed<T, K, D>(&mut self, property: &str, old: &[T], new: &[T], key: K, diff: D)
+    where
+        K: Fn(&T) -> String,
+        D: Fn(&mut Self, &str, &T, &T),
+    {
+        for old_val in old {
+            let path = format!("{}[{}]", property, key(old_val));
+            match new.iter().find(|val| key(val) == key(old_val)) {
+                Some(new_val) => diff(self, path.as_str(), old_val, new_val),
+                None => self.push(ChangeKind::Removed, path, Some(key(old_val)), None),
+            }
+        }
+        for new_val in new {
+            if !old.iter().any(|val| key(val) == key(new_val)) {
+                let path = format!("{}[{}]", property, key(new_val));
+                self.push(ChangeKind::Added, path, None, Some(key(new_val)));
+            }
+        }
+    }
+
+    fn translations(&mut self, property: &str, old: &[Translation], new: &[Translation]) {
+        self.keyed(
+            property,
+            old,
+            new,
+            |val| val.language().to_string(),
+            |diff, path, old, new| {
+                diff.value(&format!("{}.name", path), old.name(), new.name());
+                diff.optional(
+                    &format!("{}.definition", path),
+                    old.definition(),
+                    new.definition(),
+                );
+            },
+        );
+    }
+
+    fn listed_values(&mut self, old: &[ListedValue], new: &[ListedValue]) {
+        self.keyed(
+            "listedValue",
+            old,
+            new,
+            |val| val.code().to_string(),
+            |diff, path, old, new| {
+                diff.value(&format!("{}.label", path), old.label(), new.label());
+                diff.value(
+                    &format!("{}.definition", path),
+                    old.definition(),
+                    new.definition(),
+                );
+                diff.translations(
+                    &format!("{}.translation", path),
+                    old.translations(),
+                    new.translations(),
+                );
+            },
+        );
+    }
+
+    fn constraints(
+        &mut self,
+        old: Option<&AttributeConstraints>,
+        new: Option<&AttributeConstraints>,
+    ) {
+        self.optional(
+            "constraints.stringLength",
+            old.and_then(|val| val.string_length()),
+            new.and_then(|val| val.string_length()),
+        );
+        self.optional(
+            "constraints.textPattern",
+            old.and_then(|val| val.text_pattern()),
+            new.and_then(|val| val.text_pattern()),
+        );
+        self.optional(
+            "constraints.range",
+            old.and_then(|val| val.range()).map(|val| {
+                format!(
+                    "{} {}..{}",
+                    val.closure(),
+                    format_bound(val.lower_bound()),
+                    format_bound(val.upper_bound())
+                )
+            }),
+            new.and_then(|val| val.range()).map(|val| {
+                format!(
+                    "{} {}..{}",
+                    val.closure(),
+                    format_bound(val.lower_bound()),
+                    format_bound(val.upper_bound())
+                )
+            }),
+        );
+        self.optional(
+            "constraints.precision",
+            old.and_then(|val| val.precision()),
+            new.and_then(|val| val.precision()),
+        );
+    }
+
+    fn attribute_bindings(
+        &mut self,
+        property: &str,
+        old: &[AttributeBinding],
+        new: &[AttributeBinding],
+    ) {
+        self.keyed(
+            property,
+            old,
+            new,
+            |val| val.attribute().to_string(),
+            |diff, path, old, new| {
+                diff.value(
+                    &format!("{}.multiplicity", path),
+                    old.multiplicity().to_string(),
+                    new.multiplicity().to_string(),
+                );
+                diff.value(
+                    &format!("{}.sequential", path),
+                    old.sequential(),
+                    new.sequential(),
+                );
+                let old_values: Vec<String> = old
+                    .permitted_values()
+                    .iter()
+                    .map(|val| val.to_string())
+                    .collect();
+                let new_values: Vec<String> = new
+                    .permitted_values()
+                    .iter()
+                    .map(|val| val.to_string())
+                    .collect();
+                diff.list(
+                    &format!("{}.permittedValues", path),
+                    &old_values,
+                    &new_values,
+                );
+            },
+        );
+    }
+
+    fn information_bindings(&mut self, old: &[InformationBinding], new: &[InformationBinding]) {
+        self.keyed(
+            "informationBinding",
+            old,
+            new,
+            |val| {
+                format!(
+                    "{}/{}/{}",
+                    val.association(),
+                    val.role(),
+                    val.information_type()
+                )
+            },
+            |diff, path, old, new| {
+                diff.value(
+                    &format!("{}.multiplicity", path),
+                    old.multiplicity().to_string(),
+                    new.multiplicity().to_string(),
+                );
+                diff.value(
+                    &format!("{}.roleType", path),
+                    format!("{}", old.role_type()),
+                    format!("{}", new.role_type()),
+                );
+            },
+        );
+    }
+
+    fn feature_bindings(&mut self, old: &[FeatureBinding], new: &[FeatureBinding]) {
+        self.keyed(
+            "featureBinding",
+            old,
+            new,
+            |val| {
+                format!(
+                    "{}/{}/{}",
+                    val.association(),
+                    val.role(),
+                    val.feature_type()
+                )
+            },
+            |diff, path, old, new| {
+                diff.value(
+                    &format!("{}.multiplicity", path),
+                    old.multiplicity().to_string(),
+                    new.multiplicity().to_string(),
+                );
+                diff.value(
+                    &format!("{}.roleType", path),
+                    format!("{}", old.role_type()),
+                    format!("{}", new.role_type()),
+                );
+            },
+        );
+    }
+}
+
+fn format_bound(bound: Option<f64>) -> String {
+    match bound {
+        Some(val) => val.to_string(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,341 @@
+use std::{collections::HashSet, fmt};
+
+use super::{
+    feature_catalog::hierarchy, AttributeBinding, AttributeValueType, DefinitionReference,
+    FeatureBinding, FeatureCatalog, InformationBinding, Item, ItemKind, ListedValue,
+};
+use crate::{Result, S100Error};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValidationIssue {
+    item_kind: ItemKind,
+    code: String,
+    message: String,
+}
+
+impl ValidationIssue {
+    pub fn item_kind(&self) -> ItemKind {
+        self.item_kind
+    }
+
+    pub fn code(&self) -> &str {
+        self.code.as_str()
+    }
+
+    pub fn message(&self) -> &str {
+        self.message.as_str()
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?} '{}': {}", self.item_kind, self.code, self.message)
+    }
+}
+
+pub(super) fn validate(catalog: &FeatureCatalog) -> Vec<ValidationIssue> {
+    let mut validator = Validator {
+        catalog,
+        issues: Vec::new(),
+    };
+
+    validator.unique_codes(ItemKind::SimpleAttribute, catalog.simple_attributes());
+    validator.unique_codes(ItemKind::ComplexAttribute, catalog.complex_attributes());
+    validator.unique_codes(ItemKind::Role, catalog.roles());
+    validator.unique_codes(
+        ItemKind::InformationAssociation,
+        catalog.information_associations(),
+    );
+    validator.unique_codes(ItemKind::FeatureAssociation, catalog.feature_associations());
+    validator.unique_codes(ItemKind::InformationType, catalog.information_types());
+    validator.unique_codes(ItemKind::FeatureType, catalog.feature_types());
+
+    for val in catalog.simple_attributes() {
+        let item = (ItemKind::SimpleAttribute, val.code());
+        validator.definition_reference(item, val.definition_reference());
+        validator.listed_values(item, val.value_type(), val.listed_values());
+    }
+
+    for val in catalog.complex_attributes() {
+        let item = (ItemKind::ComplexAttribute, val.code());
+        validator.definition_reference(item, val.definition_reference());
+        validator.attribute_bindings(item, val.sub_attribute_bindings());
+    }
+
+    for val in catalog.roles() {
+        validator.definition_reference((ItemKind::Role, val.code()), val.definition_reference());
+    }
+
+    for val in catalog.information_associations() {
+        let item = (ItemKind::InformationAssociation, val.code());
+        validator.definition_reference(item, val.definition_reference());
+        validator.roles(item, &val.roles());
+        validator.type_hierarchy(item, val.super_type(), &val.sub_types(), |code| {
+            catalog.information_association(code).is_some()
+        });
+        validator.super_type_chain(
+            item,
+            hierarchy(
+                val.code(),
+                |code| catalog.information_association(code),
+                |val| val.super_type(),
+            ),
+        );
+    }
+
+    for val in catalog.feature_associations() {
+        let item = (ItemKind::FeatureAssociation, val.code());
+        validator.definition_reference(item, val.definition_reference());
+        validator.roles(item, &val.roles());
+        validator.type_hierarchy(item, val.super_type(), &val.sub_types(), |code| {
+            catalog.feature_association(code).is_some()
+        });
+        validator.super_type_chain(
+            item,
+            hierarchy(
+                val.code(),
+                |code| catalog.feature_association(code),
+                |val| val.super_type(),
+            ),
+        );
+    }
+
+    for val in catalog.information_types() {
+        let item = (ItemKind::InformationType, val.code());
+        validator.definition_reference(item, val.definition_reference());
+        validator.type_hierarchy(item, val.super_type(), &val.sub_types(), |code| {
+            catalog.information_type(code).is_some()
+        });
+        validator.super_type_chain(item, catalog.information_type_hierarchy(val.code()));
+        validator.attribute_bindings(item, val.attribute_bindings());
+        validator.information_bindings(item, val.information_bindings());
+    }
+
+    for val in catalog.feature_types() {
+        let item = (ItemKind::FeatureType, val.code());
+        validator.definition_reference(item, val.definition_reference());
+        validator.type_hierarchy(item, val.super_type(), &val.sub_types(), |code| {
+            catalog.feature_type(code).is_some()
+        });
+        validator.super_type_chain(item, catalog.feature_type_hierarchy(val.code()));
+        validator.attribute_bindings(item, val.attribute_bindings());
+        validator.information_bindings(item, val.information_bindings());
+        validator.feature_bindings(item, val.feature_bindings());
+    }
+
+    validator.issues
+}
+
+struct Validator<'a> {
+    catalog: &'a FeatureCatalog,
+    issues: Vec<ValidationIssue>,
+}
+
+impl<'a> Validator<'a> {
+    fn issue(&mut self, item: (ItemKind, &str), message: String) {
+        self.issues.push(ValidationIssue {
+            item_kind: item.0,
+            code: item.1.to_string(),
+            message,
+        });
+    }
+
+    fn unique_codes<T: Item>(&mut self, item_kind: ItemKind, items: &[T]) {
+        let mut codes: HashSet<&str> = HashSet::new();
+        for val in items {
+            if !codes.insert(val.code()) {
+                self.issue(
+                    (item_kind, val.code()),
+                    "is declared more than once".to_string(),
+                );
+            }
+        }
+    }
+
+    fn definition_reference(
+        &mut self,
+        item: (ItemKind, &str),
+        definition_reference: Option<&DefinitionReference>,
+    ) {
+        if let Some(val) = definition_reference {
+            if self
+                .catalog
+                .definition_source(val.definition_source())
+                .is_none()
+            {
+                self.issue(
+                    item,
+                    format!(
+                        "references an undeclared definition source '{}'",
+                        val.definition_source()
+                    ),
+                );
+            }
+        }
+    }
+
+    fn listed_values(
+        &mut self,
+        item: (ItemKind, &str),
+        value_type: AttributeValueType,
+        listed_values: &[ListedValue],
+    ) {
+        if value_type == AttributeValueType::Enumeration && listed_values.is_empty() {
+            self.issue(
+                item,
+                "is an enumeration without any listed values".to_string(),
+            );
+        }
+
+        let mut codes: HashSet<u64> = HashSet::new();
+        for val in listed_values {
+            if !codes.insert(val.code()) {
+                self.issue(
+                    item,
+                    format!("declares listed value code {} more than once", val.code()),
+                );
+            }
+            self.definition_reference(item, val.definition_reference());
+        }
+    }
+
+    fn roles(&mut self, item: (ItemKind, &str), roles: &[&str]) {
+        for role in roles {
+            if self.catalog.role(role).is_none() {
+                self.issue(item, format!("references an undefined role '{}'", role));
+            }
+        }
+    }
+
+    fn type_hierarchy<F>(
+        &mut self,
+        item: (ItemKind, &str),
+        super_type: Option<&str>,
+        sub_types: &[&str],
+        exists: F,
+    ) where
+        F: Fn(&str) -> bool,
+    {
+        if let Some(super_type) = super_type {
+            if !exists(super_type) {
+                self.issue(
+                    item,
+                    format!("references an undefined superType '{}'", super_type),
+                );
+            }
+        }
+        for sub_type in sub_types {
+            if !exists(sub_type) {
+                self.issue(
+                    item,
+                    format!("references an undefined subType '{}'", sub_type),
+                );
+            }
+        }
+    }
+
+    // Undefined superTypes are reported by type_hierarchy on the item that references them.
+    fn super_type_chain<T>(&mut self, item: (ItemKind, &str), chain: Result<Vec<T>>) {
+        if let Err(S100Error::CyclicSuperType { through, .. }) = chain {
+            self.issue(
+                item,
+                format!("has a cyclic superType chain through '{}'", through),
+            );
+        }
+    }
+
+    fn attribute_bindings(&mut self, item: (ItemKind, &str), bindings: &[AttributeBinding]) {
+        for binding in bindings {
+            let code = binding.attribute();
+            if let Some(attribute) = self.catalog.simple_attribute(code) {
+                for value in binding.permitted_values() {
+                    if !attribute
+                        .listed_values()
+                        .iter()
+                        .any(|val| val.code() == *value)
+                    {
+                        self.issue(
+                            item,
+                            format!(
+                                "permits value {} which is not a listed value of '{}'",
+                                value, code
+                            ),
+                        );
+                    }
+                }
+            } else if self.catalog.complex_attribute(code).is_none() {
+                self.issue(item, format!("binds an undefined attribute '{}'", code));
+            }
+        }
+    }
+
+    fn information_bindings(&mut self, item: (ItemKind, &str), bindings: &[InformationBinding]) {
+        for binding in bindings {
+            if self
+                .catalog
+                .information_association(binding.association())
+                .is_none()
+            {
+                self.issue(
+                    item,
+                    format!(
+                        "binds an undefined information association '{}'",
+                        binding.association()
+                    ),
+                );
+            }
+            if self.catalog.role(binding.role()).is_none() {
+                self.issue(
+                    item,
+                    format!("binds an undefined role '{}'", binding.role()),
+                );
+            }
+            if self
+                .catalog
+                .information_type(binding.information_type())
+                .is_none()
+            {
+                self.issue(
+                    item,
+                    format!(
+                        "binds an undefined information type '{}'",
+                        binding.information_type()
+                    ),
+                );
+            }
+        }
+    }
+
+    fn feature_bindings(&mut self, item: (ItemKind, &str), bindings: &[FeatureBinding]) {
+        for binding in bindings {
+            if self
+                .catalog
+                .feature_association(binding.association())
+                .is_none()
+            {
+                self.issue(
+                    item,
+                    format!(
+                        "binds an undefined feature association '{}'",
+                        binding.association()
+                    ),
+                );
+            }
+            if self.catalog.role(binding.role()).is_none() {
+                self.issue(
+                    item,
+                    format!("binds an undefined role '{}'", binding.role()),
+                );
+            }
+            if self.catalog.feature_type(binding.feature_type()).is_none() {
+                self.issue(
+                    item,
+                    format!(
+                        "binds an undefined feature type '{}'",
+                        binding.feature_type()
+                    ),
+                );
+            }
+        }
+    }
+}
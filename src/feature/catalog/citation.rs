@@ -0,0 +1,308 @@
+use libxml::tree::Node;
+
+use super::{
+    xml_writer::{add_element, add_text, Namespaces},
+    CITATION, CI_NAMESPACES,
+};
+use crate::{namespace::element_name, Result, S100Error};
+
+const TITLE: &str = "title";
+const ALTERNATE_TITLE: &str = "alternateTitle";
+const DATE: &str = "date";
+const EDITION: &str = "edition";
+const EDITION_DATE: &str = "editionDate";
+const CI_DATE: &str = "CI_Date";
+const DATE_TYPE: &str = "dateType";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Citation {
+    title: String,
+    alternate_titles: Vec<String>,
+    dates: Vec<CitationDate>,
+    edition: Option<String>,
+    edition_date: Option<String>,
+}
+
+impl Citation {
+    pub fn builder() -> CitationBuilder {
+        CitationBuilder {
+            citation: Citation {
+                title: String::new(),
+                alternate_titles: Vec::new(),
+                dates: Vec::new(),
+                edition: None,
+                edition_date: None,
+            },
+        }
+    }
+
+    // Accepts either the element typed as a citation, or one wrapping a CI_Citation.
+    pub(super) fn parse(node: Node) -> Result<Citation> {
+        let mut children = node.get_child_elements();
+        if children.len() == 1 && element_name(&children[0], CI_NAMESPACES) == CITATION {
+            return Citation::parse(children.remove(0));
+        }
+
+        let mut title: Option<String> = None;
+        let mut alternate_titles: Vec<String> = Vec::new();
+        let mut dates: Vec<CitationDate> = Vec::new();
+        let mut edition: Option<String> = None;
+        let mut edition_date: Option<String> = None;
+
+        for child_node in children {
+            match element_name(&child_node, CI_NAMESPACES).as_str() {
+                TITLE => title = Some(child_node.get_content().trim().to_string()),
+                ALTERNATE_TITLE => {
+                    alternate_titles.push(child_node.get_content().trim().to_string())
+                }
+                EDITION => edition = Some(child_node.get_content().trim().to_string()),
+                EDITION_DATE => edition_date = Some(child_node.get_content().trim().to_string()),
+                DATE => match CitationDate::parse(child_node) {
+                    Ok(val) => dates.push(val),
+                    Err(e) => return Err(e),
+                },
+                // The remaining CI_Citation properties are not used by feature catalogues.
+                _ => {}
+            };
+        }
+
+        if title.is_none() {
+            return S100Error::missing_child(node, TITLE);
+        }
+
+        Ok(Citation {
+            title: title.unwrap(),
+            alternate_titles,
+            dates,
+            edition,
+            edition_date,
+        })
+    }
+
+    pub(super) fn write(
+        &self,
+        parent: &mut Node,
+        namespaces: &Namespaces,
+        name: &str,
+    ) -> Result<()> {
+        let mut node = add_element(parent, &namespaces.fc, name)?;
+        add_text(&mut node, &namespaces.ci, TITLE, &self.title)?;
+        for val in &self.alternate_titles {
+            add_text(&mut node, &namespaces.ci, ALTERNATE_TITLE, val)?;
+        }
+        for val in &self.dates {
+            val.write(&mut node, namespaces)?;
+        }
+        if let Some(val) = &self.edition {
+            add_text(&mut node, &namespaces.ci, EDITION, val)?;
+        }
+        if let Some(val) = &self.edition_date {
+            add_text(&mut node, &namespaces.ci, EDITION_DATE, val)?;
+        }
+        Ok(())
+    }
+
+    pub fn title(&self) -> &str {
+        self.title.as_str()
+    }
+
+    pub fn alternate_titles(&self) -> Vec<&str> {
+        self.alternate_titles.iter().map(|s| s.as_str()).collect()
+    }
+
+    pub fn dates(&self) -> &[CitationDate] {
+        &self.dates
+    }
+
+    pub fn edition(&self) -> Option<&str> {
+        match self.edition.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    pub fn edition_date(&self) -> Option<&str> {
+        match self.edition_date.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CitationBuilder {
+    citation: Citation,
+}
+
+impl CitationBuilder {
+    pub fn title(mut self, title: &str) -> CitationBuilder {
+        self.citation.title = title.to_string();
+        self
+    }
+
+    pub fn alternate_title(mut self, alternate_title: &str) -> CitationBuilder {
+        self.citation
+            .alternate_titles
+            .push(alternate_title.to_string());
+        self
+    }
+
+    pub fn date(mut self, date: &str, date_type: &str) -> CitationBuilder {
+        self.citation.dates.push(CitationDate {
+            date: date.to_string(),
+            date_type: date_type.to_string(),
+        });
+        self
+    }
+
+    pub fn edition(mut self, edition: &str) -> CitationBuilder {
+        self.citation.edition = Some(edition.to_string());
+        self
+    }
+
+    pub fn edition_date(mut self, edition_date: &str) -> CitationBuilder {
+        self.citation.edition_date = Some(edition_date.to_string());
+        self
+    }
+
+    pub fn build(self) -> Result<Citation> {
+        if self.citation.title.is_empty() {
+            return S100Error::missing_field(CITATION, TITLE);
+        }
+        Ok(self.citation)
+    }
+}
+
+// A CI_Date, such as the publication or revision date of the cited resource.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CitationDate {
+    date: String,
+    date_type: String,
+}
+
+impl CitationDate {
+    fn parse(node: Node) -> Result<CitationDate> {
+        let mut children = node.get_child_elements();
+        if children.len() == 1 && element_name(&children[0], CI_NAMESPACES) == CI_DATE {
+            return CitationDate::parse(children.remove(0));
+        }
+
+        let mut date: Option<String> = None;
+        let mut date_type: Option<String> = None;
+
+        for child_node in children {
+            match element_name(&child_node, CI_NAMESPACES).as_str() {
+                DATE => date = Some(child_node.get_content().trim().to_string()),
+                DATE_TYPE => date_type = Some(parse_date_type(child_node)),
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        if date.is_none() {
+            return S100Error::missing_child(node, DATE);
+        }
+        if date_type.is_none() {
+            return S100Error::missing_child(node, DATE_TYPE);
+        }
+
+        Ok(CitationDate {
+            date: date.unwrap(),
+            date_type: date_type.unwrap(),
+        })
+    }
+
+    fn write(&self, parent: &mut Node, namespaces: &Namespaces) -> Result<()> {
+        let mut node = add_element(parent, &namespaces.ci, DATE)?;
+        add_text(&mut node, &namespaces.ci, DATE, &self.date)?;
+        add_text(&mut node, &namespaces.ci, DATE_TYPE, &self.date_type)?;
+        Ok(())
+    }
+
+    pub fn date(&self) -> &str {
+        self.date.as_str()
+    }
+
+    pub fn date_type(&self) -> &str {
+        self.date_type.as_str()
+    }
+}
+
+// The ISO 19115-3 encoding holds the code in a CI_DateTypeCode element, the S100CI one as text.
+fn parse_date_type(node: Node) -> String {
+    let content = node.get_content().trim().to_string();
+    if !content.is_empty() {
+        return content;
+    }
+    for child_node in node.get_child_elements() {
+        if let Some(val) = child_node.get_attribute("codeListValue") {
+            return val;
+        }
+    }
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use libxml::parser::Parser;
+
+    use super::Citation;
+
+    #[test]
+    fn deserialize() {
+        let xml = r#"
+            <S100FC:source xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100CI="http://www.iho.int/S100CI">
+                <S100CI:title>IHO Geospatial Information Registry</S100CI:title>
+                <S100CI:date>
+                    <S100CI:date>2018-04-01</S100CI:date>
+                    <S100CI:dateType>revision</S100CI:dateType>
+                </S100CI:date>
+                <S100CI:edition>4.0.0</S100CI:edition>
+            </S100FC:source>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = Citation::parse(node).unwrap();
+
+        assert_eq!(target.title(), "IHO Geospatial Information Registry");
+        assert_eq!(target.edition(), Some("4.0.0"));
+        assert_eq!(target.dates()[0].date(), "2018-04-01");
+        assert_eq!(target.dates()[0].date_type(), "revision");
+        assert_eq!(target.edition_date(), None);
+    }
+
+    #[test]
+    fn deserialize_wrapped() {
+        let xml = r#"
+            <S100FC:source xmlns:S100FC="http://www.iho.int/S100FC" xmlns:cit="http://standards.iso.org/iso/19115/-3/cit/1.0" xmlns:gco="http://standards.iso.org/iso/19115/-3/gco/1.0">
+                <cit:CI_Citation>
+                    <cit:title>
+                        <gco:CharacterString>IHO Dictionary, S-32</gco:CharacterString>
+                    </cit:title>
+                    <cit:alternateTitle>
+                        <gco:CharacterString>S-32</gco:CharacterString>
+                    </cit:alternateTitle>
+                    <cit:date>
+                        <cit:CI_Date>
+                            <cit:date>
+                                <gco:Date>2019-01-01</gco:Date>
+                            </cit:date>
+                            <cit:dateType>
+                                <cit:CI_DateTypeCode codeList="codeListLocation#CI_DateTypeCode" codeListValue="publication"/>
+                            </cit:dateType>
+                        </cit:CI_Date>
+                    </cit:date>
+                </cit:CI_Citation>
+            </S100FC:source>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = Citation::parse(node).unwrap();
+
+        assert_eq!(target.title(), "IHO Dictionary, S-32");
+        assert_eq!(target.alternate_titles(), vec!["S-32"]);
+        assert_eq!(target.dates()[0].date(), "2019-01-01");
+        assert_eq!(target.dates()[0].date_type(), "publication");
+    }
+}
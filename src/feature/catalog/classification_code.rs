@@ -0,0 +1,52 @@
+use std::{fmt, str::FromStr};
+
+use crate::S100Error;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ClassificationCode {
+    Unclassified,
+    Restricted,
+    Confidential,
+    Secret,
+    TopSecret,
+    SensitiveButUnclassified,
+    ForOfficialUseOnly,
+    Protected,
+    LimitedDistribution,
+}
+
+impl FromStr for ClassificationCode {
+    type Err = S100Error;
+
+    fn from_str(input: &str) -> Result<ClassificationCode, Self::Err> {
+        match input {
+            "unclassified" => Ok(ClassificationCode::Unclassified),
+            "restricted" => Ok(ClassificationCode::Restricted),
+            "confidential" => Ok(ClassificationCode::Confidential),
+            "secret" => Ok(ClassificationCode::Secret),
+            "topSecret" => Ok(ClassificationCode::TopSecret),
+            "sensitiveButUnclassified" => Ok(ClassificationCode::SensitiveButUnclassified),
+            "forOfficialUseOnly" => Ok(ClassificationCode::ForOfficialUseOnly),
+            "protected" => Ok(ClassificationCode::Protected),
+            "limitedDistribution" => Ok(ClassificationCode::LimitedDistribution),
+            _ => S100Error::invalid_enum("classification", input),
+        }
+    }
+}
+
+impl fmt::Display for ClassificationCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            ClassificationCode::Unclassified => "unclassified",
+            ClassificationCode::Restricted => "restricted",
+            ClassificationCode::Confidential => "confidential",
+            ClassificationCode::Secret => "secret",
+            ClassificationCode::TopSecret => "topSecret",
+            ClassificationCode::SensitiveButUnclassified => "sensitiveButUnclassified",
+            ClassificationCode::ForOfficialUseOnly => "forOfficialUseOnly",
+            ClassificationCode::Protected => "protected",
+            ClassificationCode::LimitedDistribution => "limitedDistribution",
+        };
+        write!(f, "{}", value)
+    }
+}
@@ -0,0 +1,31 @@
+use std::{fmt, str::FromStr};
+
+use crate::S100Error;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CodelistType {
+    OpenEnumeration,
+    Dictionary,
+}
+
+impl FromStr for CodelistType {
+    type Err = S100Error;
+
+    fn from_str(input: &str) -> Result<CodelistType, Self::Err> {
+        match input {
+            "openEnumeration" | "open enumeration" => Ok(CodelistType::OpenEnumeration),
+            "dictionary" => Ok(CodelistType::Dictionary),
+            _ => S100Error::invalid_enum("codelistType", input),
+        }
+    }
+}
+
+impl fmt::Display for CodelistType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            CodelistType::OpenEnumeration => "openEnumeration",
+            CodelistType::Dictionary => "dictionary",
+        };
+        write!(f, "{}", value)
+    }
+}
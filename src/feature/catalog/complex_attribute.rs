@@ -0,0 +1,315 @@
+use libxml::tree::Node;
+
+use super::{
+    attribute_binding::check_unique,
+    item::check_item,
+    xml_writer::{add_element, add_item, Namespaces},
+    AttributeBinding, DefinitionReference, Item, Translation, COMPLEX_ATTRIBUTE,
+    DEFINITION_REFERENCE, FC_NAMESPACES, SUB_ATTRIBUTE_BINDING, TRANSLATION,
+};
+use crate::{namespace::element_name, Result, S100Error};
+
+const NAME: &str = "name";
+const DEFINITION: &str = "definition";
+const CODE: &str = "code";
+const REMARKS: &str = "remarks";
+const ALIAS: &str = "alias";
+const LANGUAGE: &str = "language";
+
+#[derive(Clone, Debug)]
+pub struct ComplexAttribute {
+    name: String,
+    definition: String,
+    code: String,
+    remarks: Option<String>,
+    alias: Option<String>,
+    definition_reference: Option<DefinitionReference>,
+    language: Option<String>,
+    translations: Vec<Translation>,
+    sub_attribute_bindings: Vec<AttributeBinding>,
+}
+
+impl ComplexAttribute {
+    pub fn builder() -> ComplexAttributeBuilder {
+        ComplexAttributeBuilder {
+            name: String::new(),
+            definition: String::new(),
+            code: String::new(),
+            remarks: None,
+            alias: None,
+            definition_reference: None,
+            language: None,
+            translations: Vec::new(),
+            sub_attribute_bindings: Vec::new(),
+        }
+    }
+
+    pub(super) fn parse(node: Node) -> Result<ComplexAttribute> {
+        if element_name(&node, FC_NAMESPACES) != COMPLEX_ATTRIBUTE {
+            return S100Error::invalid_child(node);
+        }
+
+        let mut name: Option<String> = None;
+        let mut definition: Option<String> = None;
+        let mut code: Option<String> = None;
+        let mut remarks: Option<String> = None;
+        let mut alias: Option<String> = None;
+        let mut definition_reference: Option<DefinitionReference> = None;
+        let mut language: Option<String> = None;
+        let mut translations: Vec<Translation> = Vec::new();
+        let mut sub_attribute_bindings: Vec<AttributeBinding> = Vec::new();
+
+        for child_node in node.get_child_elements() {
+            match element_name(&child_node, FC_NAMESPACES).as_str() {
+                NAME => name = Some(child_node.get_content()),
+                DEFINITION => definition = Some(child_node.get_content()),
+                CODE => code = Some(child_node.get_content()),
+                REMARKS => remarks = Some(child_node.get_content()),
+                ALIAS => alias = Some(child_node.get_content()),
+                DEFINITION_REFERENCE => match DefinitionReference::parse(child_node) {
+                    Ok(val) => definition_reference = Some(val),
+                    Err(e) => return Err(e),
+                },
+                LANGUAGE => language = Some(child_node.get_content().trim().to_string()),
+                TRANSLATION => match Translation::parse(child_node) {
+                    Ok(val) => translations.push(val),
+                    Err(e) => return Err(e),
+                },
+                SUB_ATTRIBUTE_BINDING => match AttributeBinding::parse(child_node) {
+                    Ok(val) => sub_attribute_bindings.push(val),
+                    Err(e) => return Err(e),
+                },
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        if name.is_none() {
+            return S100Error::missing_child(node, NAME);
+        }
+        if definition.is_none() {
+            return S100Error::missing_child(node, DEFINITION);
+        }
+        if code.is_none() {
+            return S100Error::missing_child(node, CODE);
+        }
+
+        Ok(ComplexAttribute {
+            name: name.unwrap(),
+            definition: definition.unwrap(),
+            code: code.unwrap(),
+            remarks,
+            alias,
+            definition_reference,
+            language,
+            translations,
+            sub_attribute_bindings,
+        })
+    }
+
+    pub(super) fn write(&self, parent: &mut Node, namespaces: &Namespaces) -> Result<()> {
+        let mut node = add_element(parent, &namespaces.fc, COMPLEX_ATTRIBUTE)?;
+        add_item(
+            &mut node,
+            namespaces,
+            self,
+            self.remarks(),
+            self.definition_reference(),
+        )?;
+        for val in &self.sub_attribute_bindings {
+            val.write(&mut node, namespaces, SUB_ATTRIBUTE_BINDING)?;
+        }
+        Ok(())
+    }
+
+    pub fn remarks(&self) -> Option<&str> {
+        match self.remarks.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    pub fn definition_reference(&self) -> Option<&DefinitionReference> {
+        match &self.definition_reference {
+            Some(val) => Some(val),
+            None => None,
+        }
+    }
+
+    pub fn sub_attribute_bindings(&self) -> &[AttributeBinding] {
+        &self.sub_attribute_bindings
+    }
+}
+
+impl Item for ComplexAttribute {
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn definition(&self) -> &str {
+        self.definition.as_str()
+    }
+
+    fn code(&self) -> &str {
+        self.code.as_str()
+    }
+
+    fn alias(&self) -> Option<&str> {
+        match self.alias.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+    fn language(&self) -> Option<&str> {
+        match self.language.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    fn translations(&self) -> &[Translation] {
+        &self.translations
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ComplexAttributeBuilder {
+    name: String,
+    definition: String,
+    code: String,
+    remarks: Option<String>,
+    alias: Option<String>,
+    definition_reference: Option<DefinitionReference>,
+    language: Option<String>,
+    translations: Vec<Translation>,
+    sub_attribute_bindings: Vec<AttributeBinding>,
+}
+
+impl ComplexAttributeBuilder {
+    pub fn name(mut self, name: &str) -> ComplexAttributeBuilder {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn definition(mut self, definition: &str) -> ComplexAttributeBuilder {
+        self.definition = definition.to_string();
+        self
+    }
+
+    pub fn code(mut self, code: &str) -> ComplexAttributeBuilder {
+        self.code = code.to_string();
+        self
+    }
+
+    pub fn remarks(mut self, remarks: &str) -> ComplexAttributeBuilder {
+        self.remarks = Some(remarks.to_string());
+        self
+    }
+
+    pub fn alias(mut self, alias: &str) -> ComplexAttributeBuilder {
+        self.alias = Some(alias.to_string());
+        self
+    }
+
+    pub fn definition_reference(
+        mut self,
+        definition_reference: DefinitionReference,
+    ) -> ComplexAttributeBuilder {
+        self.definition_reference = Some(definition_reference);
+        self
+    }
+
+    pub fn language(mut self, language: &str) -> ComplexAttributeBuilder {
+        self.language = Some(language.to_string());
+        self
+    }
+
+    pub fn translation(mut self, translation: Translation) -> ComplexAttributeBuilder {
+        self.translations.push(translation);
+        self
+    }
+
+    pub fn sub_attribute_binding(
+        mut self,
+        sub_attribute_binding: AttributeBinding,
+    ) -> ComplexAttributeBuilder {
+        self.sub_attribute_bindings.push(sub_attribute_binding);
+        self
+    }
+
+    pub fn build(self) -> Result<ComplexAttribute> {
+        let item = ComplexAttribute {
+            name: self.name,
+            definition: self.definition,
+            code: self.code,
+            remarks: self.remarks,
+            alias: self.alias,
+            definition_reference: self.definition_reference,
+            language: self.language,
+            translations: self.translations,
+            sub_attribute_bindings: self.sub_attribute_bindings,
+        };
+        check_item(COMPLEX_ATTRIBUTE, &item)?;
+        if item.sub_attribute_bindings.is_empty() {
+            return S100Error::missing_field(COMPLEX_ATTRIBUTE, SUB_ATTRIBUTE_BINDING);
+        }
+        check_unique(COMPLEX_ATTRIBUTE, &item.sub_attribute_bindings)?;
+
+        Ok(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libxml::parser::Parser;
+
+    use super::{ComplexAttribute, Item};
+
+    #[test]
+    fn deserialize() {
+        let xml = r#"
+            <S100FC:S100_FC_ComplexAttribute xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+                <S100FC:name>Feature Name</S100FC:name>
+                <S100FC:definition>Provides the name of an entity, defines the national language of the name, and provides the option to display the name at various system display settings.</S100FC:definition>
+                <S100FC:code>featureName</S100FC:code>
+                <S100FC:subAttributeBinding sequential="false">
+                    <S100FC:multiplicity>
+                        <S100Base:lower>0</S100Base:lower>
+                        <S100Base:upper>1</S100Base:upper>
+                    </S100FC:multiplicity>
+                    <S100FC:attribute ref="language"/>
+                </S100FC:subAttributeBinding>
+                <S100FC:subAttributeBinding sequential="false">
+                    <S100FC:multiplicity>
+                        <S100Base:lower>1</S100Base:lower>
+                        <S100Base:upper>1</S100Base:upper>
+                    </S100FC:multiplicity>
+                    <S100FC:attribute ref="name"/>
+                </S100FC:subAttributeBinding>
+                <S100FC:subAttributeBinding sequential="false">
+                    <S100FC:multiplicity>
+                        <S100Base:lower>0</S100Base:lower>
+                        <S100Base:upper xsi:nil="true" infinite="true"/>
+                    </S100FC:multiplicity>
+                    <S100FC:attribute ref="nameUsage"/>
+                </S100FC:subAttributeBinding>
+            </S100FC:S100_FC_ComplexAttribute>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = ComplexAttribute::parse(node).unwrap();
+
+        assert_eq!(target.name(), "Feature Name");
+        assert_eq!(target.code(), "featureName");
+        assert_eq!(target.remarks(), None);
+        assert_eq!(target.alias(), None);
+
+        let bindings = target.sub_attribute_bindings();
+        assert_eq!(bindings.len(), 3);
+        assert_eq!(bindings[0].attribute(), "language");
+        assert_eq!(bindings[1].attribute(), "name");
+        assert_eq!(bindings[1].multiplicity().lower(), 1);
+        assert_eq!(bindings[2].attribute(), "nameUsage");
+        assert!(bindings[2].multiplicity().is_unbounded());
+    }
+}
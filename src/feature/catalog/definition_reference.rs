@@ -1,8 +1,11 @@
 use libxml::tree::Node;
 
-use crate::{Result, S100Error};
+use crate::{namespace::element_name, Result, S100Error};
 
-use super::{DEFINITION_REFERENCE, XML_REF};
+use super::{
+    xml_writer::{add_element, add_reference, add_text, Namespaces},
+    DEFINITION_REFERENCE, FC_NAMESPACES, XML_REF,
+};
 
 const SOURCE_IDENTIFIER: &str = "sourceIdentifier";
 const DEFINITION_SOURCE: &str = "definitionSource";
@@ -14,20 +17,29 @@ pub struct DefinitionReference {
 }
 
 impl DefinitionReference {
+    pub fn new(source_identifier: &str, definition_source: &str) -> Result<DefinitionReference> {
+        if source_identifier.is_empty() {
+            return S100Error::missing_field(DEFINITION_REFERENCE, SOURCE_IDENTIFIER);
+        }
+        if definition_source.is_empty() {
+            return S100Error::missing_field(DEFINITION_REFERENCE, DEFINITION_SOURCE);
+        }
+        Ok(DefinitionReference {
+            source_identifier: source_identifier.to_string(),
+            definition_source: definition_source.to_string(),
+        })
+    }
+
     pub(super) fn parse(node: Node) -> Result<DefinitionReference> {
-        let node_name = node.get_name();
-        if node_name != DEFINITION_REFERENCE {
-            return Err(S100Error::Parse(format!(
-                "'{}' received a node named '{}'",
-                DEFINITION_REFERENCE, node_name
-            )));
+        if element_name(&node, FC_NAMESPACES) != DEFINITION_REFERENCE {
+            return S100Error::invalid_child(node);
         }
 
         let mut source_identifier: Option<String> = None;
         let mut definition_source: Option<String> = None;
 
         for child_node in node.get_child_elements() {
-            match child_node.get_name().as_str() {
+            match element_name(&child_node, FC_NAMESPACES).as_str() {
                 SOURCE_IDENTIFIER => source_identifier = Some(child_node.get_content()),
                 DEFINITION_SOURCE => {
                     if let Some(val) = child_node.get_attribute(XML_REF) {
@@ -53,6 +65,22 @@ impl DefinitionReference {
         })
     }
 
+    pub(super) fn write(&self, parent: &mut Node, namespaces: &Namespaces) -> Result<()> {
+        let mut node = add_element(parent, &namespaces.fc, DEFINITION_REFERENCE)?;
+        add_text(
+            &mut node,
+            &namespaces.fc,
+            SOURCE_IDENTIFIER,
+            &self.source_identifier,
+        )?;
+        add_reference(
+            &mut node,
+            &namespaces.fc,
+            DEFINITION_SOURCE,
+            &self.definition_source,
+        )
+    }
+
     pub fn source_identifier(&self) -> &str {
         self.source_identifier.as_str()
     }
@@ -0,0 +1,101 @@
+use libxml::tree::Node;
+
+use super::{
+    xml_writer::{add_element, set_attribute, Namespaces},
+    Citation, DEFINITION_SOURCE, FC_NAMESPACES,
+};
+use crate::{namespace::element_name, Result, S100Error};
+
+const ID: &str = "id";
+const SOURCE: &str = "source";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DefinitionSource {
+    id: String,
+    source: Citation,
+}
+
+impl DefinitionSource {
+    pub fn new(id: &str, source: Citation) -> Result<DefinitionSource> {
+        if id.is_empty() {
+            return S100Error::missing_field(DEFINITION_SOURCE, ID);
+        }
+        Ok(DefinitionSource {
+            id: id.to_string(),
+            source,
+        })
+    }
+
+    pub(super) fn parse(node: Node) -> Result<DefinitionSource> {
+        if element_name(&node, FC_NAMESPACES) != DEFINITION_SOURCE {
+            return S100Error::invalid_child(node);
+        }
+
+        let id = match node.get_attribute(ID) {
+            Some(val) => val,
+            None => return S100Error::missing_attribute(node, ID),
+        };
+        let mut source: Option<Citation> = None;
+
+        for child_node in node.get_child_elements() {
+            match element_name(&child_node, FC_NAMESPACES).as_str() {
+                SOURCE => match Citation::parse(child_node) {
+                    Ok(val) => source = Some(val),
+                    Err(e) => return Err(e),
+                },
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        if source.is_none() {
+            return S100Error::missing_child(node, SOURCE);
+        }
+
+        Ok(DefinitionSource {
+            id,
+            source: source.unwrap(),
+        })
+    }
+
+    pub(super) fn write(&self, parent: &mut Node, namespaces: &Namespaces) -> Result<()> {
+        let mut node = add_element(parent, &namespaces.fc, DEFINITION_SOURCE)?;
+        set_attribute(&mut node, ID, &self.id)?;
+        self.source.write(&mut node, namespaces, SOURCE)
+    }
+
+    pub fn id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    pub fn source(&self) -> &Citation {
+        &self.source
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libxml::parser::Parser;
+
+    use super::DefinitionSource;
+
+    #[test]
+    fn deserialize() {
+        let xml = r#"
+            <S100FC:S100_FC_DefinitionSource id="IHOREG" xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100CI="http://www.iho.int/S100CI">
+                <S100FC:source>
+                    <S100CI:title>IHO Geospatial Information Registry</S100CI:title>
+                </S100FC:source>
+            </S100FC:S100_FC_DefinitionSource>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = DefinitionSource::parse(node).unwrap();
+
+        assert_eq!(target.id(), "IHOREG");
+        assert_eq!(
+            target.source().title(),
+            "IHO Geospatial Information Registry"
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use super::{AttributeBinding, FeatureBinding, InformationBinding};
+
+#[derive(Clone, Debug, Default)]
+pub struct EffectiveBindings {
+    attribute_bindings: Vec<AttributeBinding>,
+    feature_bindings: Vec<FeatureBinding>,
+    information_bindings: Vec<InformationBinding>,
+    // The code of the type that declared the binding at the same index.
+    attribute_origins: Vec<String>,
+    feature_origins: Vec<String>,
+    information_origins: Vec<String>,
+}
+
+impl EffectiveBindings {
+    // Bindings declared further down the hierarchy replace those inherited for the same target.
+    pub(super) fn merge(
+        &mut self,
+        declared_by: &str,
+        attribute_bindings: &[AttributeBinding],
+        feature_bindings: &[FeatureBinding],
+        information_bindings: &[InformationBinding],
+    ) {
+        for binding in attribute_bindings {
+            match self
+                .attribute_bindings
+                .iter()
+                .position(|val| val.attribute() == binding.attribute())
+            {
+                Some(index) => {
+                    self.attribute_bindings[index] = binding.clone();
+                    self.attribute_origins[index] = declared_by.to_string();
+                }
+                None => {
+                    self.attribute_bindings.push(binding.clone());
+                    self.attribute_origins.push(declared_by.to_string());
+                }
+            }
+        }
+
+        for binding in feature_bindings {
+            match self.feature_bindings.iter().position(|val| {
+                val.association() == binding.association()
+                    && val.role() == binding.role()
+                    && val.feature_type() == binding.feature_type()
+            }) {
+                Some(index) => {
+                    self.feature_bindings[index] = binding.clone();
+                    self.feature_origins[index] = declared_by.to_string();
+                }
+                None => {
+                    self.feature_bindings.push(binding.clone());
+                    self.feature_origins.push(declared_by.to_string());
+                }
+            }
+        }
+
+        for binding in information_bindings {
+            match self.information_bindings.iter().position(|val| {
+                val.association() == binding.association()
+                    && val.role() == binding.role()
+                    && val.information_type() == binding.information_type()
+            }) {
+                Some(index) => {
+                    self.information_bindings[index] = binding.clone();
+                    self.information_origins[index] = declared_by.to_string();
+                }
+                None => {
+                    self.information_bindings.push(binding.clone());
+                    self.information_origins.push(declared_by.to_string());
+                }
+            }
+        }
+    }
+
+    pub fn attribute_bindings(&self) -> &[AttributeBinding] {
+        &self.attribute_bindings
+    }
+
+    pub fn feature_bindings(&self) -> &[FeatureBinding] {
+        &self.feature_bindings
+    }
+
+    pub fn information_bindings(&self) -> &[InformationBinding] {
+        &self.information_bindings
+    }
+
+    // Each binding paired with the code of the type that declared it.
+    pub fn declared_attribute_bindings(&self) -> Vec<(&str, &AttributeBinding)> {
+        declared(&self.attribute_origins, &self.attribute_bindings)
+    }
+
+    pub fn declared_feature_bindings(&self) -> Vec<(&str, &FeatureBinding)> {
+        declared(&self.feature_origins, &self.feature_bindings)
+    }
+
+    pub fn declared_information_bindings(&self) -> Vec<(&str, &InformationBinding)> {
+        declared(&self.information_origins, &self.information_bindings)
+    }
+
+    pub fn attribute_binding(&self, code: &str) -> Option<&AttributeBinding> {
+        self.attribute_bindings
+            .iter()
+            .find(|binding| binding.attribute() == code)
+    }
+}
+
+fn declared<'a, T>(origins: &'a [String], bindings: &'a [T]) -> Vec<(&'a str, &'a T)> {
+    origins
+        .iter()
+        .map(|val| val.as_str())
+        .zip(bindings.iter())
+        .collect()
+}
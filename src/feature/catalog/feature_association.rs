@@ -0,0 +1,356 @@
+use libxml::tree::Node;
+
+use super::{
+    item::check_item,
+    xml_writer::{add_element, add_item, add_reference, add_text, set_attribute, Namespaces},
+    DefinitionReference, Item, Translation, DEFINITION_REFERENCE, FC_NAMESPACES,
+    FEATURE_ASSOCIATION, ROLE_REFERENCE, TRANSLATION, XML_REF,
+};
+use crate::{namespace::element_name, Result, S100Error};
+
+const IS_ABSTRACT: &str = "isAbstract";
+const NAME: &str = "name";
+const DEFINITION: &str = "definition";
+const CODE: &str = "code";
+const REMARKS: &str = "remarks";
+const ALIAS: &str = "alias";
+const LANGUAGE: &str = "language";
+const SUPER_TYPE: &str = "superType";
+const SUB_TYPE: &str = "subType";
+
+#[derive(Clone, Debug)]
+pub struct FeatureAssociation {
+    name: String,
+    definition: String,
+    code: String,
+    remarks: Option<String>,
+    alias: Option<String>,
+    definition_reference: Option<DefinitionReference>,
+    language: Option<String>,
+    translations: Vec<Translation>,
+    is_abstract: bool,
+    roles: Vec<String>,
+    super_type: Option<String>,
+    sub_types: Vec<String>,
+}
+
+impl FeatureAssociation {
+    pub fn builder() -> FeatureAssociationBuilder {
+        FeatureAssociationBuilder {
+            name: String::new(),
+            definition: String::new(),
+            code: String::new(),
+            remarks: None,
+            alias: None,
+            definition_reference: None,
+            language: None,
+            translations: Vec::new(),
+            is_abstract: false,
+            roles: Vec::new(),
+            super_type: None,
+            sub_types: Vec::new(),
+        }
+    }
+
+    pub(super) fn parse(node: Node) -> Result<FeatureAssociation> {
+        if element_name(&node, FC_NAMESPACES) != FEATURE_ASSOCIATION {
+            return S100Error::invalid_child(node);
+        }
+
+        let is_abstract = match node.get_attribute(IS_ABSTRACT) {
+            Some(val) => match val.as_str() {
+                "true" | "1" => true,
+                "false" | "0" => false,
+                _ => return S100Error::invalid_value(node),
+            },
+            None => false,
+        };
+        let mut name: Option<String> = None;
+        let mut definition: Option<String> = None;
+        let mut code: Option<String> = None;
+        let mut remarks: Option<String> = None;
+        let mut alias: Option<String> = None;
+        let mut definition_reference: Option<DefinitionReference> = None;
+        let mut language: Option<String> = None;
+        let mut translations: Vec<Translation> = Vec::new();
+        let mut roles: Vec<String> = Vec::new();
+        let mut super_type: Option<String> = None;
+        let mut sub_types: Vec<String> = Vec::new();
+
+        for child_node in node.get_child_elements() {
+            match element_name(&child_node, FC_NAMESPACES).as_str() {
+                NAME => name = Some(child_node.get_content()),
+                DEFINITION => definition = Some(child_node.get_content()),
+                CODE => code = Some(child_node.get_content()),
+                REMARKS => remarks = Some(child_node.get_content()),
+                ALIAS => alias = Some(child_node.get_content()),
+                DEFINITION_REFERENCE => match DefinitionReference::parse(child_node) {
+                    Ok(val) => definition_reference = Some(val),
+                    Err(e) => return Err(e),
+                },
+                LANGUAGE => language = Some(child_node.get_content().trim().to_string()),
+                TRANSLATION => match Translation::parse(child_node) {
+                    Ok(val) => translations.push(val),
+                    Err(e) => return Err(e),
+                },
+                ROLE_REFERENCE => match child_node.get_attribute(XML_REF) {
+                    Some(val) => roles.push(val),
+                    None => return S100Error::missing_attribute(child_node, XML_REF),
+                },
+                SUPER_TYPE => super_type = Some(child_node.get_content()),
+                SUB_TYPE => sub_types.push(child_node.get_content()),
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        if name.is_none() {
+            return S100Error::missing_child(node, NAME);
+        }
+        if definition.is_none() {
+            return S100Error::missing_child(node, DEFINITION);
+        }
+        if code.is_none() {
+            return S100Error::missing_child(node, CODE);
+        }
+
+        Ok(FeatureAssociation {
+            name: name.unwrap(),
+            definition: definition.unwrap(),
+            code: code.unwrap(),
+            remarks,
+            alias,
+            definition_reference,
+            language,
+            translations,
+            is_abstract,
+            roles,
+            super_type,
+            sub_types,
+        })
+    }
+
+    pub(super) fn write(&self, parent: &mut Node, namespaces: &Namespaces) -> Result<()> {
+        let mut node = add_element(parent, &namespaces.fc, FEATURE_ASSOCIATION)?;
+        set_attribute(&mut node, IS_ABSTRACT, &self.is_abstract.to_string())?;
+        add_item(
+            &mut node,
+            namespaces,
+            self,
+            self.remarks(),
+            self.definition_reference(),
+        )?;
+        if let Some(val) = &self.super_type {
+            add_text(&mut node, &namespaces.fc, SUPER_TYPE, val)?;
+        }
+        for val in &self.sub_types {
+            add_text(&mut node, &namespaces.fc, SUB_TYPE, val)?;
+        }
+        for val in &self.roles {
+            add_reference(&mut node, &namespaces.fc, ROLE_REFERENCE, val)?;
+        }
+        Ok(())
+    }
+
+    pub fn remarks(&self) -> Option<&str> {
+        match self.remarks.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    pub fn definition_reference(&self) -> Option<&DefinitionReference> {
+        match &self.definition_reference {
+            Some(val) => Some(val),
+            None => None,
+        }
+    }
+
+    pub fn is_abstract(&self) -> bool {
+        self.is_abstract
+    }
+
+    pub fn roles(&self) -> Vec<&str> {
+        self.roles.iter().map(|s| s.as_str()).collect()
+    }
+
+    pub fn super_type(&self) -> Option<&str> {
+        match self.super_type.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    pub fn sub_types(&self) -> Vec<&str> {
+        self.sub_types.iter().map(|s| s.as_str()).collect()
+    }
+}
+
+impl Item for FeatureAssociation {
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn definition(&self) -> &str {
+        self.definition.as_str()
+    }
+
+    fn code(&self) -> &str {
+        self.code.as_str()
+    }
+
+    fn alias(&self) -> Option<&str> {
+        match self.alias.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+    fn language(&self) -> Option<&str> {
+        match self.language.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    fn translations(&self) -> &[Translation] {
+        &self.translations
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FeatureAssociationBuilder {
+    name: String,
+    definition: String,
+    code: String,
+    remarks: Option<String>,
+    alias: Option<String>,
+    definition_reference: Option<DefinitionReference>,
+    language: Option<String>,
+    translations: Vec<Translation>,
+    is_abstract: bool,
+    roles: Vec<String>,
+    super_type: Option<String>,
+    sub_types: Vec<String>,
+}
+
+impl FeatureAssociationBuilder {
+    pub fn name(mut self, name: &str) -> FeatureAssociationBuilder {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn definition(mut self, definition: &str) -> FeatureAssociationBuilder {
+        self.definition = definition.to_string();
+        self
+    }
+
+    pub fn code(mut self, code: &str) -> FeatureAssociationBuilder {
+        self.code = code.to_string();
+        self
+    }
+
+    pub fn remarks(mut self, remarks: &str) -> FeatureAssociationBuilder {
+        self.remarks = Some(remarks.to_string());
+        self
+    }
+
+    pub fn alias(mut self, alias: &str) -> FeatureAssociationBuilder {
+        self.alias = Some(alias.to_string());
+        self
+    }
+
+    pub fn definition_reference(
+        mut self,
+        definition_reference: DefinitionReference,
+    ) -> FeatureAssociationBuilder {
+        self.definition_reference = Some(definition_reference);
+        self
+    }
+
+    pub fn language(mut self, language: &str) -> FeatureAssociationBuilder {
+        self.language = Some(language.to_string());
+        self
+    }
+
+    pub fn translation(mut self, translation: Translation) -> FeatureAssociationBuilder {
+        self.translations.push(translation);
+        self
+    }
+
+    pub fn is_abstract(mut self, is_abstract: bool) -> FeatureAssociationBuilder {
+        self.is_abstract = is_abstract;
+        self
+    }
+
+    pub fn role(mut self, role: &str) -> FeatureAssociationBuilder {
+        self.roles.push(role.to_string());
+        self
+    }
+
+    pub fn super_type(mut self, super_type: &str) -> FeatureAssociationBuilder {
+        self.super_type = Some(super_type.to_string());
+        self
+    }
+
+    pub fn sub_type(mut self, sub_type: &str) -> FeatureAssociationBuilder {
+        self.sub_types.push(sub_type.to_string());
+        self
+    }
+
+    pub fn build(self) -> Result<FeatureAssociation> {
+        let item = FeatureAssociation {
+            name: self.name,
+            definition: self.definition,
+            code: self.code,
+            remarks: self.remarks,
+            alias: self.alias,
+            definition_reference: self.definition_reference,
+            language: self.language,
+            translations: self.translations,
+            is_abstract: self.is_abstract,
+            roles: self.roles,
+            super_type: self.super_type,
+            sub_types: self.sub_types,
+        };
+        check_item(FEATURE_ASSOCIATION, &item)?;
+        // an association is made between one or two roles
+        if item.roles.is_empty() || item.roles.len() > 2 {
+            return S100Error::invalid_field(
+                FEATURE_ASSOCIATION,
+                ROLE_REFERENCE,
+                &format!("expected one or two roles, found {}", item.roles.len()),
+            );
+        }
+
+        Ok(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libxml::parser::Parser;
+
+    use super::{FeatureAssociation, Item};
+
+    #[test]
+    fn deserialize() {
+        let xml = r#"
+            <S100FC:S100_FC_FeatureAssociation isAbstract="false" xmlns:S100FC="http://www.iho.int/S100FC">
+                <S100FC:name>Structure/Equipment</S100FC:name>
+                <S100FC:definition>The relationship between a structure and the equipment mounted on it.</S100FC:definition>
+                <S100FC:code>StructureEquipment</S100FC:code>
+                <S100FC:role ref="theStructure"/>
+                <S100FC:role ref="theEquipment"/>
+            </S100FC:S100_FC_FeatureAssociation>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = FeatureAssociation::parse(node).unwrap();
+
+        assert_eq!(target.name(), "Structure/Equipment");
+        assert_eq!(target.code(), "StructureEquipment");
+        assert!(!target.is_abstract());
+        assert_eq!(target.roles(), vec!["theStructure", "theEquipment"]);
+        assert_eq!(target.super_type(), None);
+    }
+}
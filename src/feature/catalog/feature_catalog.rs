@@ -1,83 +1,837 @@
-use libxml::parser::Parser;
-use std::path::Path;
+use libxml::{
+    parser::Parser,
+    tree::{Document, Node},
+};
+use std::{fs, io::Read, path::Path, str::FromStr};
 
-use super::{SimpleAttribute, FEATURE_CATALOG};
-use crate::{Result, S100Error};
+use super::{
+    catalog_diff, catalog_validation,
+    xml_writer::{add_element, add_text, Namespaces},
+    Change, ClassificationCode, ComplexAttribute, DefinitionSource, EffectiveBindings,
+    FeatureAssociation, FeatureType, InformationAssociation, InformationType, Item, ItemIndex,
+    Responsibility, Role, SimpleAttribute, ValidationIssue, FC_NAMESPACES, FEATURE_CATALOG,
+};
+use crate::{
+    diagnostics::Diagnostics,
+    namespace::{check_namespace, element_name, Namespace},
+    ParseMode, Result, S100Error, Schema,
+};
 
+const NAME: &str = "name";
+const SCOPE: &str = "scope";
+const FIELD_OF_APPLICATION: &str = "fieldOfApplication";
+const VERSION_NUMBER: &str = "versionNumber";
+const VERSION_DATE: &str = "versionDate";
+const PRODUCER: &str = "producer";
+const CLASSIFICATION: &str = "classification";
+const DEFINITION_SOURCES: &str = "S100_FC_DefinitionSources";
 const SIMPLE_ATTRIBUTES: &str = "S100_FC_SimpleAttributes";
+const COMPLEX_ATTRIBUTES: &str = "S100_FC_ComplexAttributes";
+const ROLES: &str = "S100_FC_Roles";
+const INFORMATION_ASSOCIATIONS: &str = "S100_FC_InformationAssociations";
+const FEATURE_ASSOCIATIONS: &str = "S100_FC_FeatureAssociations";
+const INFORMATION_TYPES: &str = "S100_FC_InformationTypes";
+const FEATURE_TYPES: &str = "S100_FC_FeatureTypes";
 
 #[derive(Clone, Debug)]
 pub struct FeatureCatalog {
+    name: Option<String>,
+    scopes: Vec<String>,
+    fields_of_application: Vec<String>,
+    version_number: Option<String>,
+    version_date: Option<String>,
+    producer: Option<Responsibility>,
+    classification: Option<ClassificationCode>,
+    definition_sources: Vec<DefinitionSource>,
     simple_attributes: Vec<SimpleAttribute>,
+    complex_attributes: Vec<ComplexAttribute>,
+    roles: Vec<Role>,
+    information_associations: Vec<InformationAssociation>,
+    feature_associations: Vec<FeatureAssociation>,
+    information_types: Vec<InformationType>,
+    feature_types: Vec<FeatureType>,
+    simple_attribute_index: ItemIndex,
+    complex_attribute_index: ItemIndex,
+    role_index: ItemIndex,
+    information_association_index: ItemIndex,
+    feature_association_index: ItemIndex,
+    information_type_index: ItemIndex,
+    feature_type_index: ItemIndex,
 }
 
 impl FeatureCatalog {
     pub fn open<P: AsRef<Path>>(path: P) -> Result<FeatureCatalog> {
+        let xml = fs::read(&path)?;
+        FeatureCatalog::from_bytes(&xml).map_err(|e| e.with_path(path.as_ref()))
+    }
+
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<FeatureCatalog> {
+        let mut xml = Vec::new();
+        reader.read_to_end(&mut xml)?;
+        FeatureCatalog::from_bytes(&xml)
+    }
+
+    pub fn from_bytes(xml: &[u8]) -> Result<FeatureCatalog> {
+        let parser = Parser::default();
+        let document = parser.parse_string(xml)?;
+        FeatureCatalog::from_document(&document, &mut Diagnostics::new(ParseMode::Strict))
+    }
+
+    pub fn from_bytes_with_mode(
+        xml: &[u8],
+        mode: ParseMode,
+    ) -> Result<(FeatureCatalog, Vec<S100Error>)> {
+        let parser = Parser::default();
+        let document = parser.parse_string(xml)?;
+        let mut diagnostics = Diagnostics::new(mode);
+        let catalog = FeatureCatalog::from_document(&document, &mut diagnostics)?;
+        Ok((catalog, diagnostics.into_warnings()))
+    }
+
+    pub fn open_validated<P: AsRef<Path>>(path: P, schema: &Schema) -> Result<FeatureCatalog> {
+        let xml = fs::read(&path)?;
         let parser = Parser::default();
-        let filename_result = path.as_ref().to_str();
-        match filename_result {
-            Some(filename) => {
-                let document = parser.parse_file(filename)?;
-
-                if let Some(root) = document.get_root_element() {
-                    let mut feature_catalog = FeatureCatalog {
-                        simple_attributes: Vec::new(),
-                    };
-
-                    let root_name = root.get_name();
-                    if root_name == FEATURE_CATALOG {
-                        for node in root.get_child_elements() {
-                            match node.get_name().as_str() {
-                                SIMPLE_ATTRIBUTES => {
-                                    for simple_attribute_node in node.get_child_elements() {
-                                        match SimpleAttribute::parse(simple_attribute_node) {
-                                            Ok(simple_attribute) => feature_catalog
-                                                .simple_attributes
-                                                .push(simple_attribute),
-                                            Err(e) => return Err(e),
-                                        }
+        let document = parser.parse_string(&xml)?;
+        schema.check(&document)?;
+        FeatureCatalog::from_document(&document, &mut Diagnostics::new(ParseMode::Strict))
+            .map_err(|e| e.with_path(path.as_ref()))
+    }
+
+    pub fn open_with_mode<P: AsRef<Path>>(
+        path: P,
+        mode: ParseMode,
+    ) -> Result<(FeatureCatalog, Vec<S100Error>)> {
+        let xml = fs::read(&path)?;
+        let (catalog, warnings) = FeatureCatalog::from_bytes_with_mode(&xml, mode)
+            .map_err(|e| e.with_path(path.as_ref()))?;
+        let warnings = warnings
+            .into_iter()
+            .map(|val| val.with_path(path.as_ref()))
+            .collect();
+        Ok((catalog, warnings))
+    }
+
+    fn from_document(document: &Document, diagnostics: &mut Diagnostics) -> Result<FeatureCatalog> {
+        if let Some(root) = document.get_root_element() {
+            let mut feature_catalog = FeatureCatalog::empty();
+
+            let root_name = root.get_name();
+            if root_name == FEATURE_CATALOG {
+                check_namespace(&root, Namespace::FeatureCatalogue)?;
+
+                for node in root.get_child_elements() {
+                    match element_name(&node, FC_NAMESPACES).as_str() {
+                        NAME => feature_catalog.name = Some(node.get_content()),
+                        SCOPE => feature_catalog.scopes.push(node.get_content()),
+                        FIELD_OF_APPLICATION => feature_catalog
+                            .fields_of_application
+                            .push(node.get_content()),
+                        VERSION_NUMBER => feature_catalog.version_number = Some(node.get_content()),
+                        VERSION_DATE => feature_catalog.version_date = Some(node.get_content()),
+                        PRODUCER => match Responsibility::parse(node) {
+                            Ok(producer) => feature_catalog.producer = Some(producer),
+                            Err(e) => diagnostics.report(e)?,
+                        },
+                        CLASSIFICATION => {
+                            match ClassificationCode::from_str(node.get_content().trim()) {
+                                Ok(classification) => {
+                                    feature_catalog.classification = Some(classification)
+                                }
+                                Err(e) => diagnostics.report(e.at(&node))?,
+                            }
+                        }
+                        DEFINITION_SOURCES => {
+                            for definition_source_node in node.get_child_elements() {
+                                match DefinitionSource::parse(definition_source_node) {
+                                    Ok(definition_source) => {
+                                        feature_catalog.definition_sources.push(definition_source)
                                     }
+                                    Err(e) => diagnostics.report(e)?,
                                 }
-                                "" => {}
-                                _ => {
-                                    //TODO: return error if we find unrecognized element
+                            }
+                        }
+                        SIMPLE_ATTRIBUTES => {
+                            for simple_attribute_node in node.get_child_elements() {
+                                match SimpleAttribute::parse(simple_attribute_node, diagnostics) {
+                                    Ok(simple_attribute) => {
+                                        feature_catalog.simple_attributes.push(simple_attribute)
+                                    }
+                                    Err(e) => diagnostics.report(e)?,
                                 }
                             }
                         }
-
-                        Ok(feature_catalog)
-                    } else {
-                        Err(S100Error::Parse(format!(
-                            "Root node is not '{}', found '{} instead",
-                            FEATURE_CATALOG, root_name,
-                        )))
+                        COMPLEX_ATTRIBUTES => {
+                            for complex_attribute_node in node.get_child_elements() {
+                                match ComplexAttribute::parse(complex_attribute_node) {
+                                    Ok(complex_attribute) => {
+                                        feature_catalog.complex_attributes.push(complex_attribute)
+                                    }
+                                    Err(e) => diagnostics.report(e)?,
+                                }
+                            }
+                        }
+                        ROLES => {
+                            for role_node in node.get_child_elements() {
+                                match Role::parse(role_node) {
+                                    Ok(role) => feature_catalog.roles.push(role),
+                                    Err(e) => diagnostics.report(e)?,
+                                }
+                            }
+                        }
+                        INFORMATION_ASSOCIATIONS => {
+                            for association_node in node.get_child_elements() {
+                                match InformationAssociation::parse(association_node) {
+                                    Ok(association) => {
+                                        feature_catalog.information_associations.push(association)
+                                    }
+                                    Err(e) => diagnostics.report(e)?,
+                                }
+                            }
+                        }
+                        FEATURE_ASSOCIATIONS => {
+                            for association_node in node.get_child_elements() {
+                                match FeatureAssociation::parse(association_node) {
+                                    Ok(association) => {
+                                        feature_catalog.feature_associations.push(association)
+                                    }
+                                    Err(e) => diagnostics.report(e)?,
+                                }
+                            }
+                        }
+                        INFORMATION_TYPES => {
+                            for information_type_node in node.get_child_elements() {
+                                match InformationType::parse(information_type_node) {
+                                    Ok(information_type) => {
+                                        feature_catalog.information_types.push(information_type)
+                                    }
+                                    Err(e) => diagnostics.report(e)?,
+                                }
+                            }
+                        }
+                        FEATURE_TYPES => {
+                            for feature_type_node in node.get_child_elements() {
+                                match FeatureType::parse(feature_type_node) {
+                                    Ok(feature_type) => {
+                                        feature_catalog.feature_types.push(feature_type)
+                                    }
+                                    Err(e) => diagnostics.report(e)?,
+                                }
+                            }
+                        }
+                        "" => {}
+                        _ => diagnostics.unexpected(&node),
                     }
-                } else {
-                    Err(S100Error::Parse("Root node does not exist".to_string()))
                 }
+
+                feature_catalog.build_indices();
+                Ok(feature_catalog)
+            } else {
+                S100Error::invalid_child(root)
             }
-            None => Err(S100Error::Parse("path is empty".to_string())),
+        } else {
+            S100Error::missing_root(FEATURE_CATALOG)
+        }
+    }
+
+    fn empty() -> FeatureCatalog {
+        FeatureCatalog {
+            name: None,
+            scopes: Vec::new(),
+            fields_of_application: Vec::new(),
+            version_number: None,
+            version_date: None,
+            producer: None,
+            classification: None,
+            definition_sources: Vec::new(),
+            simple_attributes: Vec::new(),
+            complex_attributes: Vec::new(),
+            roles: Vec::new(),
+            information_associations: Vec::new(),
+            feature_associations: Vec::new(),
+            information_types: Vec::new(),
+            feature_types: Vec::new(),
+            simple_attribute_index: ItemIndex::default(),
+            complex_attribute_index: ItemIndex::default(),
+            role_index: ItemIndex::default(),
+            information_association_index: ItemIndex::default(),
+            feature_association_index: ItemIndex::default(),
+            information_type_index: ItemIndex::default(),
+            feature_type_index: ItemIndex::default(),
+        }
+    }
+
+    pub fn builder() -> FeatureCatalogBuilder {
+        FeatureCatalogBuilder {
+            catalog: FeatureCatalog::empty(),
+        }
+    }
+
+    fn build_indices(&mut self) {
+        self.simple_attribute_index = ItemIndex::new(&self.simple_attributes);
+        self.complex_attribute_index = ItemIndex::new(&self.complex_attributes);
+        self.role_index = ItemIndex::new(&self.roles);
+        self.information_association_index = ItemIndex::new(&self.information_associations);
+        self.feature_association_index = ItemIndex::new(&self.feature_associations);
+        self.information_type_index = ItemIndex::new(&self.information_types);
+        self.feature_type_index = ItemIndex::new(&self.feature_types);
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        match self.name.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
         }
     }
 
+    pub fn scopes(&self) -> Vec<&str> {
+        self.scopes.iter().map(|s| s.as_str()).collect()
+    }
+
+    pub fn fields_of_application(&self) -> Vec<&str> {
+        self.fields_of_application
+            .iter()
+            .map(|s| s.as_str())
+            .collect()
+    }
+
+    pub fn version_number(&self) -> Option<&str> {
+        match self.version_number.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    pub fn version_date(&self) -> Option<&str> {
+        match self.version_date.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    pub fn producer(&self) -> Option<&Responsibility> {
+        self.producer.as_ref()
+    }
+
+    pub fn classification(&self) -> Option<ClassificationCode> {
+        self.classification
+    }
+
+    pub fn definition_sources(&self) -> &[DefinitionSource] {
+        &self.definition_sources
+    }
+
+    pub fn definition_source(&self, id: &str) -> Option<&DefinitionSource> {
+        self.definition_sources.iter().find(|val| val.id() == id)
+    }
+
     pub fn simple_attributes(&self) -> &[SimpleAttribute] {
         &self.simple_attributes
     }
+
+    pub fn simple_attribute(&self, code: &str) -> Option<&SimpleAttribute> {
+        self.simple_attribute_index
+            .by_code(&self.simple_attributes, code)
+    }
+
+    pub fn simple_attribute_by_alias(&self, alias: &str) -> Option<&SimpleAttribute> {
+        self.simple_attribute_index
+            .by_alias(&self.simple_attributes, alias)
+    }
+
+    pub fn simple_attribute_by_name(&self, name: &str) -> Option<&SimpleAttribute> {
+        self.simple_attribute_index
+            .by_name(&self.simple_attributes, name)
+    }
+
+    pub fn complex_attributes(&self) -> &[ComplexAttribute] {
+        &self.complex_attributes
+    }
+
+    pub fn complex_attribute(&self, code: &str) -> Option<&ComplexAttribute> {
+        self.complex_attribute_index
+            .by_code(&self.complex_attributes, code)
+    }
+
+    pub fn complex_attribute_by_alias(&self, alias: &str) -> Option<&ComplexAttribute> {
+        self.complex_attribute_index
+            .by_alias(&self.complex_attributes, alias)
+    }
+
+    pub fn complex_attribute_by_name(&self, name: &str) -> Option<&ComplexAttribute> {
+        self.complex_attribute_index
+            .by_name(&self.complex_attributes, name)
+    }
+
+    pub fn roles(&self) -> &[Role] {
+        &self.roles
+    }
+
+    pub fn role(&self, code: &str) -> Option<&Role> {
+        self.role_index.by_code(&self.roles, code)
+    }
+
+    pub fn role_by_alias(&self, alias: &str) -> Option<&Role> {
+        self.role_index.by_alias(&self.roles, alias)
+    }
+
+    pub fn role_by_name(&self, name: &str) -> Option<&Role> {
+        self.role_index.by_name(&self.roles, name)
+    }
+
+    pub fn information_associations(&self) -> &[InformationAssociation] {
+        &self.information_associations
+    }
+
+    pub fn information_association(&self, code: &str) -> Option<&InformationAssociation> {
+        self.information_association_index
+            .by_code(&self.information_associations, code)
+    }
+
+    pub fn information_association_by_alias(&self, alias: &str) -> Option<&InformationAssociation> {
+        self.information_association_index
+            .by_alias(&self.information_associations, alias)
+    }
+
+    pub fn information_association_by_name(&self, name: &str) -> Option<&InformationAssociation> {
+        self.information_association_index
+            .by_name(&self.information_associations, name)
+    }
+
+    pub fn feature_associations(&self) -> &[FeatureAssociation] {
+        &self.feature_associations
+    }
+
+    pub fn feature_association(&self, code: &str) -> Option<&FeatureAssociation> {
+        self.feature_association_index
+            .by_code(&self.feature_associations, code)
+    }
+
+    pub fn feature_association_by_alias(&self, alias: &str) -> Option<&FeatureAssociation> {
+        self.feature_association_index
+            .by_alias(&self.feature_associations, alias)
+    }
+
+    pub fn feature_association_by_name(&self, name: &str) -> Option<&FeatureAssociation> {
+        self.feature_association_index
+            .by_name(&self.feature_associations, name)
+    }
+
+    pub fn information_types(&self) -> &[InformationType] {
+        &self.information_types
+    }
+
+    pub fn information_type(&self, code: &str) -> Option<&InformationType> {
+        self.information_type_index
+            .by_code(&self.information_types, code)
+    }
+
+    pub fn information_type_by_alias(&self, alias: &str) -> Option<&InformationType> {
+        self.information_type_index
+            .by_alias(&self.information_types, alias)
+    }
+
+    pub fn information_type_by_name(&self, name: &str) -> Option<&InformationType> {
+        self.information_type_index
+            .by_name(&self.information_types, name)
+    }
+
+    pub fn feature_types(&self) -> &[FeatureType] {
+        &self.feature_types
+    }
+
+    pub fn feature_type(&self, code: &str) -> Option<&FeatureType> {
+        self.feature_type_index.by_code(&self.feature_types, code)
+    }
+
+    pub fn feature_type_by_alias(&self, alias: &str) -> Option<&FeatureType> {
+        self.feature_type_index.by_alias(&self.feature_types, alias)
+    }
+
+    pub fn feature_type_by_name(&self, name: &str) -> Option<&FeatureType> {
+        self.feature_type_index.by_name(&self.feature_types, name)
+    }
+
+    // Compares this catalogue against a later edition.
+    pub fn diff(&self, other: &FeatureCatalog) -> Vec<Change> {
+        catalog_diff::diff(self, other)
+    }
+
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        catalog_validation::validate(self)
+    }
+
+    pub fn to_xml(&self) -> Result<String> {
+        let mut document = match Document::new() {
+            Ok(val) => val,
+            Err(_) => {
+                return Err(S100Error::Write {
+                    name: FEATURE_CATALOG.to_string(),
+                    reason: "unable to create a document".to_string(),
+                })
+            }
+        };
+        let mut root = match Node::new(FEATURE_CATALOG, None, &document) {
+            Ok(val) => val,
+            Err(_) => {
+                return Err(S100Error::Write {
+                    name: FEATURE_CATALOG.to_string(),
+                    reason: "unable to create the root node".to_string(),
+                })
+            }
+        };
+        document.set_root_element(&root);
+        let namespaces = Namespaces::declare(&mut root)?;
+        let fc = &namespaces.fc;
+
+        if let Some(val) = &self.name {
+            add_text(&mut root, fc, NAME, val)?;
+        }
+        for val in &self.scopes {
+            add_text(&mut root, fc, SCOPE, val)?;
+        }
+        for val in &self.fields_of_application {
+            add_text(&mut root, fc, FIELD_OF_APPLICATION, val)?;
+        }
+        if let Some(val) = &self.version_number {
+            add_text(&mut root, fc, VERSION_NUMBER, val)?;
+        }
+        if let Some(val) = &self.version_date {
+            add_text(&mut root, fc, VERSION_DATE, val)?;
+        }
+        if let Some(val) = &self.producer {
+            val.write(&mut root, &namespaces, PRODUCER)?;
+        }
+        if let Some(val) = self.classification {
+            add_text(&mut root, fc, CLASSIFICATION, &val.to_string())?;
+        }
+
+        // Each S100_FC_* container must hold at least one item, so empty ones are left out.
+        if !self.definition_sources.is_empty() {
+            let mut node = add_element(&mut root, fc, DEFINITION_SOURCES)?;
+            for val in &self.definition_sources {
+                val.write(&mut node, &namespaces)?;
+            }
+        }
+        if !self.simple_attributes.is_empty() {
+            let mut node = add_element(&mut root, fc, SIMPLE_ATTRIBUTES)?;
+            for val in &self.simple_attributes {
+                val.write(&mut node, &namespaces)?;
+            }
+        }
+        if !self.complex_attributes.is_empty() {
+            let mut node = add_element(&mut root, fc, COMPLEX_ATTRIBUTES)?;
+            for val in &self.complex_attributes {
+                val.write(&mut node, &namespaces)?;
+            }
+        }
+        if !self.roles.is_empty() {
+            let mut node = add_element(&mut root, fc, ROLES)?;
+            for val in &self.roles {
+                val.write(&mut node, &namespaces)?;
+            }
+        }
+        if !self.information_associations.is_empty() {
+            let mut node = add_element(&mut root, fc, INFORMATION_ASSOCIATIONS)?;
+            for val in &self.information_associations {
+                val.write(&mut node, &namespaces)?;
+            }
+        }
+        if !self.feature_associations.is_empty() {
+            let mut node = add_element(&mut root, fc, FEATURE_ASSOCIATIONS)?;
+            for val in &self.feature_associations {
+                val.write(&mut node, &namespaces)?;
+            }
+        }
+        if !self.information_types.is_empty() {
+            let mut node = add_element(&mut root, fc, INFORMATION_TYPES)?;
+            for val in &self.information_types {
+                val.write(&mut node, &namespaces)?;
+            }
+        }
+        if !self.feature_types.is_empty() {
+            let mut node = add_element(&mut root, fc, FEATURE_TYPES)?;
+            for val in &self.feature_types {
+                val.write(&mut node, &namespaces)?;
+            }
+        }
+
+        Ok(document.to_string())
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let xml = self.to_xml()?;
+        fs::write(path, xml)?;
+        Ok(())
+    }
+
+    pub fn information_type_hierarchy(&self, code: &str) -> Result<Vec<&InformationType>> {
+        hierarchy(
+            code,
+            |code| self.information_type(code),
+            |val| val.super_type(),
+        )
+    }
+
+    pub fn feature_type_hierarchy(&self, code: &str) -> Result<Vec<&FeatureType>> {
+        hierarchy(code, |code| self.feature_type(code), |val| val.super_type())
+    }
+
+    pub fn effective_information_bindings(&self, code: &str) -> Result<EffectiveBindings> {
+        let bindings = self.inherited_information_bindings(code)?;
+        if matches!(self.information_type(code), Some(val) if val.is_abstract()) {
+            return Err(S100Error::AbstractType {
+                code: code.to_string(),
+            });
+        }
+
+        Ok(bindings)
+    }
+
+    // Unlike effective_information_bindings, also merges the bindings of abstract types, e.g. to
+    // document them.
+    pub fn inherited_information_bindings(&self, code: &str) -> Result<EffectiveBindings> {
+        let hierarchy = self.information_type_hierarchy(code)?;
+
+        let mut bindings = EffectiveBindings::default();
+        for information_type in hierarchy.iter().rev() {
+            bindings.merge(
+                information_type.code(),
+                information_type.attribute_bindings(),
+                &[],
+                information_type.information_bindings(),
+            );
+        }
+
+        Ok(bindings)
+    }
+
+    pub fn effective_feature_bindings(&self, code: &str) -> Result<EffectiveBindings> {
+        let bindings = self.inherited_feature_bindings(code)?;
+        if matches!(self.feature_type(code), Some(val) if val.is_abstract()) {
+            return Err(S100Error::AbstractType {
+                code: code.to_string(),
+            });
+        }
+
+        Ok(bindings)
+    }
+
+    // Unlike effective_feature_bindings, also merges the bindings of abstract types.
+    pub fn inherited_feature_bindings(&self, code: &str) -> Result<EffectiveBindings> {
+        let hierarchy = self.feature_type_hierarchy(code)?;
+
+        let mut bindings = EffectiveBindings::default();
+        for feature_type in hierarchy.iter().rev() {
+            bindings.merge(
+                feature_type.code(),
+                feature_type.attribute_bindings(),
+                feature_type.feature_bindings(),
+                feature_type.information_bindings(),
+            );
+        }
+
+        Ok(bindings)
+    }
+}
+
+impl FromStr for FeatureCatalog {
+    type Err = S100Error;
+
+    fn from_str(input: &str) -> Result<FeatureCatalog> {
+        FeatureCatalog::from_bytes(input.as_bytes())
+    }
+}
+
+// Walks the superType chain starting at `code`, returning the type itself followed by its ancestors.
+pub(super) fn hierarchy<'a, T, L, S>(code: &str, lookup: L, super_type: S) -> Result<Vec<&'a T>>
+where
+    T: Item,
+    L: Fn(&str) -> Option<&'a T>,
+    S: Fn(&'a T) -> Option<&'a str>,
+{
+    let mut hierarchy: Vec<&T> = Vec::new();
+    let mut current = Some(code);
+
+    while let Some(current_code) = current {
+        if hierarchy.iter().any(|val| val.code() == current_code) {
+            return Err(S100Error::CyclicSuperType {
+                code: code.to_string(),
+                through: current_code.to_string(),
+            });
+        }
+
+        match lookup(current_code) {
+            Some(val) => {
+                hierarchy.push(val);
+                current = super_type(val);
+            }
+            None if hierarchy.is_empty() => {
+                return Err(S100Error::UndefinedItem {
+                    code: code.to_string(),
+                })
+            }
+            None => {
+                return Err(S100Error::UndefinedSuperType {
+                    code: hierarchy[hierarchy.len() - 1].code().to_string(),
+                    super_type: current_code.to_string(),
+                })
+            }
+        }
+    }
+
+    Ok(hierarchy)
+}
+
+#[derive(Clone, Debug)]
+pub struct FeatureCatalogBuilder {
+    catalog: FeatureCatalog,
+}
+
+impl FeatureCatalogBuilder {
+    pub fn name(mut self, name: &str) -> FeatureCatalogBuilder {
+        self.catalog.name = Some(name.to_string());
+        self
+    }
+
+    pub fn scope(mut self, scope: &str) -> FeatureCatalogBuilder {
+        self.catalog.scopes.push(scope.to_string());
+        self
+    }
+
+    pub fn field_of_application(mut self, field_of_application: &str) -> FeatureCatalogBuilder {
+        self.catalog
+            .fields_of_application
+            .push(field_of_application.to_string());
+        self
+    }
+
+    pub fn version_number(mut self, version_number: &str) -> FeatureCatalogBuilder {
+        self.catalog.version_number = Some(version_number.to_string());
+        self
+    }
+
+    pub fn version_date(mut self, version_date: &str) -> FeatureCatalogBuilder {
+        self.catalog.version_date = Some(version_date.to_string());
+        self
+    }
+
+    pub fn producer(mut self, producer: Responsibility) -> FeatureCatalogBuilder {
+        self.catalog.producer = Some(producer);
+        self
+    }
+
+    pub fn classification(mut self, classification: ClassificationCode) -> FeatureCatalogBuilder {
+        self.catalog.classification = Some(classification);
+        self
+    }
+
+    pub fn definition_source(
+        mut self,
+        definition_source: DefinitionSource,
+    ) -> FeatureCatalogBuilder {
+        self.catalog.definition_sources.push(definition_source);
+        self
+    }
+
+    pub fn simple_attribute(mut self, simple_attribute: SimpleAttribute) -> FeatureCatalogBuilder {
+        self.catalog.simple_attributes.push(simple_attribute);
+        self
+    }
+
+    pub fn complex_attribute(
+        mut self,
+        complex_attribute: ComplexAttribute,
+    ) -> FeatureCatalogBuilder {
+        self.catalog.complex_attributes.push(complex_attribute);
+        self
+    }
+
+    pub fn role(mut self, role: Role) -> FeatureCatalogBuilder {
+        self.catalog.roles.push(role);
+        self
+    }
+
+    pub fn information_association(
+        mut self,
+        information_association: InformationAssociation,
+    ) -> FeatureCatalogBuilder {
+        self.catalog
+            .information_associations
+            .push(information_association);
+        self
+    }
+
+    pub fn feature_association(
+        mut self,
+        feature_association: FeatureAssociation,
+    ) -> FeatureCatalogBuilder {
+        self.catalog.feature_associations.push(feature_association);
+        self
+    }
+
+    pub fn information_type(mut self, information_type: InformationType) -> FeatureCatalogBuilder {
+        self.catalog.information_types.push(information_type);
+        self
+    }
+
+    pub fn feature_type(mut self, feature_type: FeatureType) -> FeatureCatalogBuilder {
+        self.catalog.feature_types.push(feature_type);
+        self
+    }
+
+    // The finished catalogue must pass the same cross-reference checks as `validate`.
+    pub fn build(self) -> Result<FeatureCatalog> {
+        let mut catalog = self.catalog;
+        catalog.build_indices();
+
+        let issues = catalog.validate();
+        if !issues.is_empty() {
+            return Err(S100Error::InvalidCatalog(issues));
+        }
+        Ok(catalog)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::io::Write;
+    use std::{io::Write, str::FromStr};
 
     use tempfile::NamedTempFile;
 
-    use super::FeatureCatalog;
+    use super::{ClassificationCode, FeatureCatalog, Item};
+    use crate::feature::catalog::{
+        AttributeBinding, AttributeValueType, ChangeKind, Citation, DefinitionReference,
+        DefinitionSource, FeatureType, FeatureUseType, ItemKind, Multiplicity, Responsibility,
+        SimpleAttribute, SpatialPrimitiveType,
+    };
+    use crate::{ParseMode, S100Error};
 
     #[test]
     fn deserialize() {
         let xml = r#"
         <?xml version="1.0" encoding="utf-8"?>
         <S100FC:S100_FC_FeatureCatalogue xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base" xmlns:S100CI="http://www.iho.int/S100CI" xmlns:xlink="http://www.w3.org/1999/xlink" xmlns:S100FD="http://www.iho.int/S100FD" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:schemaLocation="http://www.iho.int/S100FC S100FC.xsd">
+            <S100FC:name>S-101 Feature Catalogue</S100FC:name>
+            <S100FC:scope>Electronic Navigational Chart</S100FC:scope>
+            <S100FC:fieldOfApplication>Navigation</S100FC:fieldOfApplication>
+            <S100FC:versionNumber>1.0.0</S100FC:versionNumber>
+            <S100FC:versionDate>2018-12-01</S100FC:versionDate>
+            <S100FC:producer>
+                <S100CI:role>publisher</S100CI:role>
+                <S100CI:party>
+                    <S100CI:CI_Organisation>
+                        <S100CI:name>International Hydrographic Organization</S100CI:name>
+                    </S100CI:CI_Organisation>
+                </S100CI:party>
+            </S100FC:producer>
+            <S100FC:classification>unclassified</S100FC:classification>
+            <S100FC:S100_FC_DefinitionSources>
+                <S100FC:S100_FC_DefinitionSource id="IHOREG">
+                    <S100FC:source>
+                        <S100CI:title>IHO Geospatial Information Registry</S100CI:title>
+                    </S100FC:source>
+                </S100FC:S100_FC_DefinitionSource>
+            </S100FC:S100_FC_DefinitionSources>
             <S100FC:S100_FC_SimpleAttributes>
 
             <S100FC:S100_FC_SimpleAttribute>
@@ -86,9 +840,99 @@ mod tests {
 			<S100FC:code>callSign</S100FC:code>
 			<S100FC:alias>CALSGN</S100FC:alias>
 			<S100FC:valueType>text</S100FC:valueType>
+			<S100FC:definitionReference>
+				<S100FC:sourceIdentifier>17</S100FC:sourceIdentifier>
+				<S100FC:definitionSource ref="IHOREG"/>
+			</S100FC:definitionReference>
 		</S100FC:S100_FC_SimpleAttribute>
 
             </S100FC:S100_FC_SimpleAttributes>
+            <S100FC:S100_FC_ComplexAttributes>
+
+            <S100FC:S100_FC_ComplexAttribute>
+			<S100FC:name>Feature Name</S100FC:name>
+			<S100FC:definition>Provides the name of an entity, defines the national language of the name, and provides the option to display the name at various system display settings.</S100FC:definition>
+			<S100FC:code>featureName</S100FC:code>
+			<S100FC:subAttributeBinding sequential="false">
+				<S100FC:multiplicity>
+					<S100Base:lower>1</S100Base:lower>
+					<S100Base:upper>1</S100Base:upper>
+				</S100FC:multiplicity>
+				<S100FC:attribute ref="name"/>
+			</S100FC:subAttributeBinding>
+		</S100FC:S100_FC_ComplexAttribute>
+
+            </S100FC:S100_FC_ComplexAttributes>
+            <S100FC:S100_FC_Roles>
+
+            <S100FC:S100_FC_Role>
+			<S100FC:name>the Structure</S100FC:name>
+			<S100FC:definition>The structure on which the equipment is located.</S100FC:definition>
+			<S100FC:code>theStructure</S100FC:code>
+		</S100FC:S100_FC_Role>
+
+            <S100FC:S100_FC_Role>
+			<S100FC:name>the Equipment</S100FC:name>
+			<S100FC:definition>The equipment located on the structure.</S100FC:definition>
+			<S100FC:code>theEquipment</S100FC:code>
+		</S100FC:S100_FC_Role>
+
+            </S100FC:S100_FC_Roles>
+            <S100FC:S100_FC_FeatureAssociations>
+
+            <S100FC:S100_FC_FeatureAssociation isAbstract="false">
+			<S100FC:name>Structure/Equipment</S100FC:name>
+			<S100FC:definition>The relationship between a structure and the equipment mounted on it.</S100FC:definition>
+			<S100FC:code>StructureEquipment</S100FC:code>
+			<S100FC:role ref="theStructure"/>
+			<S100FC:role ref="theEquipment"/>
+		</S100FC:S100_FC_FeatureAssociation>
+
+            </S100FC:S100_FC_FeatureAssociations>
+            <S100FC:S100_FC_InformationTypes>
+
+            <S100FC:S100_FC_InformationType isAbstract="false">
+			<S100FC:name>Nautical Information</S100FC:name>
+			<S100FC:definition>Provides textual information that cannot be encoded using other attributes.</S100FC:definition>
+			<S100FC:code>NauticalInformation</S100FC:code>
+			<S100FC:attributeBinding sequential="false">
+				<S100FC:multiplicity>
+					<S100Base:lower>0</S100Base:lower>
+					<S100Base:upper xsi:nil="true" infinite="true"/>
+				</S100FC:multiplicity>
+				<S100FC:attribute ref="featureName"/>
+			</S100FC:attributeBinding>
+		</S100FC:S100_FC_InformationType>
+
+            </S100FC:S100_FC_InformationTypes>
+            <S100FC:S100_FC_FeatureTypes>
+
+            <S100FC:S100_FC_FeatureType isAbstract="false">
+			<S100FC:name>Radio Station</S100FC:name>
+			<S100FC:definition>A place equipped to transmit radio waves.</S100FC:definition>
+			<S100FC:code>RadioStation</S100FC:code>
+			<S100FC:alias>RDOSTA</S100FC:alias>
+			<S100FC:attributeBinding sequential="false">
+				<S100FC:multiplicity>
+					<S100Base:lower>0</S100Base:lower>
+					<S100Base:upper>1</S100Base:upper>
+				</S100FC:multiplicity>
+				<S100FC:attribute ref="callSign"/>
+			</S100FC:attributeBinding>
+			<S100FC:featureBinding roleType="association">
+				<S100FC:multiplicity>
+					<S100Base:lower>0</S100Base:lower>
+					<S100Base:upper>1</S100Base:upper>
+				</S100FC:multiplicity>
+				<S100FC:association ref="StructureEquipment"/>
+				<S100FC:role ref="theStructure"/>
+				<S100FC:featureType ref="Landmark"/>
+			</S100FC:featureBinding>
+			<S100FC:featureUseType>geographic</S100FC:featureUseType>
+			<S100FC:permittedPrimitives>point</S100FC:permittedPrimitives>
+		</S100FC:S100_FC_FeatureType>
+
+            </S100FC:S100_FC_FeatureTypes>
         </S100FC:S100_FC_FeatureCatalogue>"#;
 
         let mut temp_file = NamedTempFile::new().expect("Unable to create temp file");
@@ -104,8 +948,799 @@ mod tests {
                 panic!("Unable to parse feature catalog: {}", e)
             }
             Ok(target) => {
+                assert_eq!(target.name(), Some("S-101 Feature Catalogue"));
+                assert_eq!(target.scopes(), vec!["Electronic Navigational Chart"]);
+                assert_eq!(target.fields_of_application(), vec!["Navigation"]);
+                assert_eq!(target.version_number(), Some("1.0.0"));
+                assert_eq!(target.version_date(), Some("2018-12-01"));
+                assert_eq!(target.producer().unwrap().role(), "publisher");
+                assert_eq!(
+                    target.classification(),
+                    Some(ClassificationCode::Unclassified)
+                );
+
                 assert_eq!(target.simple_attributes().len(), 1);
+                assert_eq!(target.complex_attributes().len(), 1);
+                assert_eq!(target.information_types().len(), 1);
+                assert_eq!(target.feature_types().len(), 1);
+                assert_eq!(target.roles().len(), 2);
+                assert_eq!(target.information_associations().len(), 0);
+                assert_eq!(target.feature_associations().len(), 1);
+
+                let binding = &target.feature_types()[0].feature_bindings()[0];
+                let association = target.feature_association(binding.association()).unwrap();
+                assert_eq!(association.roles(), vec!["theStructure", "theEquipment"]);
+                let role = target.role(binding.role()).unwrap();
+                assert_eq!(role.name(), "the Structure");
+
+                let call_sign = target.simple_attribute("callSign").unwrap();
+                assert_eq!(call_sign.alias(), Some("CALSGN"));
+                let reference = call_sign.definition_reference().unwrap();
+                let source = target
+                    .definition_source(reference.definition_source())
+                    .unwrap();
+                assert_eq!(
+                    source.source().title(),
+                    "IHO Geospatial Information Registry"
+                );
+                assert!(target.definition_source("S32").is_none());
+                let call_sign = target.simple_attribute_by_alias("CALSGN").unwrap();
+                assert_eq!(call_sign.code(), "callSign");
+                let radio_station = target.feature_type_by_alias("RDOSTA").unwrap();
+                assert_eq!(radio_station.code(), "RadioStation");
+                assert!(target.simple_attribute("RadioStation").is_none());
+                assert!(target.feature_type_by_alias("CALSGN").is_none());
+                let radio_station = target.feature_type_by_name("Radio Station").unwrap();
+                assert_eq!(radio_station.code(), "RadioStation");
+                let role = target.role_by_name("the Equipment").unwrap();
+                assert_eq!(role.code(), "theEquipment");
+                assert!(target.simple_attribute_by_name("Radio Station").is_none());
             }
         }
     }
+
+    fn open(xml: &str) -> crate::Result<FeatureCatalog> {
+        let mut temp_file = NamedTempFile::new().expect("Unable to create temp file");
+        temp_file
+            .write_all(xml.as_bytes())
+            .expect("Unable to write XML");
+
+        let result = FeatureCatalog::open(temp_file.path());
+        temp_file.close().expect("Unable to close temp file");
+        result
+    }
+
+    const HIERARCHY_XML: &str = r#"
+        <?xml version="1.0" encoding="utf-8"?>
+        <S100FC:S100_FC_FeatureCatalogue xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+            <S100FC:S100_FC_FeatureTypes>
+                <S100FC:S100_FC_FeatureType isAbstract="true">
+                    <S100FC:name>Supervised Area</S100FC:name>
+                    <S100FC:definition>An area for which there is a supervising authority.</S100FC:definition>
+                    <S100FC:code>SupervisedArea</S100FC:code>
+                    <S100FC:attributeBinding sequential="false">
+                        <S100FC:multiplicity>
+                            <S100Base:lower>0</S100Base:lower>
+                            <S100Base:upper>1</S100Base:upper>
+                        </S100FC:multiplicity>
+                        <S100FC:attribute ref="featureName"/>
+                    </S100FC:attributeBinding>
+                    <S100FC:attributeBinding sequential="false">
+                        <S100FC:multiplicity>
+                            <S100Base:lower>0</S100Base:lower>
+                            <S100Base:upper>1</S100Base:upper>
+                        </S100FC:multiplicity>
+                        <S100FC:attribute ref="scaleMinimum"/>
+                    </S100FC:attributeBinding>
+                    <S100FC:subType>PilotageDistrict</S100FC:subType>
+                    <S100FC:featureUseType>geographic</S100FC:featureUseType>
+                    <S100FC:permittedPrimitives>surface</S100FC:permittedPrimitives>
+                </S100FC:S100_FC_FeatureType>
+                <S100FC:S100_FC_FeatureType isAbstract="false">
+                    <S100FC:name>Pilotage District</S100FC:name>
+                    <S100FC:definition>An area within which a pilotage direction exists.</S100FC:definition>
+                    <S100FC:code>PilotageDistrict</S100FC:code>
+                    <S100FC:attributeBinding sequential="false">
+                        <S100FC:multiplicity>
+                            <S100Base:lower>1</S100Base:lower>
+                            <S100Base:upper>1</S100Base:upper>
+                        </S100FC:multiplicity>
+                        <S100FC:attribute ref="featureName"/>
+                    </S100FC:attributeBinding>
+                    <S100FC:attributeBinding sequential="false">
+                        <S100FC:multiplicity>
+                            <S100Base:lower>0</S100Base:lower>
+                            <S100Base:upper>1</S100Base:upper>
+                        </S100FC:multiplicity>
+                        <S100FC:attribute ref="communicationChannel"/>
+                    </S100FC:attributeBinding>
+                    <S100FC:superType>SupervisedArea</S100FC:superType>
+                    <S100FC:featureUseType>geographic</S100FC:featureUseType>
+                    <S100FC:permittedPrimitives>surface</S100FC:permittedPrimitives>
+                </S100FC:S100_FC_FeatureType>
+                <S100FC:S100_FC_FeatureType isAbstract="false">
+                    <S100FC:name>Loop A</S100FC:name>
+                    <S100FC:definition>First half of a cycle.</S100FC:definition>
+                    <S100FC:code>LoopA</S100FC:code>
+                    <S100FC:superType>LoopB</S100FC:superType>
+                    <S100FC:featureUseType>geographic</S100FC:featureUseType>
+                </S100FC:S100_FC_FeatureType>
+                <S100FC:S100_FC_FeatureType isAbstract="false">
+                    <S100FC:name>Loop B</S100FC:name>
+                    <S100FC:definition>Second half of a cycle.</S100FC:definition>
+                    <S100FC:code>LoopB</S100FC:code>
+                    <S100FC:superType>LoopA</S100FC:superType>
+                    <S100FC:featureUseType>geographic</S100FC:featureUseType>
+                </S100FC:S100_FC_FeatureType>
+                <S100FC:S100_FC_FeatureType isAbstract="false">
+                    <S100FC:name>Orphan</S100FC:name>
+                    <S100FC:definition>Refers to a missing supertype.</S100FC:definition>
+                    <S100FC:code>Orphan</S100FC:code>
+                    <S100FC:superType>Missing</S100FC:superType>
+                    <S100FC:featureUseType>geographic</S100FC:featureUseType>
+                </S100FC:S100_FC_FeatureType>
+            </S100FC:S100_FC_FeatureTypes>
+        </S100FC:S100_FC_FeatureCatalogue>"#;
+
+    #[test]
+    fn effective_bindings() {
+        let target = open(HIERARCHY_XML).unwrap();
+
+        let hierarchy = target.feature_type_hierarchy("PilotageDistrict").unwrap();
+        let codes: Vec<&str> = hierarchy.iter().map(|val| val.code()).collect();
+        assert_eq!(codes, vec!["PilotageDistrict", "SupervisedArea"]);
+
+        let bindings = target
+            .effective_feature_bindings("PilotageDistrict")
+            .unwrap();
+        let codes: Vec<&str> = bindings
+            .attribute_bindings()
+            .iter()
+            .map(|val| val.attribute())
+            .collect();
+        assert_eq!(
+            codes,
+            vec!["featureName", "scaleMinimum", "communicationChannel"]
+        );
+        let feature_name = bindings.attribute_binding("featureName").unwrap();
+        assert_eq!(feature_name.multiplicity().lower(), 1);
+        let origins: Vec<&str> = bindings
+            .declared_attribute_bindings()
+            .iter()
+            .map(|(declared_by, _)| *declared_by)
+            .collect();
+        assert_eq!(
+            origins,
+            vec!["PilotageDistrict", "SupervisedArea", "PilotageDistrict"]
+        );
+
+        let bindings = target.inherited_feature_bindings("SupervisedArea").unwrap();
+        assert_eq!(bindings.attribute_bindings().len(), 2);
+    }
+
+    #[test]
+    fn effective_bindings_errors() {
+        let target = open(HIERARCHY_XML).unwrap();
+
+        assert!(matches!(
+            target.effective_feature_bindings("SupervisedArea"),
+            Err(S100Error::AbstractType { .. })
+        ));
+        assert!(matches!(
+            target.effective_feature_bindings("LoopA"),
+            Err(S100Error::CyclicSuperType { .. })
+        ));
+        assert!(matches!(
+            target.effective_feature_bindings("Orphan"),
+            Err(S100Error::UndefinedSuperType { .. })
+        ));
+        assert!(matches!(
+            target.effective_feature_bindings("Unknown"),
+            Err(S100Error::UndefinedItem { .. })
+        ));
+    }
+
+    const DIFF_OLD_XML: &str = r#"
+        <?xml version="1.0" encoding="utf-8"?>
+        <S100FC:S100_FC_FeatureCatalogue xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base">
+            <S100FC:S100_FC_SimpleAttributes>
+                <S100FC:S100_FC_SimpleAttribute>
+                    <S100FC:name>Category of checkpoint</S100FC:name>
+                    <S100FC:definition>Classification of a checkpoint.</S100FC:definition>
+                    <S100FC:code>categoryOfCheckpoint</S100FC:code>
+                    <S100FC:valueType>enumeration</S100FC:valueType>
+                    <S100FC:listedValues>
+                        <S100FC:listedValue>
+                            <S100FC:label>custom</S100FC:label>
+                            <S100FC:definition>A customs office.</S100FC:definition>
+                            <S100FC:code>1</S100FC:code>
+                        </S100FC:listedValue>
+                    </S100FC:listedValues>
+                </S100FC:S100_FC_SimpleAttribute>
+                <S100FC:S100_FC_SimpleAttribute>
+                    <S100FC:name>Call sign</S100FC:name>
+                    <S100FC:definition>The designated call-sign of a radio station.</S100FC:definition>
+                    <S100FC:code>callSign</S100FC:code>
+                    <S100FC:valueType>text</S100FC:valueType>
+                </S100FC:S100_FC_SimpleAttribute>
+            </S100FC:S100_FC_SimpleAttributes>
+            <S100FC:S100_FC_FeatureTypes>
+                <S100FC:S100_FC_FeatureType isAbstract="false">
+                    <S100FC:name>Checkpoint</S100FC:name>
+                    <S100FC:definition>A place where travellers are stopped.</S100FC:definition>
+                    <S100FC:code>Checkpoint</S100FC:code>
+                    <S100FC:attributeBinding sequential="false">
+                        <S100FC:multiplicity>
+                            <S100Base:lower>0</S100Base:lower>
+                            <S100Base:upper>1</S100Base:upper>
+                        </S100FC:multiplicity>
+                        <S100FC:attribute ref="categoryOfCheckpoint"/>
+                    </S100FC:attributeBinding>
+                    <S100FC:featureUseType>geographic</S100FC:featureUseType>
+                    <S100FC:permittedPrimitives>point</S100FC:permittedPrimitives>
+                </S100FC:S100_FC_FeatureType>
+            </S100FC:S100_FC_FeatureTypes>
+        </S100FC:S100_FC_FeatureCatalogue>"#;
+
+    const DIFF_NEW_XML: &str = r#"
+        <?xml version="1.0" encoding="utf-8"?>
+        <S100FC:S100_FC_FeatureCatalogue xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base">
+            <S100FC:S100_FC_SimpleAttributes>
+                <S100FC:S100_FC_SimpleAttribute>
+                    <S100FC:name>Category of checkpoint</S100FC:name>
+                    <S100FC:definition>Classification of a checkpoint.</S100FC:definition>
+                    <S100FC:code>categoryOfCheckpoint</S100FC:code>
+                    <S100FC:valueType>enumeration</S100FC:valueType>
+                    <S100FC:listedValues>
+                        <S100FC:listedValue>
+                            <S100FC:label>customs</S100FC:label>
+                            <S100FC:definition>A customs office.</S100FC:definition>
+                            <S100FC:code>1</S100FC:code>
+                        </S100FC:listedValue>
+                        <S100FC:listedValue>
+                            <S100FC:label>border</S100FC:label>
+                            <S100FC:definition>An immigration office.</S100FC:definition>
+                            <S100FC:code>2</S100FC:code>
+                        </S100FC:listedValue>
+                    </S100FC:listedValues>
+                </S100FC:S100_FC_SimpleAttribute>
+                <S100FC:S100_FC_SimpleAttribute>
+                    <S100FC:name>Communication channel</S100FC:name>
+                    <S100FC:definition>A channel number assigned to a specific radio frequency.</S100FC:definition>
+                    <S100FC:code>communicationChannel</S100FC:code>
+                    <S100FC:valueType>text</S100FC:valueType>
+                </S100FC:S100_FC_SimpleAttribute>
+            </S100FC:S100_FC_SimpleAttributes>
+            <S100FC:S100_FC_FeatureTypes>
+                <S100FC:S100_FC_FeatureType isAbstract="false">
+                    <S100FC:name>Checkpoint</S100FC:name>
+                    <S100FC:definition>A place where travellers are stopped.</S100FC:definition>
+                    <S100FC:code>Checkpoint</S100FC:code>
+                    <S100FC:attributeBinding sequential="false">
+                        <S100FC:multiplicity>
+                            <S100Base:lower>1</S100Base:lower>
+                            <S100Base:upper>1</S100Base:upper>
+                        </S100FC:multiplicity>
+                        <S100FC:attribute ref="categoryOfCheckpoint"/>
+                    </S100FC:attributeBinding>
+                    <S100FC:featureUseType>geographic</S100FC:featureUseType>
+                    <S100FC:permittedPrimitives>point</S100FC:permittedPrimitives>
+                    <S100FC:permittedPrimitives>surface</S100FC:permittedPrimitives>
+                </S100FC:S100_FC_FeatureType>
+            </S100FC:S100_FC_FeatureTypes>
+        </S100FC:S100_FC_FeatureCatalogue>"#;
+
+    #[test]
+    fn from_memory() {
+        let expected = open(HIERARCHY_XML).unwrap();
+
+        let from_str = FeatureCatalog::from_str(HIERARCHY_XML).unwrap();
+        let from_bytes = FeatureCatalog::from_bytes(HIERARCHY_XML.as_bytes()).unwrap();
+        let from_reader = FeatureCatalog::from_reader(HIERARCHY_XML.as_bytes()).unwrap();
+
+        for catalog in [from_str, from_bytes, from_reader].iter() {
+            assert!(catalog.diff(&expected).is_empty());
+            assert_eq!(
+                catalog.feature_types().len(),
+                expected.feature_types().len()
+            );
+        }
+
+        assert!(FeatureCatalog::from_str("<S100FC:S100_FC_FeatureCatalogue").is_err());
+    }
+
+    #[test]
+    fn namespaces() {
+        let expected = open(HIERARCHY_XML).unwrap();
+
+        let edition_5 = HIERARCHY_XML
+            .replace(
+                "http://www.iho.int/S100FC\"",
+                "http://www.iho.int/S100FC/5.0\"",
+            )
+            .replace(
+                "http://www.iho.int/S100Base\"",
+                "http://www.iho.int/S100Base/5.0\"",
+            );
+        let catalog = FeatureCatalog::from_str(&edition_5).unwrap();
+        assert!(catalog.diff(&expected).is_empty());
+
+        let extended = HIERARCHY_XML.replace(
+            "<S100FC:S100_FC_FeatureTypes>",
+            "<ext:name xmlns:ext=\"http://example.com/ext\">Extension</ext:name>\n<S100FC:S100_FC_FeatureTypes>",
+        );
+        let catalog = FeatureCatalog::from_str(&extended).unwrap();
+        assert_eq!(catalog.name(), None);
+
+        let foreign = HIERARCHY_XML.replace(
+            "xmlns:S100FC=\"http://www.iho.int/S100FC\"",
+            "xmlns:S100FC=\"http://example.com/S100FC\"",
+        );
+        match FeatureCatalog::from_str(&foreign) {
+            Ok(_) => panic!("a feature catalogue in a foreign namespace was accepted"),
+            Err(S100Error::InvalidValue { location, value }) => {
+                assert_eq!(value, "http://example.com/S100FC");
+                assert_eq!(location.element_path(), "/S100FC:S100_FC_FeatureCatalogue");
+            }
+            Err(e) => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn error_location() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<S100FC:S100_FC_FeatureCatalogue xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base">
+    <S100FC:S100_FC_SimpleAttributes>
+        <S100FC:S100_FC_SimpleAttribute>
+            <S100FC:name>Name</S100FC:name>
+            <S100FC:definition>The individual name of a feature.</S100FC:definition>
+            <S100FC:code>name</S100FC:code>
+            <S100FC:valueType>text</S100FC:valueType>
+        </S100FC:S100_FC_SimpleAttribute>
+        <S100FC:S100_FC_SimpleAttribute>
+            <S100FC:name>Orientation value</S100FC:name>
+            <S100FC:definition>The angular distance measured from true north.</S100FC:definition>
+            <S100FC:code>orientationValue</S100FC:code>
+            <S100FC:valueType>angle</S100FC:valueType>
+        </S100FC:S100_FC_SimpleAttribute>
+    </S100FC:S100_FC_SimpleAttributes>
+</S100FC:S100_FC_FeatureCatalogue>"#;
+
+        let mut temp_file = NamedTempFile::new().expect("Unable to create temp file");
+        temp_file
+            .write_all(xml.as_bytes())
+            .expect("Unable to write XML");
+
+        match FeatureCatalog::open(temp_file.path()) {
+            Err(S100Error::InvalidEnum {
+                location,
+                name,
+                value,
+            }) => {
+                assert_eq!(name, "valueType");
+                assert_eq!(value, "angle");
+                assert_eq!(location.path(), Some(temp_file.path()));
+                assert_eq!(location.line(), Some(14));
+                assert_eq!(
+                    location.element_path(),
+                    "/S100FC:S100_FC_FeatureCatalogue/S100FC:S100_FC_SimpleAttributes/S100FC:S100_FC_SimpleAttribute[2]/S100FC:valueType"
+                );
+            }
+            Err(e) => panic!("unexpected error: {}", e),
+            Ok(_) => panic!("an invalid value type was accepted"),
+        }
+
+        let xml = xml.replace("<S100FC:code>name</S100FC:code>", "");
+        match FeatureCatalog::from_str(&xml) {
+            Err(e @ S100Error::MissingChild { .. }) => {
+                let location = e.location().unwrap();
+                assert_eq!(location.path(), None);
+                assert_eq!(location.line(), Some(4));
+                assert_eq!(
+                    e.to_string(),
+                    "line 4: /S100FC:S100_FC_FeatureCatalogue/S100FC:S100_FC_SimpleAttributes/S100FC:S100_FC_SimpleAttribute[1]: missing a child node called 'code'"
+                );
+            }
+            Err(e) => panic!("unexpected error: {}", e),
+            Ok(_) => panic!("a simple attribute without a code was accepted"),
+        }
+
+        let xml = xml.replace("S100_FC_FeatureCatalogue", "S100_FC_Catalogue");
+        match FeatureCatalog::from_str(&xml) {
+            Err(S100Error::UnexpectedElement { location }) => {
+                assert_eq!(location.line(), Some(2));
+                assert_eq!(location.element_path(), "/S100FC:S100_FC_Catalogue");
+            }
+            Err(e) => panic!("unexpected error: {}", e),
+            Ok(_) => panic!("a catalogue with the wrong root element was accepted"),
+        }
+
+        temp_file.close().expect("Unable to close temp file");
+    }
+
+    #[test]
+    fn lenient_mode() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<S100FC:S100_FC_FeatureCatalogue xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base">
+    <S100FC:S100_FC_SimpleAttributes>
+        <S100FC:S100_FC_SimpleAttribute>
+            <S100FC:name>Name</S100FC:name>
+            <S100FC:definition>The individual name of a feature.</S100FC:definition>
+            <S100FC:code>name</S100FC:code>
+            <S100FC:valueType>text</S100FC:valueType>
+            <S100FC:note>Unknown</S100FC:note>
+        </S100FC:S100_FC_SimpleAttribute>
+        <S100FC:S100_FC_SimpleAttribute>
+            <S100FC:name>Orientation value</S100FC:name>
+            <S100FC:definition>The angular distance measured from true north.</S100FC:definition>
+            <S100FC:code>orientationValue</S100FC:code>
+            <S100FC:valueType>angle</S100FC:valueType>
+        </S100FC:S100_FC_SimpleAttribute>
+    </S100FC:S100_FC_SimpleAttributes>
+    <S100FC:extension>Unknown</S100FC:extension>
+</S100FC:S100_FC_FeatureCatalogue>"#;
+
+        assert!(FeatureCatalog::from_bytes_with_mode(xml.as_bytes(), ParseMode::Strict).is_err());
+
+        let (feature_catalog, warnings) =
+            FeatureCatalog::from_bytes_with_mode(xml.as_bytes(), ParseMode::Lenient).unwrap();
+        assert_eq!(feature_catalog.simple_attributes().len(), 1);
+        assert_eq!(warnings.len(), 3);
+        assert!(matches!(warnings[0], S100Error::UnexpectedElement { .. }));
+        assert_eq!(
+            warnings[0].location().unwrap().element_path(),
+            "/S100FC:S100_FC_FeatureCatalogue/S100FC:S100_FC_SimpleAttributes/S100FC:S100_FC_SimpleAttribute[1]/S100FC:note"
+        );
+        assert!(matches!(warnings[1], S100Error::InvalidEnum { .. }));
+        assert!(matches!(warnings[2], S100Error::UnexpectedElement { .. }));
+        assert_eq!(warnings[2].location().unwrap().line(), Some(18));
+
+        let mut temp_file = NamedTempFile::new().expect("Unable to create temp file");
+        temp_file
+            .write_all(xml.as_bytes())
+            .expect("Unable to write XML");
+
+        let (_, warnings) =
+            FeatureCatalog::open_with_mode(temp_file.path(), ParseMode::Lenient).unwrap();
+        assert!(warnings
+            .iter()
+            .all(|val| val.location().unwrap().path() == Some(temp_file.path())));
+
+        temp_file.close().expect("Unable to close temp file");
+    }
+
+    #[test]
+    fn diff() {
+        let old = open(DIFF_OLD_XML).unwrap();
+        let new = open(DIFF_NEW_XML).unwrap();
+
+        assert!(old.diff(&old).is_empty());
+
+        let changes = old.diff(&new);
+        let summary: Vec<(ChangeKind, ItemKind, &str, Option<&str>)> = changes
+            .iter()
+            .map(|val| (val.kind(), val.item_kind(), val.code(), val.property()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    ChangeKind::Changed,
+                    ItemKind::SimpleAttribute,
+                    "categoryOfCheckpoint",
+                    Some("listedValue[1].label")
+                ),
+                (
+                    ChangeKind::Added,
+                    ItemKind::SimpleAttribute,
+                    "categoryOfCheckpoint",
+                    Some("listedValue[2]")
+                ),
+                (
+                    ChangeKind::Removed,
+                    ItemKind::SimpleAttribute,
+                    "callSign",
+                    None
+                ),
+                (
+                    ChangeKind::Added,
+                    ItemKind::SimpleAttribute,
+                    "communicationChannel",
+                    None
+                ),
+                (
+                    ChangeKind::Added,
+                    ItemKind::FeatureType,
+                    "Checkpoint",
+                    Some("permittedPrimitives[surface]")
+                ),
+                (
+                    ChangeKind::Changed,
+                    ItemKind::FeatureType,
+                    "Checkpoint",
+                    Some("attributeBinding[categoryOfCheckpoint].multiplicity")
+                ),
+            ]
+        );
+        assert_eq!(changes[0].old_value(), Some("custom"));
+        assert_eq!(changes[0].new_value(), Some("customs"));
+        assert_eq!(changes[5].old_value(), Some("0..1"));
+        assert_eq!(changes[5].new_value(), Some("1..1"));
+    }
+
+    #[test]
+    fn validate() {
+        let target = open(DIFF_OLD_XML).unwrap();
+        assert!(target.validate().is_empty());
+
+        let target = open(HIERARCHY_XML).unwrap();
+        let issues: Vec<String> = target
+            .validate()
+            .iter()
+            .map(|val| val.to_string())
+            .collect();
+        assert_eq!(
+            issues,
+            vec![
+                "FeatureType 'SupervisedArea': binds an undefined attribute 'featureName'",
+                "FeatureType 'SupervisedArea': binds an undefined attribute 'scaleMinimum'",
+                "FeatureType 'PilotageDistrict': binds an undefined attribute 'featureName'",
+                "FeatureType 'PilotageDistrict': binds an undefined attribute 'communicationChannel'",
+                "FeatureType 'LoopA': has a cyclic superType chain through 'LoopA'",
+                "FeatureType 'LoopB': has a cyclic superType chain through 'LoopB'",
+                "FeatureType 'Orphan': references an undefined superType 'Missing'",
+            ]
+        );
+    }
+
+    const SERIALIZE_XML: &str = r#"
+        <?xml version="1.0" encoding="utf-8"?>
+        <S100FC:S100_FC_FeatureCatalogue xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base" xmlns:S100CI="http://www.iho.int/S100CI" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+            <S100FC:name>S-101 Feature Catalogue</S100FC:name>
+            <S100FC:scope>Electronic Navigational Chart</S100FC:scope>
+            <S100FC:versionNumber>1.0.0</S100FC:versionNumber>
+            <S100FC:versionDate>2018-12-01</S100FC:versionDate>
+            <S100FC:producer>
+                <S100CI:role>publisher</S100CI:role>
+                <S100CI:party>
+                    <S100CI:CI_Organisation>
+                        <S100CI:name>International Hydrographic Organization</S100CI:name>
+                    </S100CI:CI_Organisation>
+                </S100CI:party>
+            </S100FC:producer>
+            <S100FC:classification>unclassified</S100FC:classification>
+            <S100FC:S100_FC_DefinitionSources>
+                <S100FC:S100_FC_DefinitionSource id="IHOREG">
+                    <S100FC:source>
+                        <S100CI:title>IHO Geospatial Information Registry</S100CI:title>
+                        <S100CI:date>
+                            <S100CI:date>2018-04-01</S100CI:date>
+                            <S100CI:dateType>revision</S100CI:dateType>
+                        </S100CI:date>
+                        <S100CI:edition>4.0</S100CI:edition>
+                    </S100FC:source>
+                </S100FC:S100_FC_DefinitionSource>
+            </S100FC:S100_FC_DefinitionSources>
+            <S100FC:S100_FC_SimpleAttributes>
+                <S100FC:S100_FC_SimpleAttribute>
+                    <S100FC:name>Category of checkpoint</S100FC:name>
+                    <S100FC:definition>Classification of a checkpoint.</S100FC:definition>
+                    <S100FC:code>categoryOfCheckpoint</S100FC:code>
+                    <S100FC:remarks>Customs &amp; immigration.</S100FC:remarks>
+                    <S100FC:alias>CATCHP</S100FC:alias>
+                    <S100FC:language>eng</S100FC:language>
+                    <S100FC:translation>
+                        <S100FC:name>Catégorie de point de contrôle</S100FC:name>
+                        <S100FC:language>fra</S100FC:language>
+                    </S100FC:translation>
+                    <S100FC:valueType>enumeration</S100FC:valueType>
+                    <S100FC:listedValues>
+                        <S100FC:listedValue>
+                            <S100FC:label>custom</S100FC:label>
+                            <S100FC:definition>A customs office.</S100FC:definition>
+                            <S100FC:code>1</S100FC:code>
+                            <S100FC:definitionReference>
+                                <S100FC:sourceIdentifier>25</S100FC:sourceIdentifier>
+                                <S100FC:definitionSource ref="IHOREG"/>
+                            </S100FC:definitionReference>
+                            <S100FC:translation>
+                                <S100FC:label>douane</S100FC:label>
+                                <S100FC:language>fra</S100FC:language>
+                            </S100FC:translation>
+                        </S100FC:listedValue>
+                    </S100FC:listedValues>
+                </S100FC:S100_FC_SimpleAttribute>
+                <S100FC:S100_FC_SimpleAttribute>
+                    <S100FC:name>Value of nominal range</S100FC:name>
+                    <S100FC:definition>The nominal range of a light.</S100FC:definition>
+                    <S100FC:code>valueOfNominalRange</S100FC:code>
+                    <S100FC:valueType>real</S100FC:valueType>
+                    <S100FC:uom>
+                        <S100Base:name>nautical mile</S100Base:name>
+                        <S100Base:symbol>M</S100Base:symbol>
+                    </S100FC:uom>
+                    <S100FC:quantitySpecification>length</S100FC:quantitySpecification>
+                    <S100FC:constraints>
+                        <S100Base:ACRange>
+                            <S100Base:lowerBound>0</S100Base:lowerBound>
+                            <S100Base:upperBound>99.5</S100Base:upperBound>
+                            <S100Base:closure>closedInterval</S100Base:closure>
+                        </S100Base:ACRange>
+                        <S100Base:precision>1</S100Base:precision>
+                    </S100FC:constraints>
+                </S100FC:S100_FC_SimpleAttribute>
+            </S100FC:S100_FC_SimpleAttributes>
+            <S100FC:S100_FC_ComplexAttributes>
+                <S100FC:S100_FC_ComplexAttribute>
+                    <S100FC:name>Feature Name</S100FC:name>
+                    <S100FC:definition>The name of an entity.</S100FC:definition>
+                    <S100FC:code>featureName</S100FC:code>
+                    <S100FC:subAttributeBinding sequential="true">
+                        <S100FC:multiplicity>
+                            <S100Base:lower>1</S100Base:lower>
+                            <S100Base:upper xsi:nil="true" infinite="true"/>
+                        </S100FC:multiplicity>
+                        <S100FC:permittedValues>
+                            <S100FC:value>1</S100FC:value>
+                        </S100FC:permittedValues>
+                        <S100FC:attribute ref="categoryOfCheckpoint"/>
+                    </S100FC:subAttributeBinding>
+                </S100FC:S100_FC_ComplexAttribute>
+            </S100FC:S100_FC_ComplexAttributes>
+            <S100FC:S100_FC_Roles>
+                <S100FC:S100_FC_Role>
+                    <S100FC:name>the Information</S100FC:name>
+                    <S100FC:definition>The information associated with a feature.</S100FC:definition>
+                    <S100FC:code>theInformation</S100FC:code>
+                </S100FC:S100_FC_Role>
+            </S100FC:S100_FC_Roles>
+            <S100FC:S100_FC_InformationAssociations>
+                <S100FC:S100_FC_InformationAssociation isAbstract="true">
+                    <S100FC:name>Additional Information</S100FC:name>
+                    <S100FC:definition>Links additional information to a feature.</S100FC:definition>
+                    <S100FC:code>AdditionalInformation</S100FC:code>
+                    <S100FC:role ref="theInformation"/>
+                </S100FC:S100_FC_InformationAssociation>
+            </S100FC:S100_FC_InformationAssociations>
+            <S100FC:S100_FC_InformationTypes>
+                <S100FC:S100_FC_InformationType isAbstract="false">
+                    <S100FC:name>Nautical Information</S100FC:name>
+                    <S100FC:definition>Provides textual information.</S100FC:definition>
+                    <S100FC:code>NauticalInformation</S100FC:code>
+                </S100FC:S100_FC_InformationType>
+            </S100FC:S100_FC_InformationTypes>
+            <S100FC:S100_FC_FeatureTypes>
+                <S100FC:S100_FC_FeatureType isAbstract="false">
+                    <S100FC:name>Checkpoint</S100FC:name>
+                    <S100FC:definition>A place where travellers are stopped.</S100FC:definition>
+                    <S100FC:code>Checkpoint</S100FC:code>
+                    <S100FC:attributeBinding sequential="false">
+                        <S100FC:multiplicity>
+                            <S100Base:lower>0</S100Base:lower>
+                            <S100Base:upper>1</S100Base:upper>
+                        </S100FC:multiplicity>
+                        <S100FC:attribute ref="featureName"/>
+                    </S100FC:attributeBinding>
+                    <S100FC:informationBinding roleType="association">
+                        <S100FC:multiplicity>
+                            <S100Base:lower>0</S100Base:lower>
+                            <S100Base:upper xsi:nil="true" infinite="true"/>
+                        </S100FC:multiplicity>
+                        <S100FC:association ref="AdditionalInformation"/>
+                        <S100FC:role ref="theInformation"/>
+                        <S100FC:informationType ref="NauticalInformation"/>
+                    </S100FC:informationBinding>
+                    <S100FC:featureUseType>geographic</S100FC:featureUseType>
+                    <S100FC:permittedPrimitives>point</S100FC:permittedPrimitives>
+                    <S100FC:permittedPrimitives>surface</S100FC:permittedPrimitives>
+                </S100FC:S100_FC_FeatureType>
+            </S100FC:S100_FC_FeatureTypes>
+        </S100FC:S100_FC_FeatureCatalogue>"#;
+
+    #[test]
+    fn serialize() {
+        let source = open(SERIALIZE_XML).unwrap();
+        let xml = source.to_xml().unwrap();
+        assert!(xml.contains("<S100FC:S100_FC_FeatureCatalogue"));
+        assert!(xml.contains("xmlns:S100Base=\"http://www.iho.int/S100Base\""));
+        assert!(!xml.contains("S100_FC_FeatureAssociations"));
+
+        let temp_file = NamedTempFile::new().expect("Unable to create temp file");
+        source.save(temp_file.path()).unwrap();
+        let target = FeatureCatalog::open(temp_file.path()).unwrap();
+        temp_file.close().expect("Unable to close temp file");
+
+        assert!(source.diff(&target).is_empty());
+        assert!(target.validate().is_empty());
+        assert_eq!(target.name(), source.name());
+        assert_eq!(target.scopes(), source.scopes());
+        assert_eq!(target.version_number(), source.version_number());
+        assert_eq!(target.version_date(), source.version_date());
+        assert_eq!(target.producer(), source.producer());
+        assert_eq!(target.classification(), source.classification());
+        assert_eq!(target.definition_sources(), source.definition_sources());
+
+        let attribute = target.simple_attribute("categoryOfCheckpoint").unwrap();
+        assert_eq!(attribute.remarks(), Some("Customs & immigration."));
+        assert_eq!(
+            attribute.localized_name("fra"),
+            "Catégorie de point de contrôle"
+        );
+        assert_eq!(
+            attribute.listed_values()[0].localized_label("fra"),
+            "douane"
+        );
+        let attribute = target.simple_attribute("valueOfNominalRange").unwrap();
+        assert_eq!(attribute.unit_of_measure().unwrap().symbol(), Some("M"));
+        let range = attribute.constraints().unwrap().range().unwrap();
+        assert_eq!(range.upper_bound(), Some(99.5));
+
+        let binding = &target
+            .complex_attribute("featureName")
+            .unwrap()
+            .sub_attribute_bindings()[0];
+        assert!(binding.sequential());
+        assert!(binding.multiplicity().is_unbounded());
+    }
+
+    #[test]
+    fn build() {
+        let source = Citation::builder()
+            .title("IHO Geospatial Information Registry")
+            .build()
+            .unwrap();
+        let call_sign = SimpleAttribute::builder()
+            .name("Call sign")
+            .definition("The designated call-sign of a radio station.")
+            .code("callSign")
+            .value_type(AttributeValueType::Text)
+            .definition_reference(DefinitionReference::new("17", "IHOREG").unwrap())
+            .build()
+            .unwrap();
+        let binding = AttributeBinding::builder()
+            .multiplicity(Multiplicity::new(0, Some(1)).unwrap())
+            .attribute("callSign")
+            .build()
+            .unwrap();
+        let radio_station = FeatureType::builder()
+            .name("Radio Station")
+            .definition("A place equipped to transmit radio waves.")
+            .code("RadioStation")
+            .attribute_binding(binding)
+            .feature_use_type(FeatureUseType::Geographic)
+            .permitted_primitive(SpatialPrimitiveType::Point)
+            .build()
+            .unwrap();
+
+        let target = FeatureCatalog::builder()
+            .name("Draft Feature Catalogue")
+            .version_number("0.1.0")
+            .producer(Responsibility::new("publisher", &["Example Office"]).unwrap())
+            .definition_source(DefinitionSource::new("IHOREG", source).unwrap())
+            .simple_attribute(call_sign.clone())
+            .feature_type(radio_station.clone())
+            .build()
+            .unwrap();
+        assert_eq!(target.name(), Some("Draft Feature Catalogue"));
+        assert_eq!(
+            target.simple_attribute("callSign").unwrap().name(),
+            "Call sign"
+        );
+        assert_eq!(target.feature_types().len(), 1);
+        let bindings = target.effective_feature_bindings("RadioStation").unwrap();
+        assert!(bindings.attribute_binding("callSign").is_some());
+
+        let result = FeatureCatalog::builder()
+            .simple_attribute(call_sign)
+            .feature_type(radio_station.clone())
+            .build();
+        match result {
+            Err(S100Error::InvalidCatalog(issues)) => assert_eq!(issues.len(), 1),
+            val => panic!("unexpected result: {:?}", val.map(|_| ())),
+        }
+        let result = FeatureCatalog::builder()
+            .feature_type(radio_station)
+            .build();
+        assert!(result.is_err());
+    }
 }
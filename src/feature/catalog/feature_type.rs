@@ -0,0 +1,593 @@
+use libxml::tree::Node;
+use std::str::FromStr;
+
+use super::{
+    attribute_binding::check_unique,
+    item::check_item,
+    xml_writer::{add_element, add_item, add_text, set_attribute, Namespaces},
+    AttributeBinding, DefinitionReference, FeatureBinding, FeatureUseType, InformationBinding,
+    Item, SpatialPrimitiveType, Translation, ATTRIBUTE_BINDING, DEFINITION_REFERENCE,
+    FC_NAMESPACES, FEATURE_BINDING, FEATURE_TYPE, INFORMATION_BINDING, TRANSLATION,
+};
+use crate::{namespace::element_name, Result, S100Error};
+
+const IS_ABSTRACT: &str = "isAbstract";
+const NAME: &str = "name";
+const DEFINITION: &str = "definition";
+const CODE: &str = "code";
+const REMARKS: &str = "remarks";
+const ALIAS: &str = "alias";
+const LANGUAGE: &str = "language";
+const SUPER_TYPE: &str = "superType";
+const SUB_TYPE: &str = "subType";
+const FEATURE_USE_TYPE: &str = "featureUseType";
+const PERMITTED_PRIMITIVES: &str = "permittedPrimitives";
+
+#[derive(Clone, Debug)]
+pub struct FeatureType {
+    name: String,
+    definition: String,
+    code: String,
+    remarks: Option<String>,
+    alias: Option<String>,
+    definition_reference: Option<DefinitionReference>,
+    language: Option<String>,
+    translations: Vec<Translation>,
+    is_abstract: bool,
+    attribute_bindings: Vec<AttributeBinding>,
+    information_bindings: Vec<InformationBinding>,
+    feature_bindings: Vec<FeatureBinding>,
+    super_type: Option<String>,
+    sub_types: Vec<String>,
+    feature_use_type: FeatureUseType,
+    permitted_primitives: Vec<SpatialPrimitiveType>,
+}
+
+impl FeatureType {
+    pub fn builder() -> FeatureTypeBuilder {
+        FeatureTypeBuilder {
+            name: String::new(),
+            definition: String::new(),
+            code: String::new(),
+            remarks: None,
+            alias: None,
+            definition_reference: None,
+            language: None,
+            translations: Vec::new(),
+            is_abstract: false,
+            attribute_bindings: Vec::new(),
+            information_bindings: Vec::new(),
+            feature_bindings: Vec::new(),
+            super_type: None,
+            sub_types: Vec::new(),
+            feature_use_type: None,
+            permitted_primitives: Vec::new(),
+        }
+    }
+
+    pub(super) fn parse(node: Node) -> Result<FeatureType> {
+        if element_name(&node, FC_NAMESPACES) != FEATURE_TYPE {
+            return S100Error::invalid_child(node);
+        }
+
+        let is_abstract = match node.get_attribute(IS_ABSTRACT) {
+            Some(val) => match val.as_str() {
+                "true" | "1" => true,
+                "false" | "0" => false,
+                _ => return S100Error::invalid_value(node),
+            },
+            None => false,
+        };
+        let mut name: Option<String> = None;
+        let mut definition: Option<String> = None;
+        let mut code: Option<String> = None;
+        let mut remarks: Option<String> = None;
+        let mut alias: Option<String> = None;
+        let mut definition_reference: Option<DefinitionReference> = None;
+        let mut language: Option<String> = None;
+        let mut translations: Vec<Translation> = Vec::new();
+        let mut attribute_bindings: Vec<AttributeBinding> = Vec::new();
+        let mut information_bindings: Vec<InformationBinding> = Vec::new();
+        let mut feature_bindings: Vec<FeatureBinding> = Vec::new();
+        let mut super_type: Option<String> = None;
+        let mut sub_types: Vec<String> = Vec::new();
+        let mut feature_use_type: Option<FeatureUseType> = None;
+        let mut permitted_primitives: Vec<SpatialPrimitiveType> = Vec::new();
+
+        for child_node in node.get_child_elements() {
+            match element_name(&child_node, FC_NAMESPACES).as_str() {
+                NAME => name = Some(child_node.get_content()),
+                DEFINITION => definition = Some(child_node.get_content()),
+                CODE => code = Some(child_node.get_content()),
+                REMARKS => remarks = Some(child_node.get_content()),
+                ALIAS => alias = Some(child_node.get_content()),
+                DEFINITION_REFERENCE => match DefinitionReference::parse(child_node) {
+                    Ok(val) => definition_reference = Some(val),
+                    Err(e) => return Err(e),
+                },
+                LANGUAGE => language = Some(child_node.get_content().trim().to_string()),
+                TRANSLATION => match Translation::parse(child_node) {
+                    Ok(val) => translations.push(val),
+                    Err(e) => return Err(e),
+                },
+                ATTRIBUTE_BINDING => match AttributeBinding::parse(child_node) {
+                    Ok(val) => attribute_bindings.push(val),
+                    Err(e) => return Err(e),
+                },
+                INFORMATION_BINDING => match InformationBinding::parse(child_node) {
+                    Ok(val) => information_bindings.push(val),
+                    Err(e) => return Err(e),
+                },
+                FEATURE_BINDING => match FeatureBinding::parse(child_node) {
+                    Ok(val) => feature_bindings.push(val),
+                    Err(e) => return Err(e),
+                },
+                SUPER_TYPE => super_type = Some(child_node.get_content()),
+                SUB_TYPE => sub_types.push(child_node.get_content()),
+                FEATURE_USE_TYPE => {
+                    match FeatureUseType::from_str(child_node.get_content().as_str()) {
+                        Ok(val) => feature_use_type = Some(val),
+                        Err(e) => return Err(e.at(&child_node)),
+                    }
+                }
+                PERMITTED_PRIMITIVES => {
+                    match SpatialPrimitiveType::from_str(child_node.get_content().as_str()) {
+                        Ok(val) => permitted_primitives.push(val),
+                        Err(e) => return Err(e.at(&child_node)),
+                    }
+                }
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        if name.is_none() {
+            return S100Error::missing_child(node, NAME);
+        }
+        if definition.is_none() {
+            return S100Error::missing_child(node, DEFINITION);
+        }
+        if code.is_none() {
+            return S100Error::missing_child(node, CODE);
+        }
+        if feature_use_type.is_none() {
+            return S100Error::missing_child(node, FEATURE_USE_TYPE);
+        }
+
+        Ok(FeatureType {
+            name: name.unwrap(),
+            definition: definition.unwrap(),
+            code: code.unwrap(),
+            remarks,
+            alias,
+            definition_reference,
+            language,
+            translations,
+            is_abstract,
+            attribute_bindings,
+            information_bindings,
+            feature_bindings,
+            super_type,
+            sub_types,
+            feature_use_type: feature_use_type.unwrap(),
+            permitted_primitives,
+        })
+    }
+
+    pub(super) fn write(&self, parent: &mut Node, namespaces: &Namespaces) -> Result<()> {
+        let mut node = add_element(parent, &namespaces.fc, FEATURE_TYPE)?;
+        set_attribute(&mut node, IS_ABSTRACT, &self.is_abstract.to_string())?;
+        add_item(
+            &mut node,
+            namespaces,
+            self,
+            self.remarks(),
+            self.definition_reference(),
+        )?;
+        for val in &self.attribute_bindings {
+            val.write(&mut node, namespaces, ATTRIBUTE_BINDING)?;
+        }
+        for val in &self.information_bindings {
+            val.write(&mut node, namespaces)?;
+        }
+        for val in &self.feature_bindings {
+            val.write(&mut node, namespaces)?;
+        }
+        if let Some(val) = &self.super_type {
+            add_text(&mut node, &namespaces.fc, SUPER_TYPE, val)?;
+        }
+        for val in &self.sub_types {
+            add_text(&mut node, &namespaces.fc, SUB_TYPE, val)?;
+        }
+        add_text(
+            &mut node,
+            &namespaces.fc,
+            FEATURE_USE_TYPE,
+            &self.feature_use_type.to_string(),
+        )?;
+        for val in &self.permitted_primitives {
+            add_text(
+                &mut node,
+                &namespaces.fc,
+                PERMITTED_PRIMITIVES,
+                &val.to_string(),
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn remarks(&self) -> Option<&str> {
+        match self.remarks.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    pub fn definition_reference(&self) -> Option<&DefinitionReference> {
+        match &self.definition_reference {
+            Some(val) => Some(val),
+            None => None,
+        }
+    }
+
+    pub fn is_abstract(&self) -> bool {
+        self.is_abstract
+    }
+
+    pub fn attribute_bindings(&self) -> &[AttributeBinding] {
+        &self.attribute_bindings
+    }
+
+    pub fn information_bindings(&self) -> &[InformationBinding] {
+        &self.information_bindings
+    }
+
+    pub fn feature_bindings(&self) -> &[FeatureBinding] {
+        &self.feature_bindings
+    }
+
+    pub fn super_type(&self) -> Option<&str> {
+        match self.super_type.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    pub fn sub_types(&self) -> Vec<&str> {
+        self.sub_types.iter().map(|s| s.as_str()).collect()
+    }
+
+    pub fn feature_use_type(&self) -> FeatureUseType {
+        self.feature_use_type
+    }
+
+    pub fn permitted_primitives(&self) -> &[SpatialPrimitiveType] {
+        &self.permitted_primitives
+    }
+}
+
+impl Item for FeatureType {
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn definition(&self) -> &str {
+        self.definition.as_str()
+    }
+
+    fn code(&self) -> &str {
+        self.code.as_str()
+    }
+
+    fn alias(&self) -> Option<&str> {
+        match self.alias.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+    fn language(&self) -> Option<&str> {
+        match self.language.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    fn translations(&self) -> &[Translation] {
+        &self.translations
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FeatureTypeBuilder {
+    name: String,
+    definition: String,
+    code: String,
+    remarks: Option<String>,
+    alias: Option<String>,
+    definition_reference: Option<DefinitionReference>,
+    language: Option<String>,
+    translations: Vec<Translation>,
+    is_abstract: bool,
+    attribute_bindings: Vec<AttributeBinding>,
+    information_bindings: Vec<InformationBinding>,
+    feature_bindings: Vec<FeatureBinding>,
+    super_type: Option<String>,
+    sub_types: Vec<String>,
+    feature_use_type: Option<FeatureUseType>,
+    permitted_primitives: Vec<SpatialPrimitiveType>,
+}
+
+impl FeatureTypeBuilder {
+    pub fn name(mut self, name: &str) -> FeatureTypeBuilder {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn definition(mut self, definition: &str) -> FeatureTypeBuilder {
+        self.definition = definition.to_string();
+        self
+    }
+
+    pub fn code(mut self, code: &str) -> FeatureTypeBuilder {
+        self.code = code.to_string();
+        self
+    }
+
+    pub fn remarks(mut self, remarks: &str) -> FeatureTypeBuilder {
+        self.remarks = Some(remarks.to_string());
+        self
+    }
+
+    pub fn alias(mut self, alias: &str) -> FeatureTypeBuilder {
+        self.alias = Some(alias.to_string());
+        self
+    }
+
+    pub fn definition_reference(
+        mut self,
+        definition_reference: DefinitionReference,
+    ) -> FeatureTypeBuilder {
+        self.definition_reference = Some(definition_reference);
+        self
+    }
+
+    pub fn language(mut self, language: &str) -> FeatureTypeBuilder {
+        self.language = Some(language.to_string());
+        self
+    }
+
+    pub fn translation(mut self, translation: Translation) -> FeatureTypeBuilder {
+        self.translations.push(translation);
+        self
+    }
+
+    pub fn is_abstract(mut self, is_abstract: bool) -> FeatureTypeBuilder {
+        self.is_abstract = is_abstract;
+        self
+    }
+
+    pub fn attribute_binding(mut self, attribute_binding: AttributeBinding) -> FeatureTypeBuilder {
+        self.attribute_bindings.push(attribute_binding);
+        self
+    }
+
+    pub fn information_binding(
+        mut self,
+        information_binding: InformationBinding,
+    ) -> FeatureTypeBuilder {
+        self.information_bindings.push(information_binding);
+        self
+    }
+
+    pub fn feature_binding(mut self, feature_binding: FeatureBinding) -> FeatureTypeBuilder {
+        self.feature_bindings.push(feature_binding);
+        self
+    }
+
+    pub fn super_type(mut self, super_type: &str) -> FeatureTypeBuilder {
+        self.super_type = Some(super_type.to_string());
+        self
+    }
+
+    pub fn sub_type(mut self, sub_type: &str) -> FeatureTypeBuilder {
+        self.sub_types.push(sub_type.to_string());
+        self
+    }
+
+    pub fn feature_use_type(mut self, feature_use_type: FeatureUseType) -> FeatureTypeBuilder {
+        self.feature_use_type = Some(feature_use_type);
+        self
+    }
+
+    pub fn permitted_primitive(
+        mut self,
+        permitted_primitive: SpatialPrimitiveType,
+    ) -> FeatureTypeBuilder {
+        self.permitted_primitives.push(permitted_primitive);
+        self
+    }
+
+    pub fn build(self) -> Result<FeatureType> {
+        if self.feature_use_type.is_none() {
+            return S100Error::missing_field(FEATURE_TYPE, FEATURE_USE_TYPE);
+        }
+        let item = FeatureType {
+            name: self.name,
+            definition: self.definition,
+            code: self.code,
+            remarks: self.remarks,
+            alias: self.alias,
+            definition_reference: self.definition_reference,
+            language: self.language,
+            translations: self.translations,
+            is_abstract: self.is_abstract,
+            attribute_bindings: self.attribute_bindings,
+            information_bindings: self.information_bindings,
+            feature_bindings: self.feature_bindings,
+            super_type: self.super_type,
+            sub_types: self.sub_types,
+            feature_use_type: self.feature_use_type.unwrap(),
+            permitted_primitives: self.permitted_primitives,
+        };
+        check_item(FEATURE_TYPE, &item)?;
+        check_unique(FEATURE_TYPE, &item.attribute_bindings)?;
+        for (index, val) in item.permitted_primitives.iter().enumerate() {
+            if item.permitted_primitives[..index].contains(val) {
+                return S100Error::invalid_field(
+                    FEATURE_TYPE,
+                    PERMITTED_PRIMITIVES,
+                    &format!("'{}' is permitted more than once", val),
+                );
+            }
+        }
+
+        Ok(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libxml::parser::Parser;
+
+    use super::{AttributeBinding, FeatureType, FeatureUseType, Item, SpatialPrimitiveType};
+    use crate::feature::catalog::Multiplicity;
+
+    #[test]
+    fn deserialize() {
+        let xml = r#"
+            <S100FC:S100_FC_FeatureType isAbstract="false" xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+                <S100FC:name>Depth Area</S100FC:name>
+                <S100FC:definition>A water area whose depth is within a defined range of values.</S100FC:definition>
+                <S100FC:code>DepthArea</S100FC:code>
+                <S100FC:alias>DEPARE</S100FC:alias>
+                <S100FC:attributeBinding sequential="false">
+                    <S100FC:multiplicity>
+                        <S100Base:lower>1</S100Base:lower>
+                        <S100Base:upper>1</S100Base:upper>
+                    </S100FC:multiplicity>
+                    <S100FC:attribute ref="depthRangeMinimumValue"/>
+                </S100FC:attributeBinding>
+                <S100FC:attributeBinding sequential="false">
+                    <S100FC:multiplicity>
+                        <S100Base:lower>0</S100Base:lower>
+                        <S100Base:upper>1</S100Base:upper>
+                    </S100FC:multiplicity>
+                    <S100FC:attribute ref="depthRangeMaximumValue"/>
+                </S100FC:attributeBinding>
+                <S100FC:informationBinding roleType="association">
+                    <S100FC:multiplicity>
+                        <S100Base:lower>0</S100Base:lower>
+                        <S100Base:upper xsi:nil="true" infinite="true"/>
+                    </S100FC:multiplicity>
+                    <S100FC:association ref="SpatialAssociation"/>
+                    <S100FC:role ref="theQualityInformation"/>
+                    <S100FC:informationType ref="SpatialQuality"/>
+                </S100FC:informationBinding>
+                <S100FC:featureBinding roleType="association">
+                    <S100FC:multiplicity>
+                        <S100Base:lower>0</S100Base:lower>
+                        <S100Base:upper xsi:nil="true" infinite="true"/>
+                    </S100FC:multiplicity>
+                    <S100FC:association ref="TextAssociation"/>
+                    <S100FC:role ref="thePositionProvider"/>
+                    <S100FC:featureType ref="TextPlacement"/>
+                </S100FC:featureBinding>
+                <S100FC:featureUseType>geographic</S100FC:featureUseType>
+                <S100FC:permittedPrimitives>surface</S100FC:permittedPrimitives>
+            </S100FC:S100_FC_FeatureType>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = FeatureType::parse(node).unwrap();
+
+        assert_eq!(target.name(), "Depth Area");
+        assert_eq!(target.code(), "DepthArea");
+        assert_eq!(target.alias(), Some("DEPARE"));
+        assert!(!target.is_abstract());
+        assert_eq!(target.super_type(), None);
+        assert_eq!(target.feature_use_type(), FeatureUseType::Geographic);
+        assert_eq!(
+            target.permitted_primitives(),
+            &[SpatialPrimitiveType::Surface]
+        );
+
+        let attribute_bindings = target.attribute_bindings();
+        assert_eq!(attribute_bindings.len(), 2);
+        assert_eq!(attribute_bindings[0].attribute(), "depthRangeMinimumValue");
+        assert_eq!(attribute_bindings[1].attribute(), "depthRangeMaximumValue");
+
+        let information_bindings = target.information_bindings();
+        assert_eq!(information_bindings.len(), 1);
+        assert_eq!(information_bindings[0].information_type(), "SpatialQuality");
+
+        let feature_bindings = target.feature_bindings();
+        assert_eq!(feature_bindings.len(), 1);
+        assert_eq!(feature_bindings[0].feature_type(), "TextPlacement");
+    }
+
+    #[test]
+    fn deserialize_abstract() {
+        let xml = r#"
+            <S100FC:S100_FC_FeatureType isAbstract="true" xmlns:S100FC="http://www.iho.int/S100FC">
+                <S100FC:name>Supervised Area</S100FC:name>
+                <S100FC:definition>An area for which there is a supervising authority.</S100FC:definition>
+                <S100FC:code>SupervisedArea</S100FC:code>
+                <S100FC:subType>PilotageDistrict</S100FC:subType>
+                <S100FC:subType>VesselTrafficServiceArea</S100FC:subType>
+                <S100FC:featureUseType>geographic</S100FC:featureUseType>
+                <S100FC:permittedPrimitives>surface</S100FC:permittedPrimitives>
+            </S100FC:S100_FC_FeatureType>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = FeatureType::parse(node).unwrap();
+
+        assert!(target.is_abstract());
+        assert_eq!(
+            target.sub_types(),
+            vec!["PilotageDistrict", "VesselTrafficServiceArea"]
+        );
+    }
+
+    #[test]
+    fn build() {
+        let binding = AttributeBinding::builder()
+            .multiplicity(Multiplicity::new(0, Some(1)).unwrap())
+            .attribute("callSign")
+            .build()
+            .unwrap();
+        let target = FeatureType::builder()
+            .name("Radio Station")
+            .definition("A place equipped to transmit radio waves.")
+            .code("RadioStation")
+            .alias("RDOSTA")
+            .attribute_binding(binding.clone())
+            .feature_use_type(FeatureUseType::Geographic)
+            .permitted_primitive(SpatialPrimitiveType::Point)
+            .build()
+            .unwrap();
+        assert_eq!(target.alias(), Some("RDOSTA"));
+        assert_eq!(target.attribute_bindings().len(), 1);
+        assert_eq!(
+            target.permitted_primitives(),
+            &[SpatialPrimitiveType::Point]
+        );
+
+        let result = FeatureType::builder()
+            .name("Radio Station")
+            .definition("A place equipped to transmit radio waves.")
+            .code("RadioStation")
+            .build();
+        assert!(result.is_err());
+        let result = FeatureType::builder()
+            .name("Radio Station")
+            .definition("A place equipped to transmit radio waves.")
+            .code("RadioStation")
+            .attribute_binding(binding.clone())
+            .attribute_binding(binding)
+            .feature_use_type(FeatureUseType::Geographic)
+            .build();
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,37 @@
+use std::{fmt, str::FromStr};
+
+use crate::S100Error;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FeatureUseType {
+    Geographic,
+    Meta,
+    Cartographic,
+    Theme,
+}
+
+impl FromStr for FeatureUseType {
+    type Err = S100Error;
+
+    fn from_str(input: &str) -> Result<FeatureUseType, Self::Err> {
+        match input {
+            "geographic" => Ok(FeatureUseType::Geographic),
+            "meta" => Ok(FeatureUseType::Meta),
+            "cartographic" => Ok(FeatureUseType::Cartographic),
+            "theme" => Ok(FeatureUseType::Theme),
+            _ => S100Error::invalid_enum("featureUseType", input),
+        }
+    }
+}
+
+impl fmt::Display for FeatureUseType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            FeatureUseType::Geographic => "geographic",
+            FeatureUseType::Meta => "meta",
+            FeatureUseType::Cartographic => "cartographic",
+            FeatureUseType::Theme => "theme",
+        };
+        write!(f, "{}", value)
+    }
+}
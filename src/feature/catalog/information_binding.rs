@@ -0,0 +1,223 @@
+use libxml::tree::Node;
+use std::str::FromStr;
+
+use super::{
+    xml_writer::{add_element, add_reference, set_attribute, Namespaces},
+    Multiplicity, RoleType, FC_NAMESPACES, INFORMATION_BINDING, MULTIPLICITY, XML_REF,
+};
+use crate::{namespace::element_name, Result, S100Error};
+
+const ROLE_TYPE: &str = "roleType";
+const ASSOCIATION: &str = "association";
+const ROLE: &str = "role";
+const INFORMATION_TYPE: &str = "informationType";
+
+#[derive(Clone, Debug)]
+pub struct InformationBinding {
+    multiplicity: Multiplicity,
+    role_type: RoleType,
+    association: String,
+    role: String,
+    information_type: String,
+}
+
+impl InformationBinding {
+    pub fn builder() -> InformationBindingBuilder {
+        InformationBindingBuilder {
+            multiplicity: None,
+            role_type: None,
+            association: String::new(),
+            role: String::new(),
+            information_type: String::new(),
+        }
+    }
+
+    pub(super) fn parse(node: Node) -> Result<InformationBinding> {
+        if element_name(&node, FC_NAMESPACES) != INFORMATION_BINDING {
+            return S100Error::invalid_child(node);
+        }
+
+        let role_type = match node.get_attribute(ROLE_TYPE) {
+            Some(val) => match RoleType::from_str(val.as_str()) {
+                Ok(val) => val,
+                Err(e) => return Err(e.at(&node)),
+            },
+            None => return S100Error::missing_attribute(node, ROLE_TYPE),
+        };
+        let mut multiplicity: Option<Multiplicity> = None;
+        let mut association: Option<String> = None;
+        let mut role: Option<String> = None;
+        let mut information_type: Option<String> = None;
+
+        for child_node in node.get_child_elements() {
+            match element_name(&child_node, FC_NAMESPACES).as_str() {
+                MULTIPLICITY => match Multiplicity::parse(child_node) {
+                    Ok(val) => multiplicity = Some(val),
+                    Err(e) => return Err(e),
+                },
+                ASSOCIATION => match child_node.get_attribute(XML_REF) {
+                    Some(val) => association = Some(val),
+                    None => return S100Error::missing_attribute(child_node, XML_REF),
+                },
+                ROLE => match child_node.get_attribute(XML_REF) {
+                    Some(val) => role = Some(val),
+                    None => return S100Error::missing_attribute(child_node, XML_REF),
+                },
+                INFORMATION_TYPE => match child_node.get_attribute(XML_REF) {
+                    Some(val) => information_type = Some(val),
+                    None => return S100Error::missing_attribute(child_node, XML_REF),
+                },
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        if multiplicity.is_none() {
+            return S100Error::missing_child(node, MULTIPLICITY);
+        }
+        if association.is_none() {
+            return S100Error::missing_child(node, ASSOCIATION);
+        }
+        if role.is_none() {
+            return S100Error::missing_child(node, ROLE);
+        }
+        if information_type.is_none() {
+            return S100Error::missing_child(node, INFORMATION_TYPE);
+        }
+
+        Ok(InformationBinding {
+            multiplicity: multiplicity.unwrap(),
+            role_type,
+            association: association.unwrap(),
+            role: role.unwrap(),
+            information_type: information_type.unwrap(),
+        })
+    }
+
+    pub(super) fn write(&self, parent: &mut Node, namespaces: &Namespaces) -> Result<()> {
+        let mut node = add_element(parent, &namespaces.fc, INFORMATION_BINDING)?;
+        set_attribute(&mut node, ROLE_TYPE, &self.role_type.to_string())?;
+        self.multiplicity.write(&mut node, namespaces)?;
+        add_reference(&mut node, &namespaces.fc, ASSOCIATION, &self.association)?;
+        add_reference(&mut node, &namespaces.fc, ROLE, &self.role)?;
+        add_reference(
+            &mut node,
+            &namespaces.fc,
+            INFORMATION_TYPE,
+            &self.information_type,
+        )
+    }
+
+    pub fn multiplicity(&self) -> Multiplicity {
+        self.multiplicity
+    }
+
+    pub fn role_type(&self) -> RoleType {
+        self.role_type
+    }
+
+    pub fn association(&self) -> &str {
+        self.association.as_str()
+    }
+
+    pub fn role(&self) -> &str {
+        self.role.as_str()
+    }
+
+    pub fn information_type(&self) -> &str {
+        self.information_type.as_str()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct InformationBindingBuilder {
+    multiplicity: Option<Multiplicity>,
+    role_type: Option<RoleType>,
+    association: String,
+    role: String,
+    information_type: String,
+}
+
+impl InformationBindingBuilder {
+    pub fn multiplicity(mut self, multiplicity: Multiplicity) -> InformationBindingBuilder {
+        self.multiplicity = Some(multiplicity);
+        self
+    }
+
+    pub fn role_type(mut self, role_type: RoleType) -> InformationBindingBuilder {
+        self.role_type = Some(role_type);
+        self
+    }
+
+    pub fn association(mut self, association: &str) -> InformationBindingBuilder {
+        self.association = association.to_string();
+        self
+    }
+
+    pub fn role(mut self, role: &str) -> InformationBindingBuilder {
+        self.role = role.to_string();
+        self
+    }
+
+    pub fn information_type(mut self, information_type: &str) -> InformationBindingBuilder {
+        self.information_type = information_type.to_string();
+        self
+    }
+
+    pub fn build(self) -> Result<InformationBinding> {
+        if self.multiplicity.is_none() {
+            return S100Error::missing_field(INFORMATION_BINDING, MULTIPLICITY);
+        }
+        if self.role_type.is_none() {
+            return S100Error::missing_field(INFORMATION_BINDING, ROLE_TYPE);
+        }
+        if self.association.is_empty() {
+            return S100Error::missing_field(INFORMATION_BINDING, ASSOCIATION);
+        }
+        if self.role.is_empty() {
+            return S100Error::missing_field(INFORMATION_BINDING, ROLE);
+        }
+        if self.information_type.is_empty() {
+            return S100Error::missing_field(INFORMATION_BINDING, INFORMATION_TYPE);
+        }
+
+        Ok(InformationBinding {
+            multiplicity: self.multiplicity.unwrap(),
+            role_type: self.role_type.unwrap(),
+            association: self.association,
+            role: self.role,
+            information_type: self.information_type,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libxml::parser::Parser;
+
+    use super::{InformationBinding, RoleType};
+
+    #[test]
+    fn deserialize() {
+        let xml = r#"
+            <S100FC:informationBinding roleType="association" xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+                <S100FC:multiplicity>
+                    <S100Base:lower>0</S100Base:lower>
+                    <S100Base:upper xsi:nil="true" infinite="true"/>
+                </S100FC:multiplicity>
+                <S100FC:association ref="AdditionalInformation"/>
+                <S100FC:role ref="theInformation"/>
+                <S100FC:informationType ref="NauticalInformation"/>
+            </S100FC:informationBinding>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = InformationBinding::parse(node).unwrap();
+
+        assert!(target.multiplicity().is_unbounded());
+        assert_eq!(target.role_type(), RoleType::Association);
+        assert_eq!(target.association(), "AdditionalInformation");
+        assert_eq!(target.role(), "theInformation");
+        assert_eq!(target.information_type(), "NauticalInformation");
+    }
+}
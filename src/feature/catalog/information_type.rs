@@ -0,0 +1,400 @@
+use libxml::tree::Node;
+
+use super::{
+    attribute_binding::check_unique,
+    item::check_item,
+    xml_writer::{add_element, add_item, add_text, set_attribute, Namespaces},
+    AttributeBinding, DefinitionReference, InformationBinding, Item, Translation,
+    ATTRIBUTE_BINDING, DEFINITION_REFERENCE, FC_NAMESPACES, INFORMATION_BINDING, INFORMATION_TYPE,
+    TRANSLATION,
+};
+use crate::{namespace::element_name, Result, S100Error};
+
+const IS_ABSTRACT: &str = "isAbstract";
+const NAME: &str = "name";
+const DEFINITION: &str = "definition";
+const CODE: &str = "code";
+const REMARKS: &str = "remarks";
+const ALIAS: &str = "alias";
+const LANGUAGE: &str = "language";
+const SUPER_TYPE: &str = "superType";
+const SUB_TYPE: &str = "subType";
+
+#[derive(Clone, Debug)]
+pub struct InformationType {
+    name: String,
+    definition: String,
+    code: String,
+    remarks: Option<String>,
+    alias: Option<String>,
+    definition_reference: Option<DefinitionReference>,
+    language: Option<String>,
+    translations: Vec<Translation>,
+    is_abstract: bool,
+    attribute_bindings: Vec<AttributeBinding>,
+    information_bindings: Vec<InformationBinding>,
+    super_type: Option<String>,
+    sub_types: Vec<String>,
+}
+
+impl InformationType {
+    pub fn builder() -> InformationTypeBuilder {
+        InformationTypeBuilder {
+            name: String::new(),
+            definition: String::new(),
+            code: String::new(),
+            remarks: None,
+            alias: None,
+            definition_reference: None,
+            language: None,
+            translations: Vec::new(),
+            is_abstract: false,
+            attribute_bindings: Vec::new(),
+            information_bindings: Vec::new(),
+            super_type: None,
+            sub_types: Vec::new(),
+        }
+    }
+
+    pub(super) fn parse(node: Node) -> Result<InformationType> {
+        if element_name(&node, FC_NAMESPACES) != INFORMATION_TYPE {
+            return S100Error::invalid_child(node);
+        }
+
+        let is_abstract = match node.get_attribute(IS_ABSTRACT) {
+            Some(val) => match val.as_str() {
+                "true" | "1" => true,
+                "false" | "0" => false,
+                _ => return S100Error::invalid_value(node),
+            },
+            None => false,
+        };
+        let mut name: Option<String> = None;
+        let mut definition: Option<String> = None;
+        let mut code: Option<String> = None;
+        let mut remarks: Option<String> = None;
+        let mut alias: Option<String> = None;
+        let mut definition_reference: Option<DefinitionReference> = None;
+        let mut language: Option<String> = None;
+        let mut translations: Vec<Translation> = Vec::new();
+        let mut attribute_bindings: Vec<AttributeBinding> = Vec::new();
+        let mut information_bindings: Vec<InformationBinding> = Vec::new();
+        let mut super_type: Option<String> = None;
+        let mut sub_types: Vec<String> = Vec::new();
+
+        for child_node in node.get_child_elements() {
+            match element_name(&child_node, FC_NAMESPACES).as_str() {
+                NAME => name = Some(child_node.get_content()),
+                DEFINITION => definition = Some(child_node.get_content()),
+                CODE => code = Some(child_node.get_content()),
+                REMARKS => remarks = Some(child_node.get_content()),
+                ALIAS => alias = Some(child_node.get_content()),
+                DEFINITION_REFERENCE => match DefinitionReference::parse(child_node) {
+                    Ok(val) => definition_reference = Some(val),
+                    Err(e) => return Err(e),
+                },
+                LANGUAGE => language = Some(child_node.get_content().trim().to_string()),
+                TRANSLATION => match Translation::parse(child_node) {
+                    Ok(val) => translations.push(val),
+                    Err(e) => return Err(e),
+                },
+                ATTRIBUTE_BINDING => match AttributeBinding::parse(child_node) {
+                    Ok(val) => attribute_bindings.push(val),
+                    Err(e) => return Err(e),
+                },
+                INFORMATION_BINDING => match InformationBinding::parse(child_node) {
+                    Ok(val) => information_bindings.push(val),
+                    Err(e) => return Err(e),
+                },
+                SUPER_TYPE => super_type = Some(child_node.get_content()),
+                SUB_TYPE => sub_types.push(child_node.get_content()),
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        if name.is_none() {
+            return S100Error::missing_child(node, NAME);
+        }
+        if definition.is_none() {
+            return S100Error::missing_child(node, DEFINITION);
+        }
+        if code.is_none() {
+            return S100Error::missing_child(node, CODE);
+        }
+
+        Ok(InformationType {
+            name: name.unwrap(),
+            definition: definition.unwrap(),
+            code: code.unwrap(),
+            remarks,
+            alias,
+            definition_reference,
+            language,
+            translations,
+            is_abstract,
+            attribute_bindings,
+            information_bindings,
+            super_type,
+            sub_types,
+        })
+    }
+
+    pub(super) fn write(&self, parent: &mut Node, namespaces: &Namespaces) -> Result<()> {
+        let mut node = add_element(parent, &namespaces.fc, INFORMATION_TYPE)?;
+        set_attribute(&mut node, IS_ABSTRACT, &self.is_abstract.to_string())?;
+        add_item(
+            &mut node,
+            namespaces,
+            self,
+            self.remarks(),
+            self.definition_reference(),
+        )?;
+        for val in &self.attribute_bindings {
+            val.write(&mut node, namespaces, ATTRIBUTE_BINDING)?;
+        }
+        for val in &self.information_bindings {
+            val.write(&mut node, namespaces)?;
+        }
+        if let Some(val) = &self.super_type {
+            add_text(&mut node, &namespaces.fc, SUPER_TYPE, val)?;
+        }
+        for val in &self.sub_types {
+            add_text(&mut node, &namespaces.fc, SUB_TYPE, val)?;
+        }
+        Ok(())
+    }
+
+    pub fn remarks(&self) -> Option<&str> {
+        match self.remarks.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    pub fn definition_reference(&self) -> Option<&DefinitionReference> {
+        match &self.definition_reference {
+            Some(val) => Some(val),
+            None => None,
+        }
+    }
+
+    pub fn is_abstract(&self) -> bool {
+        self.is_abstract
+    }
+
+    pub fn attribute_bindings(&self) -> &[AttributeBinding] {
+        &self.attribute_bindings
+    }
+
+    pub fn information_bindings(&self) -> &[InformationBinding] {
+        &self.information_bindings
+    }
+
+    pub fn super_type(&self) -> Option<&str> {
+        match self.super_type.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    pub fn sub_types(&self) -> Vec<&str> {
+        self.sub_types.iter().map(|s| s.as_str()).collect()
+    }
+}
+
+impl Item for InformationType {
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn definition(&self) -> &str {
+        self.definition.as_str()
+    }
+
+    fn code(&self) -> &str {
+        self.code.as_str()
+    }
+
+    fn alias(&self) -> Option<&str> {
+        match self.alias.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+    fn language(&self) -> Option<&str> {
+        match self.language.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    fn translations(&self) -> &[Translation] {
+        &self.translations
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct InformationTypeBuilder {
+    name: String,
+    definition: String,
+    code: String,
+    remarks: Option<String>,
+    alias: Option<String>,
+    definition_reference: Option<DefinitionReference>,
+    language: Option<String>,
+    translations: Vec<Translation>,
+    is_abstract: bool,
+    attribute_bindings: Vec<AttributeBinding>,
+    information_bindings: Vec<InformationBinding>,
+    super_type: Option<String>,
+    sub_types: Vec<String>,
+}
+
+impl InformationTypeBuilder {
+    pub fn name(mut self, name: &str) -> InformationTypeBuilder {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn definition(mut self, definition: &str) -> InformationTypeBuilder {
+        self.definition = definition.to_string();
+        self
+    }
+
+    pub fn code(mut self, code: &str) -> InformationTypeBuilder {
+        self.code = code.to_string();
+        self
+    }
+
+    pub fn remarks(mut self, remarks: &str) -> InformationTypeBuilder {
+        self.remarks = Some(remarks.to_string());
+        self
+    }
+
+    pub fn alias(mut self, alias: &str) -> InformationTypeBuilder {
+        self.alias = Some(alias.to_string());
+        self
+    }
+
+    pub fn definition_reference(
+        mut self,
+        definition_reference: DefinitionReference,
+    ) -> InformationTypeBuilder {
+        self.definition_reference = Some(definition_reference);
+        self
+    }
+
+    pub fn language(mut self, language: &str) -> InformationTypeBuilder {
+        self.language = Some(language.to_string());
+        self
+    }
+
+    pub fn translation(mut self, translation: Translation) -> InformationTypeBuilder {
+        self.translations.push(translation);
+        self
+    }
+
+    pub fn is_abstract(mut self, is_abstract: bool) -> InformationTypeBuilder {
+        self.is_abstract = is_abstract;
+        self
+    }
+
+    pub fn attribute_binding(
+        mut self,
+        attribute_binding: AttributeBinding,
+    ) -> InformationTypeBuilder {
+        self.attribute_bindings.push(attribute_binding);
+        self
+    }
+
+    pub fn information_binding(
+        mut self,
+        information_binding: InformationBinding,
+    ) -> InformationTypeBuilder {
+        self.information_bindings.push(information_binding);
+        self
+    }
+
+    pub fn super_type(mut self, super_type: &str) -> InformationTypeBuilder {
+        self.super_type = Some(super_type.to_string());
+        self
+    }
+
+    pub fn sub_type(mut self, sub_type: &str) -> InformationTypeBuilder {
+        self.sub_types.push(sub_type.to_string());
+        self
+    }
+
+    pub fn build(self) -> Result<InformationType> {
+        let item = InformationType {
+            name: self.name,
+            definition: self.definition,
+            code: self.code,
+            remarks: self.remarks,
+            alias: self.alias,
+            definition_reference: self.definition_reference,
+            language: self.language,
+            translations: self.translations,
+            is_abstract: self.is_abstract,
+            attribute_bindings: self.attribute_bindings,
+            information_bindings: self.information_bindings,
+            super_type: self.super_type,
+            sub_types: self.sub_types,
+        };
+        check_item(INFORMATION_TYPE, &item)?;
+        check_unique(INFORMATION_TYPE, &item.attribute_bindings)?;
+
+        Ok(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libxml::parser::Parser;
+
+    use super::{InformationType, Item};
+
+    #[test]
+    fn deserialize() {
+        let xml = r#"
+            <S100FC:S100_FC_InformationType isAbstract="false" xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+                <S100FC:name>Nautical Information</S100FC:name>
+                <S100FC:definition>Provides textual information that cannot be encoded using other attributes.</S100FC:definition>
+                <S100FC:code>NauticalInformation</S100FC:code>
+                <S100FC:attributeBinding sequential="false">
+                    <S100FC:multiplicity>
+                        <S100Base:lower>0</S100Base:lower>
+                        <S100Base:upper xsi:nil="true" infinite="true"/>
+                    </S100FC:multiplicity>
+                    <S100FC:attribute ref="information"/>
+                </S100FC:attributeBinding>
+                <S100FC:informationBinding roleType="association">
+                    <S100FC:multiplicity>
+                        <S100Base:lower>0</S100Base:lower>
+                        <S100Base:upper xsi:nil="true" infinite="true"/>
+                    </S100FC:multiplicity>
+                    <S100FC:association ref="AdditionalInformation"/>
+                    <S100FC:role ref="providesInformation"/>
+                    <S100FC:informationType ref="NauticalInformation"/>
+                </S100FC:informationBinding>
+                <S100FC:superType>AbstractRxN</S100FC:superType>
+            </S100FC:S100_FC_InformationType>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = InformationType::parse(node).unwrap();
+
+        assert_eq!(target.name(), "Nautical Information");
+        assert_eq!(target.code(), "NauticalInformation");
+        assert!(!target.is_abstract());
+        assert_eq!(target.super_type(), Some("AbstractRxN"));
+        assert_eq!(target.attribute_bindings().len(), 1);
+        assert_eq!(target.attribute_bindings()[0].attribute(), "information");
+        assert_eq!(target.information_bindings().len(), 1);
+        assert_eq!(
+            target.information_bindings()[0].role(),
+            "providesInformation"
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use std::{fmt, str::FromStr};
+
+use crate::S100Error;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IntervalType {
+    OpenInterval,
+    GeLtInterval,
+    GtLeInterval,
+    ClosedInterval,
+    GtSemiInterval,
+    GeSemiInterval,
+    LtSemiInterval,
+    LeSemiInterval,
+}
+
+impl FromStr for IntervalType {
+    type Err = S100Error;
+
+    fn from_str(input: &str) -> Result<IntervalType, Self::Err> {
+        match input {
+            "openInterval" => Ok(IntervalType::OpenInterval),
+            "geLtInterval" => Ok(IntervalType::GeLtInterval),
+            "gtLeInterval" => Ok(IntervalType::GtLeInterval),
+            "closedInterval" => Ok(IntervalType::ClosedInterval),
+            "gtSemiInterval" => Ok(IntervalType::GtSemiInterval),
+            "geSemiInterval" => Ok(IntervalType::GeSemiInterval),
+            "ltSemiInterval" => Ok(IntervalType::LtSemiInterval),
+            "leSemiInterval" => Ok(IntervalType::LeSemiInterval),
+            _ => S100Error::invalid_enum("closure", input),
+        }
+    }
+}
+
+impl fmt::Display for IntervalType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            IntervalType::OpenInterval => "openInterval",
+            IntervalType::GeLtInterval => "geLtInterval",
+            IntervalType::GtLeInterval => "gtLeInterval",
+            IntervalType::ClosedInterval => "closedInterval",
+            IntervalType::GtSemiInterval => "gtSemiInterval",
+            IntervalType::GeSemiInterval => "geSemiInterval",
+            IntervalType::LtSemiInterval => "ltSemiInterval",
+            IntervalType::LeSemiInterval => "leSemiInterval",
+        };
+        write!(f, "{}", value)
+    }
+}
@@ -1,5 +1,55 @@
+use super::Translation;
+use crate::{Result, S100Error};
+
+const NAME: &str = "name";
+const DEFINITION: &str = "definition";
+const CODE: &str = "code";
+
 pub trait Item {
     fn name(&self) -> &str;
     fn definition(&self) -> &str;
     fn code(&self) -> &str;
+    fn alias(&self) -> Option<&str>;
+    fn language(&self) -> Option<&str>;
+    fn translations(&self) -> &[Translation];
+
+    fn translation(&self, language: &str) -> Option<&Translation> {
+        self.translations().iter().find(|val| val.matches(language))
+    }
+
+    // Falls back to the untranslated name when no translation exists for the language.
+    fn localized_name(&self, language: &str) -> &str {
+        match self.translation(language) {
+            Some(val) => val.name(),
+            None => self.name(),
+        }
+    }
+
+    fn localized_definition(&self, language: &str) -> &str {
+        match self.translation(language).and_then(|val| val.definition()) {
+            Some(val) => val,
+            None => self.definition(),
+        }
+    }
+}
+
+// Checks the properties every catalogue item needs before a builder hands it out.
+pub(super) fn check_item<T: Item>(item_name: &str, item: &T) -> Result<()> {
+    if item.name().is_empty() {
+        return S100Error::missing_field(item_name, NAME);
+    }
+    if item.definition().is_empty() {
+        return S100Error::missing_field(item_name, DEFINITION);
+    }
+    if item.code().is_empty() {
+        return S100Error::missing_field(item_name, CODE);
+    }
+    if item.code().contains(char::is_whitespace) {
+        return S100Error::invalid_field(
+            item_name,
+            CODE,
+            &format!("'{}' contains whitespace", item.code()),
+        );
+    }
+    Ok(())
 }
@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+use super::Item;
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct ItemIndex {
+    codes: HashMap<String, usize>,
+    aliases: HashMap<String, usize>,
+    names: HashMap<String, usize>,
+}
+
+impl ItemIndex {
+    // When several items share a code, alias or name the first one declared wins.
+    pub(super) fn new<T: Item>(items: &[T]) -> ItemIndex {
+        let mut index = ItemIndex::default();
+
+        for (position, item) in items.iter().enumerate() {
+            index
+                .codes
+                .entry(item.code().to_string())
+                .or_insert(position);
+            if let Some(alias) = item.alias() {
+                index.aliases.entry(alias.to_string()).or_insert(position);
+            }
+            index
+                .names
+                .entry(item.name().to_string())
+                .or_insert(position);
+        }
+
+        index
+    }
+
+    pub(super) fn by_code<'a, T>(&self, items: &'a [T], code: &str) -> Option<&'a T> {
+        match self.codes.get(code) {
+            Some(position) => items.get(*position),
+            None => None,
+        }
+    }
+
+    pub(super) fn by_alias<'a, T>(&self, items: &'a [T], alias: &str) -> Option<&'a T> {
+        match self.aliases.get(alias) {
+            Some(position) => items.get(*position),
+            None => None,
+        }
+    }
+
+    pub(super) fn by_name<'a, T>(&self, items: &'a [T], name: &str) -> Option<&'a T> {
+        match self.names.get(name) {
+            Some(position) => items.get(*position),
+            None => None,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ItemKind {
+    SimpleAttribute,
+    ComplexAttribute,
+    Role,
+    InformationAssociation,
+    FeatureAssociation,
+    InformationType,
+    FeatureType,
+}
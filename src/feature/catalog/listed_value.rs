@@ -1,7 +1,11 @@
 use libxml::tree::Node;
 
-use super::{DefinitionReference, DEFINITION_REFERENCE, LISTED_VALUE};
-use crate::{Result, S100Error};
+use super::{
+    xml_writer::{add_element, add_text, Namespaces},
+    DefinitionReference, Translation, DEFINITION_REFERENCE, FC_NAMESPACES, LISTED_VALUE,
+    TRANSLATION,
+};
+use crate::{namespace::element_name, Result, S100Error};
 
 const LABEL: &str = "label";
 const DEFINITION: &str = "definition";
@@ -13,11 +17,24 @@ pub struct ListedValue {
     definition: String,
     code: u64,
     definition_reference: Option<DefinitionReference>,
+    translations: Vec<Translation>,
 }
 
 impl ListedValue {
+    pub fn builder() -> ListedValueBuilder {
+        ListedValueBuilder {
+            listed_value: ListedValue {
+                label: String::new(),
+                definition: String::new(),
+                code: 0,
+                definition_reference: None,
+                translations: Vec::new(),
+            },
+        }
+    }
+
     pub(super) fn parse(node: Node) -> Result<ListedValue> {
-        if node.get_name() != LISTED_VALUE {
+        if element_name(&node, FC_NAMESPACES) != LISTED_VALUE {
             return S100Error::invalid_child(node);
         }
 
@@ -25,9 +42,10 @@ impl ListedValue {
         let mut definition: Option<String> = None;
         let mut code: Option<u64> = None;
         let mut definition_reference: Option<DefinitionReference> = None;
+        let mut translations: Vec<Translation> = Vec::new();
 
         for child_node in node.get_child_elements() {
-            match child_node.get_name().as_str() {
+            match element_name(&child_node, FC_NAMESPACES).as_str() {
                 LABEL => label = Some(child_node.get_content()),
                 DEFINITION => definition = Some(child_node.get_content()),
                 CODE => match child_node.get_content().parse() {
@@ -38,6 +56,10 @@ impl ListedValue {
                     Ok(val) => definition_reference = Some(val),
                     Err(e) => return Err(e),
                 },
+                TRANSLATION => match Translation::parse(child_node) {
+                    Ok(val) => translations.push(val),
+                    Err(e) => return Err(e),
+                },
                 _ => return S100Error::invalid_child(child_node),
             };
         }
@@ -57,9 +79,24 @@ impl ListedValue {
             definition: definition.unwrap(),
             code: code.unwrap(),
             definition_reference,
+            translations,
         })
     }
 
+    pub(super) fn write(&self, parent: &mut Node, namespaces: &Namespaces) -> Result<()> {
+        let mut node = add_element(parent, &namespaces.fc, LISTED_VALUE)?;
+        add_text(&mut node, &namespaces.fc, LABEL, &self.label)?;
+        add_text(&mut node, &namespaces.fc, DEFINITION, &self.definition)?;
+        add_text(&mut node, &namespaces.fc, CODE, &self.code.to_string())?;
+        if let Some(val) = &self.definition_reference {
+            val.write(&mut node, namespaces)?;
+        }
+        for val in &self.translations {
+            val.write_label(&mut node, namespaces)?;
+        }
+        Ok(())
+    }
+
     pub fn label(&self) -> &str {
         self.label.as_str()
     }
@@ -78,6 +115,79 @@ impl ListedValue {
             None => None,
         }
     }
+
+    pub fn translations(&self) -> &[Translation] {
+        &self.translations
+    }
+
+    pub fn translation(&self, language: &str) -> Option<&Translation> {
+        self.translations.iter().find(|val| val.matches(language))
+    }
+
+    // Falls back to the untranslated label when no translation exists for the language.
+    pub fn localized_label(&self, language: &str) -> &str {
+        match self.translation(language) {
+            Some(val) => val.name(),
+            None => self.label(),
+        }
+    }
+
+    pub fn localized_definition(&self, language: &str) -> &str {
+        match self.translation(language).and_then(|val| val.definition()) {
+            Some(val) => val,
+            None => self.definition(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ListedValueBuilder {
+    listed_value: ListedValue,
+}
+
+impl ListedValueBuilder {
+    pub fn label(mut self, label: &str) -> ListedValueBuilder {
+        self.listed_value.label = label.to_string();
+        self
+    }
+
+    pub fn definition(mut self, definition: &str) -> ListedValueBuilder {
+        self.listed_value.definition = definition.to_string();
+        self
+    }
+
+    pub fn code(mut self, code: u64) -> ListedValueBuilder {
+        self.listed_value.code = code;
+        self
+    }
+
+    pub fn definition_reference(
+        mut self,
+        definition_reference: DefinitionReference,
+    ) -> ListedValueBuilder {
+        self.listed_value.definition_reference = Some(definition_reference);
+        self
+    }
+
+    pub fn translation(mut self, translation: Translation) -> ListedValueBuilder {
+        self.listed_value.translations.push(translation);
+        self
+    }
+
+    pub fn build(self) -> Result<ListedValue> {
+        let listed_value = self.listed_value;
+        if listed_value.label.is_empty() {
+            return S100Error::missing_field(LISTED_VALUE, LABEL);
+        }
+        if listed_value.definition.is_empty() {
+            return S100Error::missing_field(LISTED_VALUE, DEFINITION);
+        }
+        // listed value codes are positive integers
+        if listed_value.code == 0 {
+            return S100Error::missing_field(LISTED_VALUE, CODE);
+        }
+        Ok(listed_value)
+    }
 }
 
 #[cfg(test)]
@@ -103,5 +213,32 @@ mod tests {
         assert_eq!(target.label(), "north cardinal mark");
         assert_eq!(target.definition(), "Quadrant bounded by the true bearing NW-NE taken from the point of interest it should be passed to the north side of the mark.");
         assert_eq!(target.code(), 1);
+        assert!(target.translations().is_empty());
+    }
+
+    #[test]
+    fn deserialize_translations() {
+        let xml = r#"
+            <S100FC:listedValue xmlns:S100FC="http://www.iho.int/S100FC">
+                <S100FC:label>north cardinal mark</S100FC:label>
+                <S100FC:definition>Quadrant bounded by the true bearing NW-NE.</S100FC:definition>
+                <S100FC:code>1</S100FC:code>
+                <S100FC:translation>
+                    <S100FC:label>marque cardinale nord</S100FC:label>
+                    <S100FC:language>fra</S100FC:language>
+                </S100FC:translation>
+            </S100FC:listedValue>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = ListedValue::parse(node).unwrap();
+
+        assert_eq!(target.localized_label("FRA"), "marque cardinale nord");
+        assert_eq!(
+            target.localized_definition("fra"),
+            "Quadrant bounded by the true bearing NW-NE."
+        );
+        assert_eq!(target.localized_label("nor"), "north cardinal mark");
     }
 }
@@ -1,24 +1,143 @@
+use crate::namespace::Namespace;
+
+mod attribute_binding;
+pub use attribute_binding::{AttributeBinding, AttributeBindingBuilder};
+
+mod attribute_constraints;
+pub use attribute_constraints::{AttributeConstraints, AttributeConstraintsBuilder};
+
+mod attribute_value;
+pub use attribute_value::AttributeValue;
+
 mod attribute_value_type;
 pub use attribute_value_type::AttributeValueType;
 
+mod catalog_diff;
+pub use catalog_diff::{Change, ChangeKind};
+
+mod catalog_validation;
+pub use catalog_validation::ValidationIssue;
+
+mod citation;
+pub use citation::{Citation, CitationBuilder, CitationDate};
+
+mod classification_code;
+pub use classification_code::ClassificationCode;
+
+mod codelist_type;
+pub use codelist_type::CodelistType;
+
+mod complex_attribute;
+pub use complex_attribute::{ComplexAttribute, ComplexAttributeBuilder};
+
 mod definition_reference;
 pub use definition_reference::DefinitionReference;
 
+mod definition_source;
+pub use definition_source::DefinitionSource;
+
+mod effective_bindings;
+pub use effective_bindings::EffectiveBindings;
+
+mod feature_association;
+pub use feature_association::{FeatureAssociation, FeatureAssociationBuilder};
+
+mod feature_binding;
+pub use feature_binding::{FeatureBinding, FeatureBindingBuilder};
+
 mod feature_catalog;
-pub use feature_catalog::FeatureCatalog;
+pub use feature_catalog::{FeatureCatalog, FeatureCatalogBuilder};
+
+mod feature_type;
+pub use feature_type::{FeatureType, FeatureTypeBuilder};
+
+mod feature_use_type;
+pub use feature_use_type::FeatureUseType;
+
+mod information_association;
+pub use information_association::{InformationAssociation, InformationAssociationBuilder};
+
+mod information_binding;
+pub use information_binding::{InformationBinding, InformationBindingBuilder};
+
+mod information_type;
+pub use information_type::{InformationType, InformationTypeBuilder};
+
+mod interval_type;
+pub use interval_type::IntervalType;
 
 mod item;
 pub use item::Item;
 
+mod item_index;
+use item_index::ItemIndex;
+
+mod item_kind;
+pub use item_kind::ItemKind;
+
 mod listed_value;
-pub use listed_value::ListedValue;
+pub use listed_value::{ListedValue, ListedValueBuilder};
+
+mod multiplicity;
+pub use multiplicity::Multiplicity;
+
+mod numeric_range;
+pub use numeric_range::NumericRange;
+
+mod responsibility;
+pub use responsibility::Responsibility;
+
+mod role;
+pub use role::{Role, RoleBuilder};
+
+mod role_type;
+pub use role_type::RoleType;
 
 mod simple_attribute;
-pub use simple_attribute::SimpleAttribute;
+pub use simple_attribute::{SimpleAttribute, SimpleAttributeBuilder};
+
+mod spatial_primitive_type;
+pub use spatial_primitive_type::SpatialPrimitiveType;
 
+mod translation;
+pub use translation::Translation;
+
+mod unit_of_measure;
+pub use unit_of_measure::UnitOfMeasure;
+
+mod xml_writer;
+
+const ATTRIBUTE_BINDING: &str = "attributeBinding";
+const CITATION: &str = "CI_Citation";
+const COMPLEX_ATTRIBUTE: &str = "S100_FC_ComplexAttribute";
+const CONSTRAINTS: &str = "constraints";
 const DEFINITION_REFERENCE: &str = "definitionReference";
+const DEFINITION_SOURCE: &str = "S100_FC_DefinitionSource";
+const FEATURE_ASSOCIATION: &str = "S100_FC_FeatureAssociation";
+const FEATURE_BINDING: &str = "featureBinding";
 const FEATURE_CATALOG: &str = "S100_FC_FeatureCatalogue";
+const FEATURE_TYPE: &str = "S100_FC_FeatureType";
+const INFORMATION_ASSOCIATION: &str = "S100_FC_InformationAssociation";
+const INFORMATION_BINDING: &str = "informationBinding";
+const INFORMATION_TYPE: &str = "S100_FC_InformationType";
 const LISTED_VALUE: &str = "listedValue";
+const MULTIPLICITY: &str = "multiplicity";
+const NUMERIC_RANGE: &str = "ACRange";
+const RESPONSIBILITY: &str = "CI_Responsibility";
+const ROLE: &str = "S100_FC_Role";
+const ROLE_REFERENCE: &str = "role";
 const SIMPLE_ATTRIBUTE: &str = "S100_FC_SimpleAttribute";
+const SUB_ATTRIBUTE_BINDING: &str = "subAttributeBinding";
+const TRANSLATION: &str = "translation";
+const UNIT_OF_MEASURE: &str = "unitOfMeasure";
+const UOM: &str = "uom";
 
 const XML_REF: &str = "ref";
+
+const FC_NAMESPACES: &[Namespace] = &[Namespace::FeatureCatalogue];
+const BASE_NAMESPACES: &[Namespace] = &[Namespace::Base];
+const CI_NAMESPACES: &[Namespace] = &[
+    Namespace::Citation,
+    Namespace::IsoCitation,
+    Namespace::IsoMetadata,
+];
@@ -0,0 +1,166 @@
+use libxml::tree::Node;
+use std::fmt;
+
+use super::{
+    xml_writer::{add_element, add_text, set_attribute, Namespaces},
+    BASE_NAMESPACES, FC_NAMESPACES, MULTIPLICITY,
+};
+use crate::{namespace::element_name, Result, S100Error};
+
+const LOWER: &str = "lower";
+const UPPER: &str = "upper";
+const INFINITE: &str = "infinite";
+const XSI_NIL: &str = "xsi:nil";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Multiplicity {
+    lower: u32,
+    upper: Option<u32>,
+}
+
+impl Multiplicity {
+    pub fn new(lower: u32, upper: Option<u32>) -> Result<Multiplicity> {
+        if let Some(val) = upper {
+            if val == 0 || val < lower {
+                return S100Error::invalid_field(
+                    MULTIPLICITY,
+                    UPPER,
+                    &format!("{} is not a valid upper bound for {}", val, lower),
+                );
+            }
+        }
+        Ok(Multiplicity { lower, upper })
+    }
+
+    pub(super) fn parse(node: Node) -> Result<Multiplicity> {
+        if element_name(&node, FC_NAMESPACES) != MULTIPLICITY {
+            return S100Error::invalid_child(node);
+        }
+
+        let mut lower: Option<u32> = None;
+        let mut upper: Option<Option<u32>> = None;
+
+        for child_node in node.get_child_elements() {
+            match element_name(&child_node, BASE_NAMESPACES).as_str() {
+                LOWER => match child_node.get_content().trim().parse() {
+                    Ok(val) => lower = Some(val),
+                    Err(_) => return S100Error::invalid_value(child_node),
+                },
+                UPPER => {
+                    if child_node.get_attribute(INFINITE).as_deref() == Some("true") {
+                        upper = Some(None);
+                    } else {
+                        match child_node.get_content().trim().parse() {
+                            Ok(val) => upper = Some(Some(val)),
+                            Err(_) => return S100Error::invalid_value(child_node),
+                        }
+                    }
+                }
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        if lower.is_none() {
+            return S100Error::missing_child(node, LOWER);
+        }
+        if upper.is_none() {
+            return S100Error::missing_child(node, UPPER);
+        }
+
+        Ok(Multiplicity {
+            lower: lower.unwrap(),
+            upper: upper.unwrap(),
+        })
+    }
+
+    pub(super) fn write(&self, parent: &mut Node, namespaces: &Namespaces) -> Result<()> {
+        let mut node = add_element(parent, &namespaces.fc, MULTIPLICITY)?;
+        add_text(&mut node, &namespaces.base, LOWER, &self.lower.to_string())?;
+        match self.upper {
+            Some(val) => {
+                add_text(&mut node, &namespaces.base, UPPER, &val.to_string())?;
+            }
+            None => {
+                let mut upper_node = add_element(&mut node, &namespaces.base, UPPER)?;
+                set_attribute(&mut upper_node, XSI_NIL, "true")?;
+                set_attribute(&mut upper_node, INFINITE, "true")?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn lower(&self) -> u32 {
+        self.lower
+    }
+
+    pub fn upper(&self) -> Option<u32> {
+        self.upper
+    }
+
+    pub fn is_unbounded(&self) -> bool {
+        self.upper.is_none()
+    }
+}
+
+// Uses the UML notation, e.g. `0..1` or `1..*`.
+impl fmt::Display for Multiplicity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.upper {
+            Some(upper) => write!(f, "{}..{}", self.lower, upper),
+            None => write!(f, "{}..*", self.lower),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libxml::parser::Parser;
+
+    use super::Multiplicity;
+
+    #[test]
+    fn deserialize() {
+        let xml = r#"
+            <S100FC:multiplicity xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base">
+                <S100Base:lower>0</S100Base:lower>
+                <S100Base:upper>1</S100Base:upper>
+            </S100FC:multiplicity>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = Multiplicity::parse(node).unwrap();
+
+        assert_eq!(target.lower(), 0);
+        assert_eq!(target.upper(), Some(1));
+        assert!(!target.is_unbounded());
+    }
+
+    #[test]
+    fn deserialize_unbounded() {
+        let xml = r#"
+            <S100FC:multiplicity xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+                <S100Base:lower>1</S100Base:lower>
+                <S100Base:upper xsi:nil="true" infinite="true"/>
+            </S100FC:multiplicity>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = Multiplicity::parse(node).unwrap();
+
+        assert_eq!(target.lower(), 1);
+        assert_eq!(target.upper(), None);
+        assert!(target.is_unbounded());
+    }
+
+    #[test]
+    fn new() {
+        let target = Multiplicity::new(1, None).unwrap();
+        assert!(target.is_unbounded());
+        let target = Multiplicity::new(0, Some(1)).unwrap();
+        assert_eq!(target.upper(), Some(1));
+        assert!(Multiplicity::new(2, Some(1)).is_err());
+        assert!(Multiplicity::new(0, Some(0)).is_err());
+    }
+}
@@ -0,0 +1,207 @@
+use libxml::tree::Node;
+use std::str::FromStr;
+
+use super::{
+    xml_writer::{add_element, add_text, Namespaces},
+    IntervalType, BASE_NAMESPACES, NUMERIC_RANGE,
+};
+use crate::{namespace::element_name, Result, S100Error};
+
+const LOWER_BOUND: &str = "lowerBound";
+const UPPER_BOUND: &str = "upperBound";
+const CLOSURE: &str = "closure";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NumericRange {
+    lower_bound: Option<f64>,
+    upper_bound: Option<f64>,
+    closure: IntervalType,
+}
+
+impl NumericRange {
+    pub fn new(
+        lower_bound: Option<f64>,
+        upper_bound: Option<f64>,
+        closure: IntervalType,
+    ) -> Result<NumericRange> {
+        let (needs_lower, needs_upper) = match closure {
+            IntervalType::GtSemiInterval | IntervalType::GeSemiInterval => (true, false),
+            IntervalType::LtSemiInterval | IntervalType::LeSemiInterval => (false, true),
+            _ => (true, true),
+        };
+        if needs_lower && lower_bound.is_none() {
+            return S100Error::missing_field(NUMERIC_RANGE, LOWER_BOUND);
+        }
+        if needs_upper && upper_bound.is_none() {
+            return S100Error::missing_field(NUMERIC_RANGE, UPPER_BOUND);
+        }
+        if let (Some(lower), Some(upper)) = (lower_bound, upper_bound) {
+            if lower > upper {
+                return S100Error::invalid_field(
+                    NUMERIC_RANGE,
+                    UPPER_BOUND,
+                    &format!("{} is lower than {}", upper, lower),
+                );
+            }
+        }
+
+        Ok(NumericRange {
+            lower_bound,
+            upper_bound,
+            closure,
+        })
+    }
+
+    pub(super) fn parse(node: Node) -> Result<NumericRange> {
+        if element_name(&node, BASE_NAMESPACES) != NUMERIC_RANGE {
+            return S100Error::invalid_child(node);
+        }
+
+        let mut lower_bound: Option<f64> = None;
+        let mut upper_bound: Option<f64> = None;
+        let mut closure: Option<IntervalType> = None;
+
+        for child_node in node.get_child_elements() {
+            match element_name(&child_node, BASE_NAMESPACES).as_str() {
+                LOWER_BOUND => match child_node.get_content().trim().parse() {
+                    Ok(val) => lower_bound = Some(val),
+                    Err(_) => return S100Error::invalid_value(child_node),
+                },
+                UPPER_BOUND => match child_node.get_content().trim().parse() {
+                    Ok(val) => upper_bound = Some(val),
+                    Err(_) => return S100Error::invalid_value(child_node),
+                },
+                CLOSURE => match IntervalType::from_str(child_node.get_content().trim()) {
+                    Ok(val) => closure = Some(val),
+                    Err(e) => return Err(e.at(&child_node)),
+                },
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        if closure.is_none() {
+            return S100Error::missing_child(node, CLOSURE);
+        }
+
+        let closure = closure.unwrap();
+        let (needs_lower, needs_upper) = match closure {
+            IntervalType::GtSemiInterval | IntervalType::GeSemiInterval => (true, false),
+            IntervalType::LtSemiInterval | IntervalType::LeSemiInterval => (false, true),
+            _ => (true, true),
+        };
+        if needs_lower && lower_bound.is_none() {
+            return S100Error::missing_child(node, LOWER_BOUND);
+        }
+        if needs_upper && upper_bound.is_none() {
+            return S100Error::missing_child(node, UPPER_BOUND);
+        }
+
+        Ok(NumericRange {
+            lower_bound,
+            upper_bound,
+            closure,
+        })
+    }
+
+    pub(super) fn write(&self, parent: &mut Node, namespaces: &Namespaces) -> Result<()> {
+        let mut node = add_element(parent, &namespaces.base, NUMERIC_RANGE)?;
+        if let Some(val) = self.lower_bound {
+            add_text(&mut node, &namespaces.base, LOWER_BOUND, &val.to_string())?;
+        }
+        if let Some(val) = self.upper_bound {
+            add_text(&mut node, &namespaces.base, UPPER_BOUND, &val.to_string())?;
+        }
+        add_text(
+            &mut node,
+            &namespaces.base,
+            CLOSURE,
+            &self.closure.to_string(),
+        )?;
+        Ok(())
+    }
+
+    pub fn lower_bound(&self) -> Option<f64> {
+        self.lower_bound
+    }
+
+    pub fn upper_bound(&self) -> Option<f64> {
+        self.upper_bound
+    }
+
+    pub fn closure(&self) -> IntervalType {
+        self.closure
+    }
+
+    pub fn contains(&self, value: f64) -> bool {
+        let above_lower = |inclusive: bool| match self.lower_bound {
+            Some(lower) if inclusive => value >= lower,
+            Some(lower) => value > lower,
+            None => true,
+        };
+        let below_upper = |inclusive: bool| match self.upper_bound {
+            Some(upper) if inclusive => value <= upper,
+            Some(upper) => value < upper,
+            None => true,
+        };
+
+        match self.closure {
+            IntervalType::OpenInterval => above_lower(false) && below_upper(false),
+            IntervalType::GeLtInterval => above_lower(true) && below_upper(false),
+            IntervalType::GtLeInterval => above_lower(false) && below_upper(true),
+            IntervalType::ClosedInterval => above_lower(true) && below_upper(true),
+            IntervalType::GtSemiInterval => above_lower(false),
+            IntervalType::GeSemiInterval => above_lower(true),
+            IntervalType::LtSemiInterval => below_upper(false),
+            IntervalType::LeSemiInterval => below_upper(true),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libxml::parser::Parser;
+
+    use super::{IntervalType, NumericRange};
+
+    fn parse(xml: &str) -> NumericRange {
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        NumericRange::parse(node).unwrap()
+    }
+
+    #[test]
+    fn deserialize() {
+        let target = parse(
+            r#"
+            <S100Base:ACRange xmlns:S100Base="http://www.iho.int/S100Base">
+                <S100Base:lowerBound>0</S100Base:lowerBound>
+                <S100Base:upperBound>360</S100Base:upperBound>
+                <S100Base:closure>geLtInterval</S100Base:closure>
+            </S100Base:ACRange>"#,
+        );
+
+        assert_eq!(target.lower_bound(), Some(0.0));
+        assert_eq!(target.upper_bound(), Some(360.0));
+        assert_eq!(target.closure(), IntervalType::GeLtInterval);
+        assert!(target.contains(0.0));
+        assert!(target.contains(359.9));
+        assert!(!target.contains(360.0));
+        assert!(!target.contains(-0.1));
+    }
+
+    #[test]
+    fn deserialize_semi_interval() {
+        let target = parse(
+            r#"
+            <S100Base:ACRange xmlns:S100Base="http://www.iho.int/S100Base">
+                <S100Base:lowerBound>0</S100Base:lowerBound>
+                <S100Base:closure>gtSemiInterval</S100Base:closure>
+            </S100Base:ACRange>"#,
+        );
+
+        assert_eq!(target.upper_bound(), None);
+        assert!(!target.contains(0.0));
+        assert!(target.contains(12000.0));
+    }
+}
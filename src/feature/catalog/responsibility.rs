@@ -0,0 +1,169 @@
+use libxml::tree::Node;
+
+use super::{
+    xml_writer::{add_element, add_text, Namespaces},
+    CI_NAMESPACES, RESPONSIBILITY,
+};
+use crate::{namespace::element_name, Result, S100Error};
+
+const ROLE: &str = "role";
+const ROLE_CODE: &str = "CI_RoleCode";
+const CODE_LIST_VALUE: &str = "codeListValue";
+const PARTY: &str = "party";
+const ORGANISATION: &str = "CI_Organisation";
+const INDIVIDUAL: &str = "CI_Individual";
+const NAME: &str = "name";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Responsibility {
+    role: String,
+    parties: Vec<String>,
+}
+
+impl Responsibility {
+    // Accepts either the element typed as a responsibility, or one wrapping a CI_Responsibility.
+    pub fn new(role: &str, parties: &[&str]) -> Result<Responsibility> {
+        if role.is_empty() {
+            return S100Error::missing_field(RESPONSIBILITY, ROLE);
+        }
+        Ok(Responsibility {
+            role: role.to_string(),
+            parties: parties.iter().map(|val| val.to_string()).collect(),
+        })
+    }
+
+    pub(super) fn parse(node: Node) -> Result<Responsibility> {
+        let mut children = node.get_child_elements();
+        if children.len() == 1 && element_name(&children[0], CI_NAMESPACES) == RESPONSIBILITY {
+            return Responsibility::parse(children.remove(0));
+        }
+
+        let mut role: Option<String> = None;
+        let mut parties: Vec<String> = Vec::new();
+
+        for child_node in children {
+            match element_name(&child_node, CI_NAMESPACES).as_str() {
+                ROLE => role = Some(parse_role(child_node)),
+                PARTY => {
+                    for party_node in child_node.get_child_elements() {
+                        match element_name(&party_node, CI_NAMESPACES).as_str() {
+                            ORGANISATION | INDIVIDUAL => {
+                                for name_node in party_node.get_child_elements() {
+                                    if element_name(&name_node, CI_NAMESPACES) == NAME {
+                                        parties.push(name_node.get_content().trim().to_string());
+                                    }
+                                }
+                            }
+                            _ => return S100Error::invalid_child(party_node),
+                        }
+                    }
+                }
+                // The remaining CI_Responsibility properties are not used by feature catalogues.
+                _ => {}
+            };
+        }
+
+        if role.is_none() {
+            return S100Error::missing_child(node, ROLE);
+        }
+
+        Ok(Responsibility {
+            role: role.unwrap(),
+            parties,
+        })
+    }
+
+    pub(super) fn write(
+        &self,
+        parent: &mut Node,
+        namespaces: &Namespaces,
+        name: &str,
+    ) -> Result<()> {
+        let mut node = add_element(parent, &namespaces.fc, name)?;
+        add_text(&mut node, &namespaces.ci, ROLE, &self.role)?;
+        for val in &self.parties {
+            let mut party_node = add_element(&mut node, &namespaces.ci, PARTY)?;
+            let mut organisation_node = add_element(&mut party_node, &namespaces.ci, ORGANISATION)?;
+            add_text(&mut organisation_node, &namespaces.ci, NAME, val)?;
+        }
+        Ok(())
+    }
+
+    pub fn role(&self) -> &str {
+        self.role.as_str()
+    }
+
+    pub fn parties(&self) -> Vec<&str> {
+        self.parties.iter().map(|s| s.as_str()).collect()
+    }
+}
+
+fn parse_role(node: Node) -> String {
+    for child_node in node.get_child_elements() {
+        if element_name(&child_node, CI_NAMESPACES) == ROLE_CODE {
+            if let Some(val) = child_node.get_attribute(CODE_LIST_VALUE) {
+                return val;
+            }
+        }
+    }
+
+    node.get_content().trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use libxml::parser::Parser;
+
+    use super::Responsibility;
+
+    #[test]
+    fn deserialize() {
+        let xml = r#"
+            <S100FC:producer xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100CI="http://www.iho.int/S100CI">
+                <S100CI:role>publisher</S100CI:role>
+                <S100CI:party>
+                    <S100CI:CI_Organisation>
+                        <S100CI:name>International Hydrographic Organization</S100CI:name>
+                    </S100CI:CI_Organisation>
+                </S100CI:party>
+            </S100FC:producer>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = Responsibility::parse(node).unwrap();
+
+        assert_eq!(target.role(), "publisher");
+        assert_eq!(
+            target.parties(),
+            vec!["International Hydrographic Organization"]
+        );
+    }
+
+    #[test]
+    fn deserialize_wrapped() {
+        let xml = r#"
+            <S100FC:producer xmlns:S100FC="http://www.iho.int/S100FC" xmlns:cit="http://standards.iso.org/iso/19115/-3/cit/1.0" xmlns:gco="http://standards.iso.org/iso/19115/-3/gco/1.0">
+                <cit:CI_Responsibility>
+                    <cit:role>
+                        <cit:CI_RoleCode codeList="http://standards.iso.org/iso/19115/resources/Codelists/cat/codelists.xml#CI_RoleCode" codeListValue="pointOfContact">pointOfContact</cit:CI_RoleCode>
+                    </cit:role>
+                    <cit:party>
+                        <cit:CI_Organisation>
+                            <cit:name>
+                                <gco:CharacterString>IHO</gco:CharacterString>
+                            </cit:name>
+                        </cit:CI_Organisation>
+                    </cit:party>
+                </cit:CI_Responsibility>
+            </S100FC:producer>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = Responsibility::parse(node).unwrap();
+
+        assert_eq!(target.role(), "pointOfContact");
+        assert_eq!(target.parties(), vec!["IHO"]);
+    }
+}
@@ -0,0 +1,34 @@
+use std::{fmt, str::FromStr};
+
+use crate::S100Error;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoleType {
+    Association,
+    Aggregation,
+    Composition,
+}
+
+impl FromStr for RoleType {
+    type Err = S100Error;
+
+    fn from_str(input: &str) -> Result<RoleType, Self::Err> {
+        match input {
+            "association" => Ok(RoleType::Association),
+            "aggregation" => Ok(RoleType::Aggregation),
+            "composition" => Ok(RoleType::Composition),
+            _ => S100Error::invalid_enum("roleType", input),
+        }
+    }
+}
+
+impl fmt::Display for RoleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            RoleType::Association => "association",
+            RoleType::Aggregation => "aggregation",
+            RoleType::Composition => "composition",
+        };
+        write!(f, "{}", value)
+    }
+}
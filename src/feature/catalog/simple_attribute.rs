@@ -2,11 +2,16 @@ use libxml::tree::Node;
 use std::str::FromStr;
 
 use super::{
-    AttributeValueType, DefinitionReference, Item, ListedValue, DEFINITION_REFERENCE,
-    SIMPLE_ATTRIBUTE,
+    item::check_item,
+    xml_writer::{add_element, add_item, add_text, Namespaces},
+    AttributeConstraints, AttributeValue, AttributeValueType, CodelistType, DefinitionReference,
+    Item, ListedValue, Translation, UnitOfMeasure, CONSTRAINTS, DEFINITION_REFERENCE,
+    FC_NAMESPACES, SIMPLE_ATTRIBUTE, TRANSLATION, UNIT_OF_MEASURE, UOM,
 };
 use crate::{
+    diagnostics::Diagnostics,
     feature::{concept::QuantitySpecification, QUANTITY_SPECIFICATION},
+    namespace::element_name,
     Result, S100Error,
 };
 
@@ -15,8 +20,11 @@ const DEFINITION: &str = "definition";
 const CODE: &str = "code";
 const REMARKS: &str = "remarks";
 const ALIAS: &str = "alias";
+const LANGUAGE: &str = "language";
 const VALUE_TYPE: &str = "valueType";
 const LISTED_VALUES: &str = "listedValues";
+const CODELIST_TYPE: &str = "codelistType";
+const DICTIONARY_REFERENCE: &str = "dictionaryReference";
 
 #[derive(Clone, Debug)]
 pub struct SimpleAttribute {
@@ -27,13 +35,39 @@ pub struct SimpleAttribute {
     alias: Option<String>,
     value_type: AttributeValueType,
     listed_values: Vec<ListedValue>,
+    codelist_type: Option<CodelistType>,
+    dictionary_reference: Option<String>,
     definition_reference: Option<DefinitionReference>,
+    language: Option<String>,
+    translations: Vec<Translation>,
     quality_specification: Option<QuantitySpecification>,
+    unit_of_measure: Option<UnitOfMeasure>,
+    constraints: Option<AttributeConstraints>,
 }
 
 impl SimpleAttribute {
-    pub(super) fn parse(node: Node) -> Result<SimpleAttribute> {
-        if node.get_name() != SIMPLE_ATTRIBUTE {
+    pub fn builder() -> SimpleAttributeBuilder {
+        SimpleAttributeBuilder {
+            name: String::new(),
+            definition: String::new(),
+            code: String::new(),
+            remarks: None,
+            alias: None,
+            definition_reference: None,
+            language: None,
+            translations: Vec::new(),
+            value_type: None,
+            listed_values: Vec::new(),
+            codelist_type: None,
+            dictionary_reference: None,
+            quality_specification: None,
+            unit_of_measure: None,
+            constraints: None,
+        }
+    }
+
+    pub(super) fn parse(node: Node, diagnostics: &mut Diagnostics) -> Result<SimpleAttribute> {
+        if element_name(&node, FC_NAMESPACES) != SIMPLE_ATTRIBUTE {
             return S100Error::invalid_child(node);
         }
 
@@ -44,11 +78,17 @@ impl SimpleAttribute {
         let mut alias: Option<String> = None;
         let mut value_type: Option<AttributeValueType> = None;
         let mut listed_values: Vec<ListedValue> = Vec::new();
+        let mut codelist_type: Option<CodelistType> = None;
+        let mut dictionary_reference: Option<String> = None;
         let mut definition_reference: Option<DefinitionReference> = None;
+        let mut language: Option<String> = None;
+        let mut translations: Vec<Translation> = Vec::new();
         let mut quality_specification: Option<QuantitySpecification> = None;
+        let mut unit_of_measure: Option<UnitOfMeasure> = None;
+        let mut constraints: Option<AttributeConstraints> = None;
 
         for child_node in node.get_child_elements() {
-            match child_node.get_name().as_str() {
+            match element_name(&child_node, FC_NAMESPACES).as_str() {
                 NAME => name = Some(child_node.get_content()),
                 DEFINITION => definition = Some(child_node.get_content()),
                 CODE => code = Some(child_node.get_content()),
@@ -57,7 +97,7 @@ impl SimpleAttribute {
                 VALUE_TYPE => {
                     match AttributeValueType::from_str(child_node.get_content().as_str()) {
                         Ok(val) => value_type = Some(val),
-                        Err(_) => return S100Error::invalid_value(child_node),
+                        Err(e) => return Err(e.at(&child_node)),
                     }
                 }
                 LISTED_VALUES => {
@@ -68,20 +108,37 @@ impl SimpleAttribute {
                         }
                     }
                 }
+                CODELIST_TYPE => match CodelistType::from_str(child_node.get_content().trim()) {
+                    Ok(val) => codelist_type = Some(val),
+                    Err(e) => return Err(e.at(&child_node)),
+                },
+                DICTIONARY_REFERENCE => {
+                    dictionary_reference = Some(child_node.get_content().trim().to_string())
+                }
                 DEFINITION_REFERENCE => match DefinitionReference::parse(child_node) {
                     Ok(val) => definition_reference = Some(val),
                     Err(e) => return Err(e),
                 },
+                LANGUAGE => language = Some(child_node.get_content().trim().to_string()),
+                TRANSLATION => match Translation::parse(child_node) {
+                    Ok(val) => translations.push(val),
+                    Err(e) => return Err(e),
+                },
                 QUANTITY_SPECIFICATION => {
                     match QuantitySpecification::from_str(child_node.get_content().as_str()) {
                         Ok(val) => quality_specification = Some(val),
-                        Err(_) => return S100Error::invalid_value(child_node),
+                        Err(e) => return Err(e.at(&child_node)),
                     }
                 }
-                _ => {
-                    //TODO: return error if we find unrecognized element
-                    //return Error::invalid_child(child_node);
-                }
+                UOM | UNIT_OF_MEASURE => match UnitOfMeasure::parse(child_node) {
+                    Ok(val) => unit_of_measure = Some(val),
+                    Err(e) => return Err(e),
+                },
+                CONSTRAINTS => match AttributeConstraints::parse(child_node) {
+                    Ok(val) => constraints = Some(val),
+                    Err(e) => return Err(e),
+                },
+                _ => diagnostics.unexpected(&child_node),
             };
         }
 
@@ -106,20 +163,63 @@ impl SimpleAttribute {
             alias,
             value_type: value_type.unwrap(),
             listed_values,
+            codelist_type,
+            dictionary_reference,
             definition_reference,
+            language,
+            translations,
             quality_specification,
+            unit_of_measure,
+            constraints,
         })
     }
 
-    pub fn remarks(&self) -> Option<&str> {
-        match self.remarks.as_ref() {
-            Some(val) => Some(val.as_str()),
-            None => None,
+    pub(super) fn write(&self, parent: &mut Node, namespaces: &Namespaces) -> Result<()> {
+        let mut node = add_element(parent, &namespaces.fc, SIMPLE_ATTRIBUTE)?;
+        add_item(
+            &mut node,
+            namespaces,
+            self,
+            self.remarks(),
+            self.definition_reference(),
+        )?;
+        add_text(
+            &mut node,
+            &namespaces.fc,
+            VALUE_TYPE,
+            &self.value_type.to_string(),
+        )?;
+        if let Some(val) = &self.unit_of_measure {
+            val.write(&mut node, namespaces)?;
+        }
+        if let Some(val) = self.quality_specification {
+            add_text(
+                &mut node,
+                &namespaces.fc,
+                QUANTITY_SPECIFICATION,
+                &val.to_string(),
+            )?;
+        }
+        if let Some(val) = &self.constraints {
+            val.write(&mut node, namespaces)?;
+        }
+        if !self.listed_values.is_empty() {
+            let mut values_node = add_element(&mut node, &namespaces.fc, LISTED_VALUES)?;
+            for val in &self.listed_values {
+                val.write(&mut values_node, namespaces)?;
+            }
         }
+        if let Some(val) = self.codelist_type {
+            add_text(&mut node, &namespaces.fc, CODELIST_TYPE, &val.to_string())?;
+        }
+        if let Some(val) = &self.dictionary_reference {
+            add_text(&mut node, &namespaces.fc, DICTIONARY_REFERENCE, val)?;
+        }
+        Ok(())
     }
 
-    pub fn alias(&self) -> Option<&str> {
-        match self.alias.as_ref() {
+    pub fn remarks(&self) -> Option<&str> {
+        match self.remarks.as_ref() {
             Some(val) => Some(val.as_str()),
             None => None,
         }
@@ -133,6 +233,17 @@ impl SimpleAttribute {
         &self.listed_values
     }
 
+    pub fn codelist_type(&self) -> Option<CodelistType> {
+        self.codelist_type
+    }
+
+    pub fn dictionary_reference(&self) -> Option<&str> {
+        match self.dictionary_reference.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
     pub fn definition_reference(&self) -> Option<&DefinitionReference> {
         match &self.definition_reference {
             Some(val) => Some(val),
@@ -143,6 +254,20 @@ impl SimpleAttribute {
     pub fn quality_specification(&self) -> Option<QuantitySpecification> {
         self.quality_specification
     }
+
+    pub fn unit_of_measure(&self) -> Option<&UnitOfMeasure> {
+        self.unit_of_measure.as_ref()
+    }
+
+    pub fn constraints(&self) -> Option<&AttributeConstraints> {
+        self.constraints.as_ref()
+    }
+
+    // Checks the value type, listed values, string length, numeric range and precision
+    // constraints. Attributes with a textPattern constraint fail with UnsupportedConstraint.
+    pub fn parse_value(&self, input: &str) -> Result<AttributeValue> {
+        AttributeValue::parse(self, input)
+    }
 }
 
 impl Item for SimpleAttribute {
@@ -157,13 +282,201 @@ impl Item for SimpleAttribute {
     fn code(&self) -> &str {
         self.code.as_str()
     }
+
+    fn alias(&self) -> Option<&str> {
+        match self.alias.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+    fn language(&self) -> Option<&str> {
+        match self.language.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    fn translations(&self) -> &[Translation] {
+        &self.translations
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SimpleAttributeBuilder {
+    name: String,
+    definition: String,
+    code: String,
+    remarks: Option<String>,
+    alias: Option<String>,
+    definition_reference: Option<DefinitionReference>,
+    language: Option<String>,
+    translations: Vec<Translation>,
+    value_type: Option<AttributeValueType>,
+    listed_values: Vec<ListedValue>,
+    codelist_type: Option<CodelistType>,
+    dictionary_reference: Option<String>,
+    quality_specification: Option<QuantitySpecification>,
+    unit_of_measure: Option<UnitOfMeasure>,
+    constraints: Option<AttributeConstraints>,
+}
+
+impl SimpleAttributeBuilder {
+    pub fn name(mut self, name: &str) -> SimpleAttributeBuilder {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn definition(mut self, definition: &str) -> SimpleAttributeBuilder {
+        self.definition = definition.to_string();
+        self
+    }
+
+    pub fn code(mut self, code: &str) -> SimpleAttributeBuilder {
+        self.code = code.to_string();
+        self
+    }
+
+    pub fn remarks(mut self, remarks: &str) -> SimpleAttributeBuilder {
+        self.remarks = Some(remarks.to_string());
+        self
+    }
+
+    pub fn alias(mut self, alias: &str) -> SimpleAttributeBuilder {
+        self.alias = Some(alias.to_string());
+        self
+    }
+
+    pub fn definition_reference(
+        mut self,
+        definition_reference: DefinitionReference,
+    ) -> SimpleAttributeBuilder {
+        self.definition_reference = Some(definition_reference);
+        self
+    }
+
+    pub fn language(mut self, language: &str) -> SimpleAttributeBuilder {
+        self.language = Some(language.to_string());
+        self
+    }
+
+    pub fn translation(mut self, translation: Translation) -> SimpleAttributeBuilder {
+        self.translations.push(translation);
+        self
+    }
+
+    pub fn value_type(mut self, value_type: AttributeValueType) -> SimpleAttributeBuilder {
+        self.value_type = Some(value_type);
+        self
+    }
+
+    pub fn listed_value(mut self, listed_value: ListedValue) -> SimpleAttributeBuilder {
+        self.listed_values.push(listed_value);
+        self
+    }
+
+    pub fn codelist_type(mut self, codelist_type: CodelistType) -> SimpleAttributeBuilder {
+        self.codelist_type = Some(codelist_type);
+        self
+    }
+
+    pub fn dictionary_reference(mut self, dictionary_reference: &str) -> SimpleAttributeBuilder {
+        self.dictionary_reference = Some(dictionary_reference.to_string());
+        self
+    }
+
+    pub fn quality_specification(
+        mut self,
+        quality_specification: QuantitySpecification,
+    ) -> SimpleAttributeBuilder {
+        self.quality_specification = Some(quality_specification);
+        self
+    }
+
+    pub fn unit_of_measure(mut self, unit_of_measure: UnitOfMeasure) -> SimpleAttributeBuilder {
+        self.unit_of_measure = Some(unit_of_measure);
+        self
+    }
+
+    pub fn constraints(mut self, constraints: AttributeConstraints) -> SimpleAttributeBuilder {
+        self.constraints = Some(constraints);
+        self
+    }
+
+    pub fn build(self) -> Result<SimpleAttribute> {
+        if self.value_type.is_none() {
+            return S100Error::missing_field(SIMPLE_ATTRIBUTE, VALUE_TYPE);
+        }
+        let item = SimpleAttribute {
+            name: self.name,
+            definition: self.definition,
+            code: self.code,
+            remarks: self.remarks,
+            alias: self.alias,
+            definition_reference: self.definition_reference,
+            language: self.language,
+            translations: self.translations,
+            value_type: self.value_type.unwrap(),
+            listed_values: self.listed_values,
+            codelist_type: self.codelist_type,
+            dictionary_reference: self.dictionary_reference,
+            quality_specification: self.quality_specification,
+            unit_of_measure: self.unit_of_measure,
+            constraints: self.constraints,
+        };
+        check_item(SIMPLE_ATTRIBUTE, &item)?;
+        match item.value_type {
+            AttributeValueType::Enumeration => {
+                if item.listed_values.is_empty() {
+                    return S100Error::missing_field(SIMPLE_ATTRIBUTE, LISTED_VALUES);
+                }
+            }
+            AttributeValueType::Codelist => {}
+            _ => {
+                if !item.listed_values.is_empty() {
+                    return S100Error::invalid_field(
+                        SIMPLE_ATTRIBUTE,
+                        LISTED_VALUES,
+                        &format!("'{}' attributes have no listed values", item.value_type),
+                    );
+                }
+            }
+        }
+        if item.codelist_type.is_some() && item.value_type != AttributeValueType::Codelist {
+            return S100Error::invalid_field(
+                SIMPLE_ATTRIBUTE,
+                CODELIST_TYPE,
+                &format!("'{}' attributes are not codelists", item.value_type),
+            );
+        }
+        for (index, val) in item.listed_values.iter().enumerate() {
+            if item.listed_values[..index]
+                .iter()
+                .any(|listed_value| listed_value.code() == val.code())
+            {
+                return S100Error::invalid_field(
+                    SIMPLE_ATTRIBUTE,
+                    LISTED_VALUES,
+                    &format!("code {} is listed more than once", val.code()),
+                );
+            }
+        }
+
+        Ok(item)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use libxml::parser::Parser;
 
-    use super::{AttributeValueType, Item, SimpleAttribute};
+    use super::{
+        AttributeValue, AttributeValueType, CodelistType, Item, ListedValue, SimpleAttribute,
+    };
+    use crate::{
+        diagnostics::Diagnostics,
+        feature::concept::{QuantitySpecification, Unit},
+        ParseMode,
+    };
 
     #[test]
     fn deserialize() {
@@ -191,7 +504,8 @@ mod tests {
         let parser = Parser::default();
         let document = parser.parse_string(xml).unwrap();
         let node = document.get_root_element().unwrap();
-        let target = SimpleAttribute::parse(node).unwrap();
+        let target =
+            SimpleAttribute::parse(node, &mut Diagnostics::new(ParseMode::Strict)).unwrap();
 
         assert_eq!(target.name(), "Category of checkpoint");
         assert_eq!(target.definition(), "Classification of a place where vehicles or travellers are stopped for identification or inspection");
@@ -199,5 +513,194 @@ mod tests {
         assert_eq!(target.remarks(), None);
         assert_eq!(target.alias(), Some("CATCHP"));
         assert_eq!(target.value_type(), AttributeValueType::Enumeration);
+        assert!(target.constraints().is_none());
+        assert!(target.unit_of_measure().is_none());
+    }
+
+    #[test]
+    fn deserialize_translations() {
+        let xml = r#"
+            <S100FC:S100_FC_SimpleAttribute xmlns:S100FC="http://www.iho.int/S100FC">
+                <S100FC:name>Call sign</S100FC:name>
+                <S100FC:definition>The designated call-sign of a radio station.</S100FC:definition>
+                <S100FC:code>callSign</S100FC:code>
+                <S100FC:language>eng</S100FC:language>
+                <S100FC:translation>
+                    <S100FC:name>Indicatif d'appel</S100FC:name>
+                    <S100FC:definition>L'indicatif d'appel d'une station radio.</S100FC:definition>
+                    <S100FC:language>fra</S100FC:language>
+                </S100FC:translation>
+                <S100FC:translation>
+                    <S100FC:name>Kallesignal</S100FC:name>
+                    <S100FC:language>nor</S100FC:language>
+                </S100FC:translation>
+                <S100FC:valueType>text</S100FC:valueType>
+            </S100FC:S100_FC_SimpleAttribute>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target =
+            SimpleAttribute::parse(node, &mut Diagnostics::new(ParseMode::Strict)).unwrap();
+
+        assert_eq!(target.language(), Some("eng"));
+        assert_eq!(target.translations().len(), 2);
+        assert_eq!(target.localized_name("fra"), "Indicatif d'appel");
+        assert_eq!(target.localized_name("NOR"), "Kallesignal");
+        assert_eq!(target.localized_name("deu"), "Call sign");
+        assert_eq!(
+            target.localized_definition("fra"),
+            "L'indicatif d'appel d'une station radio."
+        );
+        assert_eq!(
+            target.localized_definition("nor"),
+            "The designated call-sign of a radio station."
+        );
+    }
+
+    #[test]
+    fn deserialize_constraints() {
+        let xml = r#"
+            <S100FC:S100_FC_SimpleAttribute xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base">
+                <S100FC:name>Orientation Value</S100FC:name>
+                <S100FC:definition>The angular distance measured from true north to the major axis of the feature.</S100FC:definition>
+                <S100FC:code>orientationValue</S100FC:code>
+                <S100FC:alias>ORIENT</S100FC:alias>
+                <S100FC:valueType>real</S100FC:valueType>
+                <S100FC:quantitySpecification>planeAngle</S100FC:quantitySpecification>
+                <S100FC:constraints>
+                    <S100Base:ACRange>
+                        <S100Base:lowerBound>0</S100Base:lowerBound>
+                        <S100Base:upperBound>360</S100Base:upperBound>
+                        <S100Base:closure>geLtInterval</S100Base:closure>
+                    </S100Base:ACRange>
+                    <S100Base:precision>1</S100Base:precision>
+                </S100FC:constraints>
+            </S100FC:S100_FC_SimpleAttribute>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target =
+            SimpleAttribute::parse(node, &mut Diagnostics::new(ParseMode::Strict)).unwrap();
+
+        assert_eq!(target.value_type(), AttributeValueType::Real);
+
+        let constraints = target.constraints().unwrap();
+        assert_eq!(constraints.precision(), Some(1));
+        assert!(constraints.range().unwrap().contains(359.5));
+        assert!(!constraints.range().unwrap().contains(360.0));
+    }
+
+    #[test]
+    fn deserialize_unit_of_measure() {
+        let xml = r#"
+            <S100FC:S100_FC_SimpleAttribute xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base">
+                <S100FC:name>Value of Sounding</S100FC:name>
+                <S100FC:definition>The value of the depth at a specific location.</S100FC:definition>
+                <S100FC:code>valueOfSounding</S100FC:code>
+                <S100FC:alias>VALSOU</S100FC:alias>
+                <S100FC:valueType>real</S100FC:valueType>
+                <S100FC:uom>
+                    <S100Base:name>metres</S100Base:name>
+                    <S100Base:symbol>m</S100Base:symbol>
+                </S100FC:uom>
+                <S100FC:quantitySpecification>length</S100FC:quantitySpecification>
+            </S100FC:S100_FC_SimpleAttribute>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target =
+            SimpleAttribute::parse(node, &mut Diagnostics::new(ParseMode::Strict)).unwrap();
+
+        assert_eq!(
+            target.quality_specification(),
+            Some(QuantitySpecification::Length)
+        );
+
+        let unit_of_measure = target.unit_of_measure().unwrap();
+        assert_eq!(unit_of_measure.name(), "metres");
+        assert_eq!(unit_of_measure.unit(), Some(Unit::Metre));
+        let feet = unit_of_measure.convert(10.0, Unit::Foot).unwrap();
+        assert!((feet - 32.808_398_950_131_23).abs() < 1e-9);
+    }
+
+    #[test]
+    fn deserialize_codelist() {
+        let xml = r#"
+            <S100FC:S100_FC_SimpleAttribute xmlns:S100FC="http://www.iho.int/S100FC">
+                <S100FC:name>Nationality</S100FC:name>
+                <S100FC:definition>The nationality of a person or organisation.</S100FC:definition>
+                <S100FC:code>nationality</S100FC:code>
+                <S100FC:alias>NATION</S100FC:alias>
+                <S100FC:valueType>S100_Codelist</S100FC:valueType>
+                <S100FC:codelistType>dictionary</S100FC:codelistType>
+                <S100FC:dictionaryReference>ISO 3166-1 alpha-2</S100FC:dictionaryReference>
+            </S100FC:S100_FC_SimpleAttribute>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target =
+            SimpleAttribute::parse(node, &mut Diagnostics::new(ParseMode::Strict)).unwrap();
+
+        assert_eq!(target.value_type(), AttributeValueType::Codelist);
+        assert_eq!(target.codelist_type(), Some(CodelistType::Dictionary));
+        assert_eq!(target.dictionary_reference(), Some("ISO 3166-1 alpha-2"));
+        assert!(target.listed_values().is_empty());
+    }
+
+    #[test]
+    fn build() {
+        let listed_value = ListedValue::builder()
+            .label("customs")
+            .definition("A customs office.")
+            .code(1)
+            .build()
+            .unwrap();
+        let target = SimpleAttribute::builder()
+            .name("Category of checkpoint")
+            .definition("Classification of a checkpoint.")
+            .code("categoryOfCheckpoint")
+            .value_type(AttributeValueType::Enumeration)
+            .listed_value(listed_value.clone())
+            .build()
+            .unwrap();
+        assert_eq!(target.code(), "categoryOfCheckpoint");
+        assert_eq!(target.listed_values().len(), 1);
+        assert_eq!(
+            target.parse_value("1").unwrap(),
+            AttributeValue::Enumeration(1)
+        );
+
+        let result = SimpleAttribute::builder()
+            .name("Category of checkpoint")
+            .definition("Classification of a checkpoint.")
+            .code("categoryOfCheckpoint")
+            .build();
+        assert!(result.is_err());
+        let result = SimpleAttribute::builder()
+            .name("Category of checkpoint")
+            .definition("Classification of a checkpoint.")
+            .code("categoryOfCheckpoint")
+            .value_type(AttributeValueType::Enumeration)
+            .build();
+        assert!(result.is_err());
+        let result = SimpleAttribute::builder()
+            .name("Call sign")
+            .definition("The designated call-sign of a radio station.")
+            .code("call sign")
+            .value_type(AttributeValueType::Text)
+            .build();
+        assert!(result.is_err());
+        let result = SimpleAttribute::builder()
+            .name("Call sign")
+            .definition("The designated call-sign of a radio station.")
+            .code("callSign")
+            .value_type(AttributeValueType::Text)
+            .listed_value(listed_value)
+            .build();
+        assert!(result.is_err());
     }
 }
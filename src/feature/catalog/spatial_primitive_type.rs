@@ -0,0 +1,49 @@
+use std::{fmt, str::FromStr};
+
+use crate::S100Error;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SpatialPrimitiveType {
+    Point,
+    PointSet,
+    Curve,
+    Surface,
+    Coverage,
+    ArcByCenterPoint,
+    CircleByCenterPoint,
+    NoGeometry,
+}
+
+impl FromStr for SpatialPrimitiveType {
+    type Err = S100Error;
+
+    fn from_str(input: &str) -> Result<SpatialPrimitiveType, Self::Err> {
+        match input {
+            "point" => Ok(SpatialPrimitiveType::Point),
+            "pointSet" => Ok(SpatialPrimitiveType::PointSet),
+            "curve" => Ok(SpatialPrimitiveType::Curve),
+            "surface" => Ok(SpatialPrimitiveType::Surface),
+            "coverage" => Ok(SpatialPrimitiveType::Coverage),
+            "arcByCenterPoint" => Ok(SpatialPrimitiveType::ArcByCenterPoint),
+            "circleByCenterPoint" => Ok(SpatialPrimitiveType::CircleByCenterPoint),
+            "noGeometry" => Ok(SpatialPrimitiveType::NoGeometry),
+            _ => S100Error::invalid_enum("permittedPrimitives", input),
+        }
+    }
+}
+
+impl fmt::Display for SpatialPrimitiveType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            SpatialPrimitiveType::Point => "point",
+            SpatialPrimitiveType::PointSet => "pointSet",
+            SpatialPrimitiveType::Curve => "curve",
+            SpatialPrimitiveType::Surface => "surface",
+            SpatialPrimitiveType::Coverage => "coverage",
+            SpatialPrimitiveType::ArcByCenterPoint => "arcByCenterPoint",
+            SpatialPrimitiveType::CircleByCenterPoint => "circleByCenterPoint",
+            SpatialPrimitiveType::NoGeometry => "noGeometry",
+        };
+        write!(f, "{}", value)
+    }
+}
@@ -0,0 +1,134 @@
+use libxml::tree::Node;
+
+use super::{
+    xml_writer::{add_element, add_text, Namespaces},
+    FC_NAMESPACES, TRANSLATION,
+};
+use crate::{namespace::element_name, Result, S100Error};
+
+const NAME: &str = "name";
+const LABEL: &str = "label";
+const DEFINITION: &str = "definition";
+const LANGUAGE: &str = "language";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Translation {
+    name: String,
+    definition: Option<String>,
+    language: String,
+}
+
+impl Translation {
+    pub fn new(language: &str, name: &str, definition: Option<&str>) -> Result<Translation> {
+        if language.is_empty() {
+            return S100Error::missing_field(TRANSLATION, LANGUAGE);
+        }
+        if name.is_empty() {
+            return S100Error::missing_field(TRANSLATION, NAME);
+        }
+        Ok(Translation {
+            name: name.to_string(),
+            definition: definition.map(|val| val.to_string()),
+            language: language.to_string(),
+        })
+    }
+
+    pub(super) fn parse(node: Node) -> Result<Translation> {
+        if element_name(&node, FC_NAMESPACES) != TRANSLATION {
+            return S100Error::invalid_child(node);
+        }
+
+        let mut name: Option<String> = None;
+        let mut definition: Option<String> = None;
+        let mut language: Option<String> = None;
+
+        for child_node in node.get_child_elements() {
+            match element_name(&child_node, FC_NAMESPACES).as_str() {
+                // Translations of listed values carry a label rather than a name.
+                NAME | LABEL => name = Some(child_node.get_content()),
+                DEFINITION => definition = Some(child_node.get_content()),
+                LANGUAGE => language = Some(child_node.get_content().trim().to_string()),
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        if name.is_none() {
+            return S100Error::missing_child(node, NAME);
+        }
+        if language.is_none() {
+            return S100Error::missing_child(node, LANGUAGE);
+        }
+
+        Ok(Translation {
+            name: name.unwrap(),
+            definition,
+            language: language.unwrap(),
+        })
+    }
+
+    pub(super) fn write(&self, parent: &mut Node, namespaces: &Namespaces) -> Result<()> {
+        self.write_as(parent, namespaces, NAME)
+    }
+
+    pub(super) fn write_label(&self, parent: &mut Node, namespaces: &Namespaces) -> Result<()> {
+        self.write_as(parent, namespaces, LABEL)
+    }
+
+    fn write_as(&self, parent: &mut Node, namespaces: &Namespaces, name: &str) -> Result<()> {
+        let mut node = add_element(parent, &namespaces.fc, TRANSLATION)?;
+        add_text(&mut node, &namespaces.fc, name, &self.name)?;
+        if let Some(val) = &self.definition {
+            add_text(&mut node, &namespaces.fc, DEFINITION, val)?;
+        }
+        add_text(&mut node, &namespaces.fc, LANGUAGE, &self.language)?;
+        Ok(())
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    pub fn definition(&self) -> Option<&str> {
+        match self.definition.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    pub fn language(&self) -> &str {
+        self.language.as_str()
+    }
+
+    pub(super) fn matches(&self, language: &str) -> bool {
+        self.language.eq_ignore_ascii_case(language)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libxml::parser::Parser;
+
+    use super::Translation;
+
+    #[test]
+    fn deserialize() {
+        let xml = r#"
+            <S100FC:translation xmlns:S100FC="http://www.iho.int/S100FC">
+                <S100FC:name>Indicatif d'appel</S100FC:name>
+                <S100FC:definition>L'indicatif d'appel d'une station radio.</S100FC:definition>
+                <S100FC:language>fra</S100FC:language>
+            </S100FC:translation>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = Translation::parse(node).unwrap();
+
+        assert_eq!(target.name(), "Indicatif d'appel");
+        assert_eq!(
+            target.definition(),
+            Some("L'indicatif d'appel d'une station radio.")
+        );
+        assert_eq!(target.language(), "fra");
+    }
+}
@@ -0,0 +1,159 @@
+use libxml::tree::Node;
+use std::str::FromStr;
+
+use super::{
+    xml_writer::{add_element, add_text, Namespaces},
+    BASE_NAMESPACES, FC_NAMESPACES, UNIT_OF_MEASURE, UOM,
+};
+use crate::{feature::concept::Unit, namespace::element_name, Result, S100Error};
+
+const NAME: &str = "name";
+const DEFINITION: &str = "definition";
+const SYMBOL: &str = "symbol";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnitOfMeasure {
+    name: String,
+    definition: Option<String>,
+    symbol: Option<String>,
+}
+
+impl UnitOfMeasure {
+    pub fn new(
+        name: &str,
+        definition: Option<&str>,
+        symbol: Option<&str>,
+    ) -> Result<UnitOfMeasure> {
+        if name.is_empty() {
+            return S100Error::missing_field(UOM, NAME);
+        }
+        Ok(UnitOfMeasure {
+            name: name.to_string(),
+            definition: definition.map(|val| val.to_string()),
+            symbol: symbol.map(|val| val.to_string()),
+        })
+    }
+
+    pub(super) fn parse(node: Node) -> Result<UnitOfMeasure> {
+        match element_name(&node, FC_NAMESPACES).as_str() {
+            UOM | UNIT_OF_MEASURE => {}
+            _ => return S100Error::invalid_child(node),
+        }
+
+        let mut name: Option<String> = None;
+        let mut definition: Option<String> = None;
+        let mut symbol: Option<String> = None;
+
+        for child_node in node.get_child_elements() {
+            match element_name(&child_node, BASE_NAMESPACES).as_str() {
+                NAME => name = Some(child_node.get_content()),
+                DEFINITION => definition = Some(child_node.get_content()),
+                SYMBOL => symbol = Some(child_node.get_content()),
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        if name.is_none() {
+            return S100Error::missing_child(node, NAME);
+        }
+
+        Ok(UnitOfMeasure {
+            name: name.unwrap(),
+            definition,
+            symbol,
+        })
+    }
+
+    pub(super) fn write(&self, parent: &mut Node, namespaces: &Namespaces) -> Result<()> {
+        let mut node = add_element(parent, &namespaces.fc, UOM)?;
+        add_text(&mut node, &namespaces.base, NAME, &self.name)?;
+        if let Some(val) = &self.definition {
+            add_text(&mut node, &namespaces.base, DEFINITION, val)?;
+        }
+        if let Some(val) = &self.symbol {
+            add_text(&mut node, &namespaces.base, SYMBOL, val)?;
+        }
+        Ok(())
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    pub fn definition(&self) -> Option<&str> {
+        match self.definition.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    pub fn symbol(&self) -> Option<&str> {
+        match self.symbol.as_ref() {
+            Some(val) => Some(val.as_str()),
+            None => None,
+        }
+    }
+
+    // Catalogues are inconsistent about filling in the symbol, so fall back to the name.
+    pub fn unit(&self) -> Option<Unit> {
+        if let Some(symbol) = self.symbol() {
+            if let Ok(unit) = Unit::from_str(symbol) {
+                return Some(unit);
+            }
+        }
+
+        Unit::from_str(self.name()).ok()
+    }
+
+    pub fn convert(&self, value: f64, to: Unit) -> Option<f64> {
+        match self.unit() {
+            Some(unit) => unit.convert(value, to),
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libxml::parser::Parser;
+
+    use super::{Unit, UnitOfMeasure};
+
+    #[test]
+    fn deserialize() {
+        let xml = r#"
+            <S100FC:uom xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base">
+                <S100Base:name>metres</S100Base:name>
+                <S100Base:definition>The length of the path travelled by light in vacuum during 1/299792458 of a second.</S100Base:definition>
+                <S100Base:symbol>m</S100Base:symbol>
+            </S100FC:uom>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = UnitOfMeasure::parse(node).unwrap();
+
+        assert_eq!(target.name(), "metres");
+        assert_eq!(target.symbol(), Some("m"));
+        assert_eq!(target.unit(), Some(Unit::Metre));
+        assert!((target.convert(18.288, Unit::Fathom).unwrap() - 10.0).abs() < 1e-9);
+        assert_eq!(target.convert(1.0, Unit::Knot), None);
+    }
+
+    #[test]
+    fn deserialize_name_only() {
+        let xml = r#"
+            <S100FC:unitOfMeasure xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base">
+                <S100Base:name>knots</S100Base:name>
+            </S100FC:unitOfMeasure>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = UnitOfMeasure::parse(node).unwrap();
+
+        assert_eq!(target.definition(), None);
+        assert_eq!(target.symbol(), None);
+        assert_eq!(target.unit(), Some(Unit::Knot));
+    }
+}
@@ -0,0 +1,128 @@
+use libxml::tree::{Namespace, Node};
+
+use super::{DefinitionReference, Item, XML_REF};
+use crate::{Result, S100Error};
+
+const NAME: &str = "name";
+const DEFINITION: &str = "definition";
+const CODE: &str = "code";
+const REMARKS: &str = "remarks";
+const ALIAS: &str = "alias";
+const LANGUAGE: &str = "language";
+
+const S100FC_PREFIX: &str = "S100FC";
+const S100FC_HREF: &str = "http://www.iho.int/S100FC";
+const S100BASE_PREFIX: &str = "S100Base";
+const S100BASE_HREF: &str = "http://www.iho.int/S100Base";
+const S100CI_PREFIX: &str = "S100CI";
+const S100CI_HREF: &str = "http://www.iho.int/S100CI";
+const XSI_PREFIX: &str = "xsi";
+const XSI_HREF: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+pub(super) struct Namespaces {
+    pub(super) fc: Namespace,
+    pub(super) base: Namespace,
+    pub(super) ci: Namespace,
+}
+
+impl Namespaces {
+    // Declares every namespace used by a feature catalogue on the root element.
+    pub(super) fn declare(root: &mut Node) -> Result<Namespaces> {
+        let fc = namespace(S100FC_PREFIX, S100FC_HREF, root)?;
+        let base = namespace(S100BASE_PREFIX, S100BASE_HREF, root)?;
+        let ci = namespace(S100CI_PREFIX, S100CI_HREF, root)?;
+        namespace(XSI_PREFIX, XSI_HREF, root)?;
+
+        match root.set_namespace(&fc) {
+            Ok(_) => Ok(Namespaces { fc, base, ci }),
+            Err(e) => Err(S100Error::Write {
+                name: root.get_name(),
+                reason: e.to_string(),
+            }),
+        }
+    }
+}
+
+fn namespace(prefix: &str, href: &str, root: &mut Node) -> Result<Namespace> {
+    match Namespace::new(prefix, href, root) {
+        Ok(val) => Ok(val),
+        Err(e) => Err(S100Error::Write {
+            name: prefix.to_string(),
+            reason: e.to_string(),
+        }),
+    }
+}
+
+pub(super) fn add_element(parent: &mut Node, ns: &Namespace, name: &str) -> Result<Node> {
+    match parent.new_child(Some(ns.clone()), name) {
+        Ok(val) => Ok(val),
+        Err(e) => Err(S100Error::Write {
+            name: name.to_string(),
+            reason: e.to_string(),
+        }),
+    }
+}
+
+pub(super) fn add_text(
+    parent: &mut Node,
+    ns: &Namespace,
+    name: &str,
+    content: &str,
+) -> Result<Node> {
+    match parent.add_text_child(&Some(ns.clone()), name, content) {
+        Ok(val) => Ok(val),
+        Err(e) => Err(S100Error::Write {
+            name: name.to_string(),
+            reason: e.to_string(),
+        }),
+    }
+}
+
+pub(super) fn set_attribute(node: &mut Node, name: &str, value: &str) -> Result<()> {
+    match node.set_attribute(name, value) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(S100Error::Write {
+            name: name.to_string(),
+            reason: e.to_string(),
+        }),
+    }
+}
+
+pub(super) fn add_reference(
+    parent: &mut Node,
+    ns: &Namespace,
+    name: &str,
+    reference: &str,
+) -> Result<()> {
+    let mut node = add_element(parent, ns, name)?;
+    set_attribute(&mut node, XML_REF, reference)
+}
+
+// Writes the properties shared by every catalogue item, in schema order.
+pub(super) fn add_item(
+    node: &mut Node,
+    namespaces: &Namespaces,
+    item: &dyn Item,
+    remarks: Option<&str>,
+    definition_reference: Option<&DefinitionReference>,
+) -> Result<()> {
+    add_text(node, &namespaces.fc, NAME, item.name())?;
+    add_text(node, &namespaces.fc, DEFINITION, item.definition())?;
+    add_text(node, &namespaces.fc, CODE, item.code())?;
+    if let Some(val) = remarks {
+        add_text(node, &namespaces.fc, REMARKS, val)?;
+    }
+    if let Some(val) = item.alias() {
+        add_text(node, &namespaces.fc, ALIAS, val)?;
+    }
+    if let Some(val) = definition_reference {
+        val.write(node, namespaces)?;
+    }
+    if let Some(val) = item.language() {
+        add_text(node, &namespaces.fc, LANGUAGE, val)?;
+    }
+    for val in item.translations() {
+        val.write(node, namespaces)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,575 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+};
+
+use crate::{
+    feature::catalog::{AttributeBinding, AttributeValueType, FeatureCatalog, Item, ListedValue},
+    Result, S100Error,
+};
+
+const HEADER: &str = "// Generated from an S-100 feature catalogue, do not edit by hand.\n";
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+    "where", "while", "abstract", "become", "box", "do", "final", "macro", "override", "priv",
+    "try", "typeof", "unsized", "virtual", "yield",
+];
+
+// Emits an enum per enumeration attribute, and a struct per complex attribute and per concrete
+// information and feature type. Type fields follow the effective (inherited) attribute bindings.
+pub fn generate(catalog: &FeatureCatalog) -> Result<String> {
+    let mut output = String::from(HEADER);
+    if let Some(name) = catalog.name() {
+        match catalog.version_number() {
+            Some(version) => output.push_str(&format!("// {} {}\n", name.trim(), version.trim())),
+            None => output.push_str(&format!("// {}\n", name.trim())),
+        }
+    }
+
+    // All types share one output file, so their names must not collide.
+    let mut type_names: HashMap<String, &str> = HashMap::new();
+
+    for simple_attribute in catalog.simple_attributes() {
+        if simple_attribute.value_type() == AttributeValueType::Enumeration {
+            declare_type(&mut type_names, simple_attribute)?;
+            output.push('\n');
+            write_enumeration(
+                &mut output,
+                simple_attribute,
+                simple_attribute.listed_values(),
+            )?;
+        }
+    }
+
+    for complex_attribute in catalog.complex_attributes() {
+        declare_type(&mut type_names, complex_attribute)?;
+        output.push('\n');
+        write_struct(
+            &mut output,
+            catalog,
+            complex_attribute,
+            complex_attribute.sub_attribute_bindings(),
+        )?;
+    }
+
+    for information_type in catalog.information_types() {
+        if information_type.is_abstract() {
+            continue;
+        }
+        declare_type(&mut type_names, information_type)?;
+        let bindings = catalog.effective_information_bindings(information_type.code())?;
+        output.push('\n');
+        write_struct(
+            &mut output,
+            catalog,
+            information_type,
+            bindings.attribute_bindings(),
+        )?;
+    }
+
+    for feature_type in catalog.feature_types() {
+        if feature_type.is_abstract() {
+            continue;
+        }
+        declare_type(&mut type_names, feature_type)?;
+        let bindings = catalog.effective_feature_bindings(feature_type.code())?;
+        output.push('\n');
+        write_struct(
+            &mut output,
+            catalog,
+            feature_type,
+            bindings.attribute_bindings(),
+        )?;
+    }
+
+    Ok(output)
+}
+
+// Intended for build scripts, e.g. writing into `OUT_DIR` and pulling the result in with
+// `include!`.
+pub fn write<P: AsRef<Path>>(catalog: &FeatureCatalog, path: P) -> Result<()> {
+    let output = generate(catalog)?;
+    fs::write(path, output)?;
+    Ok(())
+}
+
+fn write_doc(output: &mut String, indent: &str, definition: &str) {
+    for line in definition.lines() {
+        let line = line.trim();
+        if !line.is_empty() {
+            output.push_str(&format!("{}/// {}\n", indent, line));
+        }
+    }
+}
+
+fn declare_type<'a>(type_names: &mut HashMap<String, &'a str>, item: &'a dyn Item) -> Result<()> {
+    let name = type_name(item.code());
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err(S100Error::InvalidName {
+            code: item.code().to_string(),
+            name,
+        });
+    }
+
+    match type_names.insert(name.clone(), item.code()) {
+        Some(other) => Err(S100Error::DuplicateName {
+            name,
+            first: other.to_string(),
+            second: item.code().to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+fn write_enumeration(
+    output: &mut String,
+    item: &dyn Item,
+    listed_values: &[ListedValue],
+) -> Result<()> {
+    let name = type_name(item.code());
+    if listed_values.is_empty() {
+        return Err(S100Error::MissingListedValues {
+            code: item.code().to_string(),
+        });
+    }
+
+    let mut codes: HashSet<u64> = HashSet::new();
+    let mut variants: Vec<(String, &ListedValue)> = Vec::new();
+    let mut used: HashSet<String> = HashSet::new();
+    for listed_value in listed_values {
+        if !codes.insert(listed_value.code()) {
+            return Err(S100Error::DuplicateListedValue {
+                code: item.code().to_string(),
+                value: listed_value.code(),
+            });
+        }
+
+        let mut variant = type_name(listed_value.label());
+        if variant.is_empty() || variant.starts_with(|c: char| c.is_ascii_digit()) {
+            variant = format!("Value{}", variant);
+        }
+        // Repeated labels get their code appended, and a counter if that is taken as well.
+        if used.contains(&variant) {
+            let base = format!("{}{}", variant, listed_value.code());
+            variant = base.clone();
+            let mut index = 1;
+            while used.contains(&variant) {
+                index += 1;
+                variant = format!("{}Alt{}", base, index);
+            }
+        }
+        used.insert(variant.clone());
+        variants.push((variant, listed_value));
+    }
+
+    write_doc(output, "", item.definition());
+    output.push_str("#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]\n");
+    output.push_str("#[repr(u64)]\n");
+    output.push_str(&format!("pub enum {} {{\n", name));
+    for (variant, listed_value) in variants.iter() {
+        write_doc(output, "    ", listed_value.definition());
+        output.push_str(&format!("    {} = {},\n", variant, listed_value.code()));
+    }
+    output.push_str("}\n\n");
+
+    output.push_str(&format!("impl {} {{\n", name));
+    output.push_str("    pub fn code(&self) -> u64 {\n");
+    output.push_str("        *self as u64\n");
+    output.push_str("    }\n\n");
+    output.push_str(&format!(
+        "    pub fn from_code(code: u64) -> Option<{}> {{\n",
+        name
+    ));
+    output.push_str("        match code {\n");
+    for (variant, listed_value) in variants.iter() {
+        output.push_str(&format!(
+            "            {} => Some({}::{}),\n",
+            listed_value.code(),
+            name,
+            variant
+        ));
+    }
+    output.push_str("            _ => None,\n");
+    output.push_str("        }\n");
+    output.push_str("    }\n\n");
+    output.push_str("    pub fn label(&self) -> &'static str {\n");
+    output.push_str("        match self {\n");
+    for (variant, listed_value) in variants.iter() {
+        output.push_str(&format!(
+            "            {}::{} => {:?},\n",
+            name,
+            variant,
+            listed_value.label()
+        ));
+    }
+    output.push_str("        }\n");
+    output.push_str("    }\n");
+    output.push_str("}\n");
+
+    Ok(())
+}
+
+fn write_struct(
+    output: &mut String,
+    catalog: &FeatureCatalog,
+    item: &dyn Item,
+    bindings: &[AttributeBinding],
+) -> Result<()> {
+    let name = type_name(item.code());
+
+    let mut field_names: HashMap<String, &str> = HashMap::new();
+    let mut fields: Vec<(String, String)> = Vec::new();
+    for binding in bindings {
+        let field = field_name(binding.attribute());
+        if field.is_empty() || field.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(S100Error::InvalidName {
+                code: binding.attribute().to_string(),
+                name: field,
+            });
+        }
+        if let Some(other) = field_names.insert(field.clone(), binding.attribute()) {
+            return Err(S100Error::DuplicateName {
+                name: field,
+                first: other.to_string(),
+                second: binding.attribute().to_string(),
+            });
+        }
+        fields.push((field, field_type(catalog, item.code(), binding)?));
+    }
+
+    write_doc(output, "", item.definition());
+    output.push_str("#[derive(Clone, Debug, PartialEq)]\n");
+    output.push_str(&format!("pub struct {} {{\n", name));
+    for (field, rust_type) in fields {
+        output.push_str(&format!("    pub {}: {},\n", field, rust_type));
+    }
+    output.push_str("}\n");
+
+    Ok(())
+}
+
+fn field_type(catalog: &FeatureCatalog, owner: &str, binding: &AttributeBinding) -> Result<String> {
+    let code = binding.attribute();
+    let base = if let Some(simple_attribute) = catalog.simple_attribute(code) {
+        match simple_attribute.value_type() {
+            AttributeValueType::Boolean => "bool".to_string(),
+            AttributeValueType::Enumeration => type_name(code),
+            AttributeValueType::Integer => "i64".to_string(),
+            AttributeValueType::Real => "f64".to_string(),
+            AttributeValueType::IntervalType => "s100::feature::catalog::IntervalType".to_string(),
+            AttributeValueType::NumPlusUnit => "(f64, String)".to_string(),
+            AttributeValueType::UnlimitedInteger => "Option<u64>".to_string(),
+            _ => "String".to_string(),
+        }
+    } else if catalog.complex_attribute(code).is_some() {
+        type_name(code)
+    } else {
+        return Err(S100Error::UndefinedAttribute {
+            code: owner.to_string(),
+            attribute: code.to_string(),
+        });
+    };
+
+    // A struct holding itself, directly or through other complex attributes, needs indirection.
+    let recursive = catalog.complex_attribute(code).is_some()
+        && reaches(catalog, code, owner, &mut HashSet::new());
+    let multiplicity = binding.multiplicity();
+    Ok(match multiplicity.upper() {
+        Some(1) if recursive && multiplicity.lower() == 0 => format!("Option<Box<{}>>", base),
+        Some(1) if recursive => format!("Box<{}>", base),
+        Some(1) if multiplicity.lower() == 0 => format!("Option<{}>", base),
+        Some(1) => base,
+        _ => format!("Vec<{}>", base),
+    })
+}
+
+// Whether the complex attribute `code` is, or contains, the complex attribute `target`.
+fn reaches<'a>(
+    catalog: &'a FeatureCatalog,
+    code: &'a str,
+    target: &str,
+    visited: &mut HashSet<&'a str>,
+) -> bool {
+    if code == target {
+        return true;
+    }
+    if !visited.insert(code) {
+        return false;
+    }
+
+    match catalog.complex_attribute(code) {
+        Some(complex_attribute) => complex_attribute
+            .sub_attribute_bindings()
+            .iter()
+            .any(|val| reaches(catalog, val.attribute(), target, visited)),
+        None => false,
+    }
+}
+
+// `categoryOfLight` and `directional function` both become UpperCamelCase identifiers.
+fn type_name(input: &str) -> String {
+    let mut name = String::new();
+    for word in input.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            name.push(first.to_ascii_uppercase());
+            name.push_str(chars.as_str());
+        }
+    }
+    name
+}
+
+fn field_name(input: &str) -> String {
+    let mut name = String::new();
+    for (position, c) in input.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if position > 0 && !name.ends_with('_') {
+                name.push('_');
+            }
+            name.push(c.to_ascii_lowercase());
+        } else if c.is_ascii_alphanumeric() {
+            name.push(c);
+        } else if !name.ends_with('_') {
+            name.push('_');
+        }
+    }
+
+    match name.as_str() {
+        // These keywords cannot be raw identifiers.
+        "crate" | "self" | "super" => format!("{}_", name),
+        val if KEYWORDS.contains(&val) => format!("r#{}", name),
+        _ => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Write, str::FromStr};
+
+    use tempfile::NamedTempFile;
+
+    use super::{field_name, generate, type_name};
+    use crate::{feature::catalog::FeatureCatalog, S100Error};
+
+    #[test]
+    fn names() {
+        assert_eq!(type_name("categoryOfLight"), "CategoryOfLight");
+        assert_eq!(type_name("directional function"), "DirectionalFunction");
+        assert_eq!(type_name("AIS, automatic"), "AISAutomatic");
+        assert_eq!(field_name("categoryOfLight"), "category_of_light");
+        assert_eq!(field_name("type"), "r#type");
+        assert_eq!(field_name("self"), "self_");
+    }
+
+    #[test]
+    fn generate_catalog() {
+        let xml = r#"
+        <?xml version="1.0" encoding="utf-8"?>
+        <S100FC:S100_FC_FeatureCatalogue xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+            <S100FC:name>Test Feature Catalogue</S100FC:name>
+            <S100FC:versionNumber>1.0.0</S100FC:versionNumber>
+            <S100FC:S100_FC_SimpleAttributes>
+                <S100FC:S100_FC_SimpleAttribute>
+                    <S100FC:name>Category of light</S100FC:name>
+                    <S100FC:definition>Classification of different light types.</S100FC:definition>
+                    <S100FC:code>categoryOfLight</S100FC:code>
+                    <S100FC:valueType>enumeration</S100FC:valueType>
+                    <S100FC:listedValues>
+                        <S100FC:listedValue>
+                            <S100FC:label>directional function</S100FC:label>
+                            <S100FC:definition>A light illuminating a sector of very narrow angle.</S100FC:definition>
+                            <S100FC:code>1</S100FC:code>
+                        </S100FC:listedValue>
+                        <S100FC:listedValue>
+                            <S100FC:label>leading light</S100FC:label>
+                            <S100FC:definition>A light associated with other lights to form a leading line.</S100FC:definition>
+                            <S100FC:code>4</S100FC:code>
+                        </S100FC:listedValue>
+                    </S100FC:listedValues>
+                </S100FC:S100_FC_SimpleAttribute>
+                <S100FC:S100_FC_SimpleAttribute>
+                    <S100FC:name>Name</S100FC:name>
+                    <S100FC:definition>The individual name of a feature.</S100FC:definition>
+                    <S100FC:code>name</S100FC:code>
+                    <S100FC:valueType>text</S100FC:valueType>
+                </S100FC:S100_FC_SimpleAttribute>
+                <S100FC:S100_FC_SimpleAttribute>
+                    <S100FC:name>Height</S100FC:name>
+                    <S100FC:definition>The vertical distance to the top of an object.</S100FC:definition>
+                    <S100FC:code>height</S100FC:code>
+                    <S100FC:valueType>real</S100FC:valueType>
+                </S100FC:S100_FC_SimpleAttribute>
+            </S100FC:S100_FC_SimpleAttributes>
+            <S100FC:S100_FC_ComplexAttributes>
+                <S100FC:S100_FC_ComplexAttribute>
+                    <S100FC:name>Feature Name</S100FC:name>
+                    <S100FC:definition>Provides the name of an entity.</S100FC:definition>
+                    <S100FC:code>featureName</S100FC:code>
+                    <S100FC:subAttributeBinding sequential="false">
+                        <S100FC:multiplicity>
+                            <S100Base:lower>1</S100Base:lower>
+                            <S100Base:upper>1</S100Base:upper>
+                        </S100FC:multiplicity>
+                        <S100FC:attribute ref="name"/>
+                    </S100FC:subAttributeBinding>
+                </S100FC:S100_FC_ComplexAttribute>
+            </S100FC:S100_FC_ComplexAttributes>
+            <S100FC:S100_FC_FeatureTypes>
+                <S100FC:S100_FC_FeatureType isAbstract="false">
+                    <S100FC:name>Light</S100FC:name>
+                    <S100FC:definition>A luminous or lighted aid to navigation.</S100FC:definition>
+                    <S100FC:code>Light</S100FC:code>
+                    <S100FC:attributeBinding sequential="false">
+                        <S100FC:multiplicity>
+                            <S100Base:lower>1</S100Base:lower>
+                            <S100Base:upper xsi:nil="true" infinite="true"/>
+                        </S100FC:multiplicity>
+                        <S100FC:attribute ref="categoryOfLight"/>
+                    </S100FC:attributeBinding>
+                    <S100FC:attributeBinding sequential="false">
+                        <S100FC:multiplicity>
+                            <S100Base:lower>0</S100Base:lower>
+                            <S100Base:upper>1</S100Base:upper>
+                        </S100FC:multiplicity>
+                        <S100FC:attribute ref="height"/>
+                    </S100FC:attributeBinding>
+                    <S100FC:attributeBinding sequential="false">
+                        <S100FC:multiplicity>
+                            <S100Base:lower>0</S100Base:lower>
+                            <S100Base:upper xsi:nil="true" infinite="true"/>
+                        </S100FC:multiplicity>
+                        <S100FC:attribute ref="featureName"/>
+                    </S100FC:attributeBinding>
+                    <S100FC:featureUseType>geographic</S100FC:featureUseType>
+                    <S100FC:permittedPrimitives>point</S100FC:permittedPrimitives>
+                </S100FC:S100_FC_FeatureType>
+            </S100FC:S100_FC_FeatureTypes>
+        </S100FC:S100_FC_FeatureCatalogue>"#;
+
+        let mut temp_file = NamedTempFile::new().expect("Unable to create temp file");
+        temp_file
+            .write_all(xml.as_bytes())
+            .expect("Unable to write XML");
+        let catalog = FeatureCatalog::open(temp_file.path()).unwrap();
+        temp_file.close().expect("Unable to close temp file");
+
+        let output = generate(&catalog).unwrap();
+
+        assert!(output.contains("// Test Feature Catalogue 1.0.0\n"));
+        assert!(output.contains("pub enum CategoryOfLight {\n"));
+        assert!(output.contains("    DirectionalFunction = 1,\n"));
+        assert!(output.contains("    LeadingLight = 4,\n"));
+        assert!(output.contains("            4 => Some(CategoryOfLight::LeadingLight),\n"));
+        assert!(
+            output.contains("            CategoryOfLight::LeadingLight => \"leading light\",\n")
+        );
+        assert!(output.contains("pub struct FeatureName {\n    pub name: String,\n}\n"));
+        assert!(output.contains(
+            "/// A luminous or lighted aid to navigation.\n#[derive(Clone, Debug, PartialEq)]\npub struct Light {\n    pub category_of_light: Vec<CategoryOfLight>,\n    pub height: Option<f64>,\n    pub feature_name: Vec<FeatureName>,\n}\n"
+        ));
+    }
+
+    fn enumeration(code: &str, listed_values: &[(&str, u64)]) -> String {
+        let listed_values: String = listed_values
+            .iter()
+            .map(|(label, code)| {
+                format!(
+                    "<S100FC:listedValue><S100FC:label>{}</S100FC:label><S100FC:definition>{0}</S100FC:definition><S100FC:code>{}</S100FC:code></S100FC:listedValue>",
+                    label, code
+                )
+            })
+            .collect();
+        format!(
+            "<S100FC:S100_FC_SimpleAttribute><S100FC:name>{0}</S100FC:name><S100FC:definition>{0}</S100FC:definition><S100FC:code>{0}</S100FC:code><S100FC:valueType>enumeration</S100FC:valueType><S100FC:listedValues>{1}</S100FC:listedValues></S100FC:S100_FC_SimpleAttribute>",
+            code, listed_values
+        )
+    }
+
+    fn text(code: &str) -> String {
+        format!(
+            "<S100FC:S100_FC_SimpleAttribute><S100FC:name>{0}</S100FC:name><S100FC:definition>{0}</S100FC:definition><S100FC:code>{0}</S100FC:code><S100FC:valueType>text</S100FC:valueType></S100FC:S100_FC_SimpleAttribute>",
+            code
+        )
+    }
+
+    fn complex(code: &str, attributes: &[&str]) -> String {
+        let bindings: Vec<String> = attributes
+            .iter()
+            .map(|val| format!("<S100FC:subAttributeBinding sequential=\"false\"><S100FC:multiplicity><S100Base:lower>0</S100Base:lower><S100Base:upper>1</S100Base:upper></S100FC:multiplicity><S100FC:attribute ref=\"{}\"/></S100FC:subAttributeBinding>", val))
+            .collect();
+        format!(
+            "<S100FC:S100_FC_ComplexAttribute><S100FC:name>{0}</S100FC:name><S100FC:definition>{0}</S100FC:definition><S100FC:code>{0}</S100FC:code>{1}</S100FC:S100_FC_ComplexAttribute>",
+            code,
+            bindings.concat()
+        )
+    }
+
+    fn catalog(simple_attributes: &[String]) -> FeatureCatalog {
+        catalog_with(simple_attributes, &[])
+    }
+
+    fn catalog_with(simple_attributes: &[String], complex_attributes: &[String]) -> FeatureCatalog {
+        FeatureCatalog::from_str(&format!(
+            r#"<S100FC:S100_FC_FeatureCatalogue xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base"><S100FC:S100_FC_SimpleAttributes>{}</S100FC:S100_FC_SimpleAttributes><S100FC:S100_FC_ComplexAttributes>{}</S100FC:S100_FC_ComplexAttributes></S100FC:S100_FC_FeatureCatalogue>"#,
+            simple_attributes.concat(),
+            complex_attributes.concat()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn generate_invalid() {
+        let output = generate(&catalog(&[enumeration(
+            "status",
+            &[("a", 1), ("a", 2), ("a 2", 3), ("a2", 4)],
+        )]))
+        .unwrap();
+        assert!(output.contains("    A = 1,\n"));
+        assert!(output.contains("    A2 = 2,\n"));
+        assert!(output.contains("    A23 = 3,\n"));
+        assert!(output.contains("    A24 = 4,\n"));
+
+        assert!(generate(&catalog(&[enumeration("status", &[])])).is_err());
+        assert!(generate(&catalog(&[enumeration("status", &[("a", 1), ("b", 1)])])).is_err());
+        assert!(matches!(
+            generate(&catalog(&[
+                enumeration("status", &[("a", 1)]),
+                enumeration("Status", &[("a", 1)])
+            ])),
+            Err(S100Error::DuplicateName { .. })
+        ));
+    }
+
+    #[test]
+    fn generate_recursive() {
+        let output = generate(&catalog_with(
+            &[enumeration("status", &[("a", 1)])],
+            &[
+                complex("outer", &["inner", "status"]),
+                complex("inner", &["outer"]),
+                complex("leaf", &["status"]),
+                complex("node", &["leaf", "node"]),
+            ],
+        ))
+        .unwrap();
+        assert!(output.contains("pub struct Outer {\n    pub inner: Option<Box<Inner>>,\n"));
+        assert!(output.contains("pub struct Inner {\n    pub outer: Option<Box<Outer>>,\n"));
+        assert!(output.contains("    pub leaf: Option<Leaf>,\n    pub node: Option<Box<Node>>,\n"));
+
+        assert!(matches!(
+            generate(&catalog_with(
+                &[text("name"), text("Name")],
+                &[complex("outer", &["name", "Name"])],
+            )),
+            Err(S100Error::DuplicateName { .. })
+        ));
+        assert!(matches!(
+            generate(&catalog_with(&[text("3d")], &[complex("outer", &["3d"])])),
+            Err(S100Error::InvalidName { .. })
+        ));
+    }
+}
@@ -1,2 +1,5 @@
 mod quantity_specification;
 pub use quantity_specification::QuantitySpecification;
+
+mod unit;
+pub use unit::Unit;
@@ -1,5 +1,6 @@
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
 
+use super::Unit;
 use crate::{feature::QUANTITY_SPECIFICATION, S100Error};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -22,6 +23,49 @@ pub enum QuantitySpecification {
     OtherQuantity,
 }
 
+impl QuantitySpecification {
+    pub fn units(&self) -> &'static [Unit] {
+        match self {
+            QuantitySpecification::AngularVelocity => &[
+                Unit::RadiansPerSecond,
+                Unit::DegreesPerSecond,
+                Unit::DegreesPerMinute,
+            ],
+            QuantitySpecification::Area => &[
+                Unit::SquareMetre,
+                Unit::SquareKilometre,
+                Unit::SquareNauticalMile,
+            ],
+            QuantitySpecification::Duration => &[Unit::Second, Unit::Minute, Unit::Hour, Unit::Day],
+            QuantitySpecification::Frequency => &[Unit::Hertz, Unit::Kilohertz, Unit::Megahertz],
+            QuantitySpecification::Length => &[
+                Unit::Metre,
+                Unit::Kilometre,
+                Unit::Centimetre,
+                Unit::Millimetre,
+                Unit::Foot,
+                Unit::Fathom,
+                Unit::NauticalMile,
+            ],
+            QuantitySpecification::Mass => &[Unit::Kilogram, Unit::Gram, Unit::Tonne],
+            QuantitySpecification::PlaneAngle => &[Unit::Radian, Unit::Degree, Unit::ArcMinute],
+            QuantitySpecification::Power => &[Unit::Watt, Unit::Kilowatt],
+            QuantitySpecification::Pressure => {
+                &[Unit::Pascal, Unit::Hectopascal, Unit::Millibar, Unit::Bar]
+            }
+            QuantitySpecification::Speed => {
+                &[Unit::MetresPerSecond, Unit::KilometresPerHour, Unit::Knot]
+            }
+            QuantitySpecification::Temperature => &[Unit::Kelvin, Unit::Celsius, Unit::Fahrenheit],
+            QuantitySpecification::Volume => &[Unit::CubicMetre, Unit::Litre],
+            QuantitySpecification::Density
+            | QuantitySpecification::Salinity
+            | QuantitySpecification::Weight
+            | QuantitySpecification::OtherQuantity => &[],
+        }
+    }
+}
+
 impl FromStr for QuantitySpecification {
     type Err = S100Error;
 
@@ -43,10 +87,31 @@ impl FromStr for QuantitySpecification {
             "volume" => Ok(QuantitySpecification::Volume),
             "weight" => Ok(QuantitySpecification::Weight),
             "otherQuantity" => Ok(QuantitySpecification::OtherQuantity),
-            _ => Err(S100Error::Parse(format!(
-                "Invalid enum value for '{}': {}",
-                QUANTITY_SPECIFICATION, input
-            ))),
+            _ => S100Error::invalid_enum(QUANTITY_SPECIFICATION, input),
         }
     }
 }
+
+impl fmt::Display for QuantitySpecification {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            QuantitySpecification::AngularVelocity => "angularVelocity",
+            QuantitySpecification::Area => "area",
+            QuantitySpecification::Density => "density",
+            QuantitySpecification::Duration => "duration",
+            QuantitySpecification::Frequency => "frequency",
+            QuantitySpecification::Length => "length",
+            QuantitySpecification::Mass => "mass",
+            QuantitySpecification::PlaneAngle => "planeAngle",
+            QuantitySpecification::Power => "power",
+            QuantitySpecification::Pressure => "pressure",
+            QuantitySpecification::Salinity => "salinity",
+            QuantitySpecification::Speed => "speed",
+            QuantitySpecification::Temperature => "temperature",
+            QuantitySpecification::Volume => "volume",
+            QuantitySpecification::Weight => "weight",
+            QuantitySpecification::OtherQuantity => "otherQuantity",
+        };
+        write!(f, "{}", value)
+    }
+}
@@ -0,0 +1,295 @@
+use std::{f64::consts::PI, str::FromStr};
+
+use super::QuantitySpecification;
+use crate::S100Error;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Unit {
+    Metre,
+    Kilometre,
+    Centimetre,
+    Millimetre,
+    Foot,
+    Fathom,
+    NauticalMile,
+    SquareMetre,
+    SquareKilometre,
+    SquareNauticalMile,
+    CubicMetre,
+    Litre,
+    MetresPerSecond,
+    KilometresPerHour,
+    Knot,
+    Radian,
+    Degree,
+    ArcMinute,
+    RadiansPerSecond,
+    DegreesPerSecond,
+    DegreesPerMinute,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Hertz,
+    Kilohertz,
+    Megahertz,
+    Kilogram,
+    Gram,
+    Tonne,
+    Pascal,
+    Hectopascal,
+    Millibar,
+    Bar,
+    Watt,
+    Kilowatt,
+    Kelvin,
+    Celsius,
+    Fahrenheit,
+}
+
+impl Unit {
+    pub fn quantity(&self) -> QuantitySpecification {
+        match self {
+            Unit::Metre
+            | Unit::Kilometre
+            | Unit::Centimetre
+            | Unit::Millimetre
+            | Unit::Foot
+            | Unit::Fathom
+            | Unit::NauticalMile => QuantitySpecification::Length,
+            Unit::SquareMetre | Unit::SquareKilometre | Unit::SquareNauticalMile => {
+                QuantitySpecification::Area
+            }
+            Unit::CubicMetre | Unit::Litre => QuantitySpecification::Volume,
+            Unit::MetresPerSecond | Unit::KilometresPerHour | Unit::Knot => {
+                QuantitySpecification::Speed
+            }
+            Unit::Radian | Unit::Degree | Unit::ArcMinute => QuantitySpecification::PlaneAngle,
+            Unit::RadiansPerSecond | Unit::DegreesPerSecond | Unit::DegreesPerMinute => {
+                QuantitySpecification::AngularVelocity
+            }
+            Unit::Second | Unit::Minute | Unit::Hour | Unit::Day => QuantitySpecification::Duration,
+            Unit::Hertz | Unit::Kilohertz | Unit::Megahertz => QuantitySpecification::Frequency,
+            Unit::Kilogram | Unit::Gram | Unit::Tonne => QuantitySpecification::Mass,
+            Unit::Pascal | Unit::Hectopascal | Unit::Millibar | Unit::Bar => {
+                QuantitySpecification::Pressure
+            }
+            Unit::Watt | Unit::Kilowatt => QuantitySpecification::Power,
+            Unit::Kelvin | Unit::Celsius | Unit::Fahrenheit => QuantitySpecification::Temperature,
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Unit::Metre => "m",
+            Unit::Kilometre => "km",
+            Unit::Centimetre => "cm",
+            Unit::Millimetre => "mm",
+            Unit::Foot => "ft",
+            Unit::Fathom => "fm",
+            Unit::NauticalMile => "NM",
+            Unit::SquareMetre => "m2",
+            Unit::SquareKilometre => "km2",
+            Unit::SquareNauticalMile => "NM2",
+            Unit::CubicMetre => "m3",
+            Unit::Litre => "L",
+            Unit::MetresPerSecond => "m/s",
+            Unit::KilometresPerHour => "km/h",
+            Unit::Knot => "kn",
+            Unit::Radian => "rad",
+            Unit::Degree => "°",
+            Unit::ArcMinute => "'",
+            Unit::RadiansPerSecond => "rad/s",
+            Unit::DegreesPerSecond => "°/s",
+            Unit::DegreesPerMinute => "°/min",
+            Unit::Second => "s",
+            Unit::Minute => "min",
+            Unit::Hour => "h",
+            Unit::Day => "d",
+            Unit::Hertz => "Hz",
+            Unit::Kilohertz => "kHz",
+            Unit::Megahertz => "MHz",
+            Unit::Kilogram => "kg",
+            Unit::Gram => "g",
+            Unit::Tonne => "t",
+            Unit::Pascal => "Pa",
+            Unit::Hectopascal => "hPa",
+            Unit::Millibar => "mbar",
+            Unit::Bar => "bar",
+            Unit::Watt => "W",
+            Unit::Kilowatt => "kW",
+            Unit::Kelvin => "K",
+            Unit::Celsius => "°C",
+            Unit::Fahrenheit => "°F",
+        }
+    }
+
+    // Scale and offset that take a value in this unit to the SI unit of its quantity.
+    fn to_si(self) -> (f64, f64) {
+        match self {
+            Unit::Metre => (1.0, 0.0),
+            Unit::Kilometre => (1000.0, 0.0),
+            Unit::Centimetre => (0.01, 0.0),
+            Unit::Millimetre => (0.001, 0.0),
+            Unit::Foot => (0.3048, 0.0),
+            Unit::Fathom => (1.8288, 0.0),
+            Unit::NauticalMile => (1852.0, 0.0),
+            Unit::SquareMetre => (1.0, 0.0),
+            Unit::SquareKilometre => (1.0e6, 0.0),
+            Unit::SquareNauticalMile => (1852.0 * 1852.0, 0.0),
+            Unit::CubicMetre => (1.0, 0.0),
+            Unit::Litre => (0.001, 0.0),
+            Unit::MetresPerSecond => (1.0, 0.0),
+            Unit::KilometresPerHour => (1000.0 / 3600.0, 0.0),
+            Unit::Knot => (1852.0 / 3600.0, 0.0),
+            Unit::Radian => (1.0, 0.0),
+            Unit::Degree => (PI / 180.0, 0.0),
+            Unit::ArcMinute => (PI / 10800.0, 0.0),
+            Unit::RadiansPerSecond => (1.0, 0.0),
+            Unit::DegreesPerSecond => (PI / 180.0, 0.0),
+            Unit::DegreesPerMinute => (PI / 10800.0, 0.0),
+            Unit::Second => (1.0, 0.0),
+            Unit::Minute => (60.0, 0.0),
+            Unit::Hour => (3600.0, 0.0),
+            Unit::Day => (86400.0, 0.0),
+            Unit::Hertz => (1.0, 0.0),
+            Unit::Kilohertz => (1.0e3, 0.0),
+            Unit::Megahertz => (1.0e6, 0.0),
+            Unit::Kilogram => (1.0, 0.0),
+            Unit::Gram => (0.001, 0.0),
+            Unit::Tonne => (1000.0, 0.0),
+            Unit::Pascal => (1.0, 0.0),
+            Unit::Hectopascal => (100.0, 0.0),
+            Unit::Millibar => (100.0, 0.0),
+            Unit::Bar => (1.0e5, 0.0),
+            Unit::Watt => (1.0, 0.0),
+            Unit::Kilowatt => (1000.0, 0.0),
+            Unit::Kelvin => (1.0, 0.0),
+            Unit::Celsius => (1.0, 273.15),
+            Unit::Fahrenheit => (5.0 / 9.0, 273.15 - 32.0 * 5.0 / 9.0),
+        }
+    }
+
+    pub fn convert(&self, value: f64, to: Unit) -> Option<f64> {
+        if self.quantity() != to.quantity() {
+            return None;
+        }
+        if *self == to {
+            return Some(value);
+        }
+
+        let (from_scale, from_offset) = self.to_si();
+        let (to_scale, to_offset) = to.to_si();
+        Some((value * from_scale + from_offset - to_offset) / to_scale)
+    }
+}
+
+impl FromStr for Unit {
+    type Err = S100Error;
+
+    fn from_str(input: &str) -> Result<Unit, Self::Err> {
+        match input.trim() {
+            "m" | "metre" | "metres" | "meter" | "meters" => Ok(Unit::Metre),
+            "km" | "kilometre" | "kilometres" | "kilometer" | "kilometers" => Ok(Unit::Kilometre),
+            "cm" | "centimetre" | "centimetres" | "centimeter" | "centimeters" => {
+                Ok(Unit::Centimetre)
+            }
+            "mm" | "millimetre" | "millimetres" | "millimeter" | "millimeters" => {
+                Ok(Unit::Millimetre)
+            }
+            "ft" | "foot" | "feet" => Ok(Unit::Foot),
+            "fm" | "fathom" | "fathoms" => Ok(Unit::Fathom),
+            "M" | "NM" | "nmi" | "nautical mile" | "nautical miles" => Ok(Unit::NauticalMile),
+            "m2" | "m²" | "square metre" | "square metres" => Ok(Unit::SquareMetre),
+            "km2" | "km²" | "square kilometre" | "square kilometres" => Ok(Unit::SquareKilometre),
+            "NM2" | "NM²" | "square nautical mile" | "square nautical miles" => {
+                Ok(Unit::SquareNauticalMile)
+            }
+            "m3" | "m³" | "cubic metre" | "cubic metres" => Ok(Unit::CubicMetre),
+            "L" | "l" | "litre" | "litres" | "liter" | "liters" => Ok(Unit::Litre),
+            "m/s" | "metres per second" | "meters per second" => Ok(Unit::MetresPerSecond),
+            "km/h" | "kilometres per hour" | "kilometers per hour" => Ok(Unit::KilometresPerHour),
+            "kn" | "kt" | "knot" | "knots" => Ok(Unit::Knot),
+            "rad" | "radian" | "radians" => Ok(Unit::Radian),
+            "°" | "deg" | "degree" | "degrees" | "degrees (arc)" => Ok(Unit::Degree),
+            "'" | "arc minute" | "arc minutes" => Ok(Unit::ArcMinute),
+            "rad/s" | "radians per second" => Ok(Unit::RadiansPerSecond),
+            "°/s" | "degrees per second" => Ok(Unit::DegreesPerSecond),
+            "°/min" | "degrees per minute" => Ok(Unit::DegreesPerMinute),
+            "s" | "sec" | "second" | "seconds" => Ok(Unit::Second),
+            "min" | "minute" | "minutes" => Ok(Unit::Minute),
+            "h" | "hr" | "hour" | "hours" => Ok(Unit::Hour),
+            "d" | "day" | "days" => Ok(Unit::Day),
+            "Hz" | "hertz" => Ok(Unit::Hertz),
+            "kHz" | "kilohertz" => Ok(Unit::Kilohertz),
+            "MHz" | "megahertz" => Ok(Unit::Megahertz),
+            "kg" | "kilogram" | "kilograms" => Ok(Unit::Kilogram),
+            "g" | "gram" | "grams" => Ok(Unit::Gram),
+            "t" | "tonne" | "tonnes" => Ok(Unit::Tonne),
+            "Pa" | "pascal" | "pascals" => Ok(Unit::Pascal),
+            "hPa" | "hectopascal" | "hectopascals" => Ok(Unit::Hectopascal),
+            "mbar" | "mb" | "millibar" | "millibars" => Ok(Unit::Millibar),
+            "bar" | "bars" => Ok(Unit::Bar),
+            "W" | "watt" | "watts" => Ok(Unit::Watt),
+            "kW" | "kilowatt" | "kilowatts" => Ok(Unit::Kilowatt),
+            "K" | "kelvin" => Ok(Unit::Kelvin),
+            "°C" | "degC" | "degrees Celsius" | "celsius" => Ok(Unit::Celsius),
+            "°F" | "degF" | "degrees Fahrenheit" | "fahrenheit" => Ok(Unit::Fahrenheit),
+            _ => S100Error::invalid_enum("unitOfMeasure", input),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{QuantitySpecification, Unit};
+
+    fn assert_close(actual: Option<f64>, expected: f64) {
+        let actual = actual.unwrap();
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {} but got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn convert() {
+        assert_close(Unit::Metre.convert(1.8288, Unit::Fathom), 1.0);
+        assert_close(Unit::Fathom.convert(1.0, Unit::Foot), 6.0);
+        assert_close(
+            Unit::Knot.convert(1.0, Unit::MetresPerSecond),
+            1852.0 / 3600.0,
+        );
+        assert_close(
+            Unit::Degree.convert(180.0, Unit::Radian),
+            std::f64::consts::PI,
+        );
+        assert_close(Unit::Celsius.convert(100.0, Unit::Fahrenheit), 212.0);
+        assert_eq!(Unit::Metre.convert(1.0, Unit::Knot), None);
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Unit::from_str("metres").unwrap(), Unit::Metre);
+        assert_eq!(Unit::from_str("kn").unwrap(), Unit::Knot);
+        assert_eq!(
+            Unit::from_str("degrees").unwrap().quantity(),
+            QuantitySpecification::PlaneAngle
+        );
+        assert!(Unit::from_str("furlong").is_err());
+    }
+
+    #[test]
+    fn units() {
+        let quantity = QuantitySpecification::Length;
+        assert!(quantity.units().contains(&Unit::Fathom));
+        for unit in quantity.units() {
+            assert_eq!(unit.quantity(), quantity);
+        }
+        assert!(QuantitySpecification::Salinity.units().is_empty());
+    }
+}
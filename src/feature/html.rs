@@ -0,0 +1,913 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::Path,
+};
+
+use crate::{
+    feature::catalog::{
+        AttributeBinding, ComplexAttribute, DefinitionReference, EffectiveBindings, FeatureBinding,
+        FeatureCatalog, FeatureType, InformationBinding, InformationType, Item, SimpleAttribute,
+    },
+    Result, S100Error,
+};
+
+const INDEX_PAGE: &str = "index.html";
+const ATTRIBUTES_PAGE: &str = "attributes.html";
+const STYLE: &str = "body{font-family:sans-serif;margin:2em;max-width:70em}\
+table{border-collapse:collapse;margin:1em 0}\
+th,td{border:1px solid #ccc;padding:.3em .6em;text-align:left;vertical-align:top}\
+th{background:#eee}\
+dt{font-weight:bold}\
+.code{font-family:monospace}";
+
+// Returns (file name, contents) pairs for a static site: an index, one page per feature and
+// information type, and a single attribute page that doubles as the cross-reference of where
+// each attribute is bound.
+pub fn generate(catalog: &FeatureCatalog) -> Result<Vec<(String, String)>> {
+    check_names(catalog)?;
+
+    let mut usages: BTreeMap<String, Vec<(String, String)>> = BTreeMap::new();
+    let mut pages = Vec::new();
+
+    for feature_type in catalog.feature_types() {
+        let hierarchy = catalog.feature_type_hierarchy(feature_type.code())?;
+        let bindings = catalog.inherited_feature_bindings(feature_type.code())?;
+        for binding in bindings.attribute_bindings() {
+            usages
+                .entry(binding.attribute().to_string())
+                .or_default()
+                .push((
+                    feature_type.name().to_string(),
+                    feature_page(feature_type.code()),
+                ));
+        }
+
+        let body = feature_type_body(catalog, feature_type, &hierarchy, &bindings);
+        pages.push((
+            feature_page(feature_type.code()),
+            page(feature_type.name(), &body),
+        ));
+    }
+
+    for information_type in catalog.information_types() {
+        let hierarchy = catalog.information_type_hierarchy(information_type.code())?;
+        let bindings = catalog.inherited_information_bindings(information_type.code())?;
+        for binding in bindings.attribute_bindings() {
+            usages
+                .entry(binding.attribute().to_string())
+                .or_default()
+                .push((
+                    information_type.name().to_string(),
+                    information_page(information_type.code()),
+                ));
+        }
+
+        let body = information_type_body(catalog, information_type, &hierarchy, &bindings);
+        pages.push((
+            information_page(information_type.code()),
+            page(information_type.name(), &body),
+        ));
+    }
+
+    for complex_attribute in catalog.complex_attributes() {
+        for binding in complex_attribute.sub_attribute_bindings() {
+            usages
+                .entry(binding.attribute().to_string())
+                .or_default()
+                .push((
+                    complex_attribute.name().to_string(),
+                    attribute_link(complex_attribute.code()),
+                ));
+        }
+    }
+
+    pages.push((
+        ATTRIBUTES_PAGE.to_string(),
+        page("Attributes", &attributes_body(catalog, &usages)),
+    ));
+
+    let title = match catalog.name() {
+        Some(name) => name.trim(),
+        None => "Feature Catalogue",
+    };
+    pages.insert(
+        0,
+        (INDEX_PAGE.to_string(), page(title, &index_body(catalog))),
+    );
+
+    Ok(pages)
+}
+
+pub fn write<P: AsRef<Path>>(catalog: &FeatureCatalog, directory: P) -> Result<()> {
+    let pages = generate(catalog)?;
+    fs::create_dir_all(&directory)?;
+    for (name, contents) in pages {
+        fs::write(directory.as_ref().join(name), contents)?;
+    }
+    Ok(())
+}
+
+// Codes that differ only in characters `anchor` replaces would share a page or a fragment, and
+// one would silently replace the other. File systems may ignore case, so page names are compared
+// in lower case.
+fn check_names(catalog: &FeatureCatalog) -> Result<()> {
+    let mut pages: HashMap<String, &str> = HashMap::new();
+    for val in catalog.feature_types() {
+        unique(
+            &mut pages,
+            feature_page(val.code()).to_lowercase(),
+            val.code(),
+        )?;
+    }
+    for val in catalog.information_types() {
+        unique(
+            &mut pages,
+            information_page(val.code()).to_lowercase(),
+            val.code(),
+        )?;
+    }
+
+    let mut attributes: HashMap<String, &str> = HashMap::new();
+    for val in catalog.simple_attributes() {
+        unique(&mut attributes, anchor(val.code()), val.code())?;
+    }
+    for val in catalog.complex_attributes() {
+        unique(&mut attributes, anchor(val.code()), val.code())?;
+    }
+
+    let mut sources: HashMap<String, &str> = HashMap::new();
+    for val in catalog.definition_sources() {
+        unique(&mut sources, anchor(val.id()), val.id())?;
+    }
+
+    Ok(())
+}
+
+fn unique<'a>(names: &mut HashMap<String, &'a str>, name: String, code: &'a str) -> Result<()> {
+    match names.insert(name.clone(), code) {
+        Some(other) => Err(S100Error::DuplicateName {
+            name,
+            first: other.to_string(),
+            second: code.to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+fn index_body(catalog: &FeatureCatalog) -> String {
+    let mut body = String::new();
+
+    body.push_str(&format!(
+        "<h1>{}</h1>\n",
+        escape(catalog.name().unwrap_or("Feature Catalogue").trim())
+    ));
+    body.push_str("<dl>\n");
+    if let Some(version) = catalog.version_number() {
+        definition(&mut body, "Version", &escape(version));
+    }
+    if let Some(date) = catalog.version_date() {
+        definition(&mut body, "Version date", &escape(date));
+    }
+    if let Some(producer) = catalog.producer() {
+        definition(
+            &mut body,
+            "Producer",
+            &escape(&producer.parties().join(", ")),
+        );
+    }
+    for scope in catalog.scopes() {
+        definition(&mut body, "Scope", &escape(scope));
+    }
+    for field_of_application in catalog.fields_of_application() {
+        definition(
+            &mut body,
+            "Field of application",
+            &escape(field_of_application),
+        );
+    }
+    body.push_str("</dl>\n");
+
+    body.push_str("<h2>Feature types</h2>\n<ul>\n");
+    for feature_type in sorted(catalog.feature_types()) {
+        list_item(&mut body, feature_type, &feature_page(feature_type.code()));
+    }
+    body.push_str("</ul>\n");
+
+    body.push_str("<h2>Information types</h2>\n<ul>\n");
+    for information_type in sorted(catalog.information_types()) {
+        list_item(
+            &mut body,
+            information_type,
+            &information_page(information_type.code()),
+        );
+    }
+    body.push_str("</ul>\n");
+
+    body.push_str("<h2>Attributes</h2>\n<ul>\n");
+    for simple_attribute in sorted(catalog.simple_attributes()) {
+        list_item(
+            &mut body,
+            simple_attribute,
+            &attribute_link(simple_attribute.code()),
+        );
+    }
+    for complex_attribute in sorted(catalog.complex_attributes()) {
+        list_item(
+            &mut body,
+            complex_attribute,
+            &attribute_link(complex_attribute.code()),
+        );
+    }
+    body.push_str("</ul>\n");
+
+    if !catalog.definition_sources().is_empty() {
+        body.push_str("<h2>Definition sources</h2>\n<dl>\n");
+        for definition_source in catalog.definition_sources() {
+            let citation = definition_source.source();
+            let mut text = escape(citation.title());
+            if let Some(edition) = citation.edition() {
+                text.push_str(&format!(", edition {}", escape(edition)));
+            }
+            if let Some(date) = citation.edition_date() {
+                text.push_str(&format!(" ({})", escape(date)));
+            }
+            body.push_str(&format!(
+                "<dt id=\"source-{}\">{}</dt><dd>{}</dd>\n",
+                anchor(definition_source.id()),
+                escape(definition_source.id()),
+                text
+            ));
+        }
+        body.push_str("</dl>\n");
+    }
+
+    body
+}
+
+fn feature_type_body(
+    catalog: &FeatureCatalog,
+    feature_type: &FeatureType,
+    hierarchy: &[&FeatureType],
+    bindings: &EffectiveBindings,
+) -> String {
+    let mut body = item_header(
+        catalog,
+        feature_type,
+        feature_type.remarks(),
+        feature_type.definition_reference(),
+    );
+
+    body.push_str("<dl>\n");
+    if feature_type.is_abstract() {
+        definition(&mut body, "Abstract", "yes");
+    }
+    definition(
+        &mut body,
+        "Feature use type",
+        &escape(&feature_type.feature_use_type().to_string()),
+    );
+    if !feature_type.permitted_primitives().is_empty() {
+        let primitives: Vec<String> = feature_type
+            .permitted_primitives()
+            .iter()
+            .map(|val| val.to_string())
+            .collect();
+        definition(
+            &mut body,
+            "Permitted primitives",
+            &escape(&primitives.join(", ")),
+        );
+    }
+    if hierarchy.len() > 1 {
+        let links: Vec<String> = hierarchy[1..]
+            .iter()
+            .map(|val| link(&feature_page(val.code()), val.name()))
+            .collect();
+        definition(&mut body, "Supertypes", &links.join(" &rarr; "));
+    }
+    if !feature_type.sub_types().is_empty() {
+        let links: Vec<String> = feature_type
+            .sub_types()
+            .iter()
+            .map(|val| type_link(catalog, val))
+            .collect();
+        definition(&mut body, "Subtypes", &links.join(", "));
+    }
+    body.push_str("</dl>\n");
+
+    attribute_table(
+        catalog,
+        &mut body,
+        &bindings.declared_attribute_bindings(),
+        |val| type_link(catalog, val),
+    );
+    feature_binding_table(catalog, &mut body, &bindings.declared_feature_bindings());
+    information_binding_table(
+        catalog,
+        &mut body,
+        &bindings.declared_information_bindings(),
+    );
+
+    body
+}
+
+fn information_type_body(
+    catalog: &FeatureCatalog,
+    information_type: &InformationType,
+    hierarchy: &[&InformationType],
+    bindings: &EffectiveBindings,
+) -> String {
+    let mut body = item_header(
+        catalog,
+        information_type,
+        information_type.remarks(),
+        information_type.definition_reference(),
+    );
+
+    body.push_str("<dl>\n");
+    if information_type.is_abstract() {
+        definition(&mut body, "Abstract", "yes");
+    }
+    if hierarchy.len() > 1 {
+        let links: Vec<String> = hierarchy[1..]
+            .iter()
+            .map(|val| link(&information_page(val.code()), val.name()))
+            .collect();
+        definition(&mut body, "Supertypes", &links.join(" &rarr; "));
+    }
+    if !information_type.sub_types().is_empty() {
+        let links: Vec<String> = information_type
+            .sub_types()
+            .iter()
+            .map(|val| type_link(catalog, val))
+            .collect();
+        definition(&mut body, "Subtypes", &links.join(", "));
+    }
+    body.push_str("</dl>\n");
+
+    attribute_table(
+        catalog,
+        &mut body,
+        &bindings.declared_attribute_bindings(),
+        |val| type_link(catalog, val),
+    );
+    information_binding_table(
+        catalog,
+        &mut body,
+        &bindings.declared_information_bindings(),
+    );
+
+    body
+}
+
+fn attributes_body(
+    catalog: &FeatureCatalog,
+    usages: &BTreeMap<String, Vec<(String, String)>>,
+) -> String {
+    let mut body = String::from("<h1>Attributes</h1>\n<p>");
+    body.push_str(&link(INDEX_PAGE, "Back to the index"));
+    body.push_str("</p>\n");
+
+    for simple_attribute in sorted(catalog.simple_attributes()) {
+        simple_attribute_section(catalog, &mut body, simple_attribute);
+        used_by(&mut body, usages.get(simple_attribute.code()));
+    }
+
+    for complex_attribute in sorted(catalog.complex_attributes()) {
+        complex_attribute_section(catalog, &mut body, complex_attribute);
+        used_by(&mut body, usages.get(complex_attribute.code()));
+    }
+
+    body
+}
+
+fn simple_attribute_section(
+    catalog: &FeatureCatalog,
+    body: &mut String,
+    simple_attribute: &SimpleAttribute,
+) {
+    attribute_header(
+        catalog,
+        body,
+        simple_attribute,
+        simple_attribute.remarks(),
+        simple_attribute.definition_reference(),
+    );
+
+    body.push_str("<dl>\n");
+    definition(
+        body,
+        "Value type",
+        &escape(&simple_attribute.value_type().to_string()),
+    );
+    if let Some(codelist_type) = simple_attribute.codelist_type() {
+        definition(body, "Codelist type", &escape(&codelist_type.to_string()));
+    }
+    if let Some(unit_of_measure) = simple_attribute.unit_of_measure() {
+        let unit = match unit_of_measure.symbol() {
+            Some(symbol) => format!("{} ({})", unit_of_measure.name(), symbol),
+            None => unit_of_measure.name().to_string(),
+        };
+        definition(body, "Unit of measure", &escape(&unit));
+    }
+    if let Some(constraints) = simple_attribute.constraints() {
+        if let Some(val) = constraints.string_length() {
+            definition(body, "Maximum length", &val.to_string());
+        }
+        if let Some(val) = constraints.text_pattern() {
+            definition(
+                body,
+                "Pattern",
+                &format!("<span class=\"code\">{}</span>", escape(val)),
+            );
+        }
+        if let Some(range) = constraints.range() {
+            let lower = match range.lower_bound() {
+                Some(val) => val.to_string(),
+                None => "&minus;&infin;".to_string(),
+            };
+            let upper = match range.upper_bound() {
+                Some(val) => val.to_string(),
+                None => "&infin;".to_string(),
+            };
+            definition(
+                body,
+                "Range",
+                &format!("{} .. {} ({})", lower, upper, range.closure()),
+            );
+        }
+        if let Some(val) = constraints.precision() {
+            definition(body, "Precision", &val.to_string());
+        }
+    }
+    body.push_str("</dl>\n");
+
+    if !simple_attribute.listed_values().is_empty() {
+        body.push_str(
+            "<table>\n<tr><th>Code</th><th>Label</th><th>Definition</th><th>Reference</th></tr>\n",
+        );
+        for listed_value in simple_attribute.listed_values() {
+            let reference = match listed_value.definition_reference() {
+                Some(val) => reference_link(catalog, val),
+                None => String::new(),
+            };
+            body.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                listed_value.code(),
+                escape(listed_value.label()),
+                escape(listed_value.definition()),
+                reference
+            ));
+        }
+        body.push_str("</table>\n");
+    }
+}
+
+fn complex_attribute_section(
+    catalog: &FeatureCatalog,
+    body: &mut String,
+    complex_attribute: &ComplexAttribute,
+) {
+    attribute_header(
+        catalog,
+        body,
+        complex_attribute,
+        complex_attribute.remarks(),
+        complex_attribute.definition_reference(),
+    );
+
+    let bindings: Vec<(&str, &AttributeBinding)> = complex_attribute
+        .sub_attribute_bindings()
+        .iter()
+        .map(|val| (complex_attribute.code(), val))
+        .collect();
+    attribute_table(catalog, body, &bindings, |_| String::new());
+}
+
+fn attribute_header(
+    catalog: &FeatureCatalog,
+    body: &mut String,
+    item: &dyn Item,
+    remarks: Option<&str>,
+    definition_reference: Option<&DefinitionReference>,
+) {
+    body.push_str(&format!(
+        "<h2 id=\"{}\">{} <span class=\"code\">{}</span></h2>\n",
+        anchor(item.code()),
+        escape(item.name()),
+        escape(item.code())
+    ));
+    description(catalog, body, item, remarks, definition_reference);
+}
+
+fn item_header(
+    catalog: &FeatureCatalog,
+    item: &dyn Item,
+    remarks: Option<&str>,
+    definition_reference: Option<&DefinitionReference>,
+) -> String {
+    let mut body = format!("<p>{}</p>\n", link(INDEX_PAGE, "Back to the index"));
+    body.push_str(&format!(
+        "<h1>{} <span class=\"code\">{}</span></h1>\n",
+        escape(item.name()),
+        escape(item.code())
+    ));
+    description(catalog, &mut body, item, remarks, definition_reference);
+    body
+}
+
+fn description(
+    catalog: &FeatureCatalog,
+    body: &mut String,
+    item: &dyn Item,
+    remarks: Option<&str>,
+    definition_reference: Option<&DefinitionReference>,
+) {
+    body.push_str(&format!("<p>{}</p>\n", escape(item.definition())));
+    if let Some(val) = remarks {
+        body.push_str(&format!("<p><em>{}</em></p>\n", escape(val)));
+    }
+    if let Some(val) = item.alias() {
+        body.push_str(&format!("<p>Alias: {}</p>\n", escape(val)));
+    }
+    if let Some(val) = definition_reference {
+        body.push_str(&format!(
+            "<p>Reference: {}</p>\n",
+            reference_link(catalog, val)
+        ));
+    }
+}
+
+// The origin column is rendered by `origin`, which is handed the code of the type or complex
+// attribute that declared the binding.
+fn attribute_table<F>(
+    catalog: &FeatureCatalog,
+    body: &mut String,
+    bindings: &[(&str, &AttributeBinding)],
+    origin: F,
+) where
+    F: Fn(&str) -> String,
+{
+    if bindings.is_empty() {
+        return;
+    }
+
+    body.push_str(
+        "<h3>Attributes</h3>\n<table>\n<tr><th>Attribute</th><th>Multiplicity</th><th>Value type</th><th>Permitted values</th><th>Declared by</th></tr>\n",
+    );
+    for (declared_by, binding) in bindings {
+        let value_type = match catalog.simple_attribute(binding.attribute()) {
+            Some(val) => val.value_type().to_string(),
+            None if catalog.complex_attribute(binding.attribute()).is_some() => {
+                "complex".to_string()
+            }
+            None => String::new(),
+        };
+        let permitted_values: Vec<String> = binding
+            .permitted_values()
+            .iter()
+            .map(|val| val.to_string())
+            .collect();
+        body.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            attribute_reference(catalog, binding.attribute()),
+            binding.multiplicity(),
+            escape(&value_type),
+            permitted_values.join(", "),
+            origin(declared_by)
+        ));
+    }
+    body.push_str("</table>\n");
+}
+
+fn feature_binding_table(
+    catalog: &FeatureCatalog,
+    body: &mut String,
+    bindings: &[(&str, &FeatureBinding)],
+) {
+    if bindings.is_empty() {
+        return;
+    }
+
+    body.push_str(
+        "<h3>Feature bindings</h3>\n<table>\n<tr><th>Association</th><th>Role</th><th>Feature type</th><th>Multiplicity</th><th>Role type</th></tr>\n",
+    );
+    for (_, binding) in bindings {
+        body.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            escape(binding.association()),
+            escape(binding.role()),
+            type_link(catalog, binding.feature_type()),
+            binding.multiplicity(),
+            binding.role_type()
+        ));
+    }
+    body.push_str("</table>\n");
+}
+
+fn information_binding_table(
+    catalog: &FeatureCatalog,
+    body: &mut String,
+    bindings: &[(&str, &InformationBinding)],
+) {
+    if bindings.is_empty() {
+        return;
+    }
+
+    body.push_str(
+        "<h3>Information bindings</h3>\n<table>\n<tr><th>Association</th><th>Role</th><th>Information type</th><th>Multiplicity</th><th>Role type</th></tr>\n",
+    );
+    for (_, binding) in bindings {
+        body.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            escape(binding.association()),
+            escape(binding.role()),
+            type_link(catalog, binding.information_type()),
+            binding.multiplicity(),
+            binding.role_type()
+        ));
+    }
+    body.push_str("</table>\n");
+}
+
+fn used_by(body: &mut String, usages: Option<&Vec<(String, String)>>) {
+    let usages = match usages {
+        Some(val) => val,
+        None => {
+            body.push_str("<p>Not bound by any type.</p>\n");
+            return;
+        }
+    };
+
+    let mut usages: Vec<&(String, String)> = usages.iter().collect();
+    usages.sort();
+    usages.dedup();
+
+    body.push_str("<p>Used by:</p>\n<ul>\n");
+    for (name, href) in usages {
+        body.push_str(&format!("<li>{}</li>\n", link(href, name)));
+    }
+    body.push_str("</ul>\n");
+}
+
+fn sorted<T: Item>(items: &[T]) -> Vec<&T> {
+    let mut sorted: Vec<&T> = items.iter().collect();
+    sorted.sort_by_key(|val| val.name().to_lowercase());
+    sorted
+}
+
+fn page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape(title),
+        STYLE,
+        body
+    )
+}
+
+fn definition(body: &mut String, term: &str, description: &str) {
+    body.push_str(&format!("<dt>{}</dt><dd>{}</dd>\n", term, description));
+}
+
+fn list_item(body: &mut String, item: &dyn Item, href: &str) {
+    body.push_str(&format!(
+        "<li>{} <span class=\"code\">{}</span></li>\n",
+        link(href, item.name()),
+        escape(item.code())
+    ));
+}
+
+fn link(href: &str, text: &str) -> String {
+    format!("<a href=\"{}\">{}</a>", escape(href), escape(text))
+}
+
+fn type_link(catalog: &FeatureCatalog, code: &str) -> String {
+    if let Some(val) = catalog.feature_type(code) {
+        return link(&feature_page(code), val.name());
+    }
+    if let Some(val) = catalog.information_type(code) {
+        return link(&information_page(code), val.name());
+    }
+    escape(code)
+}
+
+fn attribute_reference(catalog: &FeatureCatalog, code: &str) -> String {
+    let name = match catalog.simple_attribute(code) {
+        Some(val) => Some(val.name()),
+        None => catalog.complex_attribute(code).map(|val| val.name()),
+    };
+
+    match name {
+        Some(name) => format!(
+            "{} <span class=\"code\">{}</span>",
+            link(&attribute_link(code), name),
+            escape(code)
+        ),
+        None => format!("<span class=\"code\">{}</span>", escape(code)),
+    }
+}
+
+fn reference_link(catalog: &FeatureCatalog, reference: &DefinitionReference) -> String {
+    let source = reference.definition_source();
+    let label = format!("{} {}", source, reference.source_identifier());
+    match catalog.definition_source(source) {
+        Some(_) => link(&format!("{}#source-{}", INDEX_PAGE, anchor(source)), &label),
+        None => escape(&label),
+    }
+}
+
+fn feature_page(code: &str) -> String {
+    format!("feature-{}.html", anchor(code))
+}
+
+fn information_page(code: &str) -> String {
+    format!("information-{}.html", anchor(code))
+}
+
+fn attribute_link(code: &str) -> String {
+    format!("{}#{}", ATTRIBUTES_PAGE, anchor(code))
+}
+
+// Codes are camel case identifiers in practice, but they end up in file names and fragment
+// identifiers so anything else is replaced.
+fn anchor(code: &str) -> String {
+    code.chars()
+        .map(|val| {
+            if val.is_ascii_alphanumeric() || val == '_' || val == '-' {
+                val
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn escape(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    for val in input.chars() {
+        match val {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&#39;"),
+            _ => output.push(val),
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Write, str::FromStr};
+
+    use tempfile::NamedTempFile;
+
+    use super::generate;
+    use crate::{feature::catalog::FeatureCatalog, S100Error};
+
+    #[test]
+    fn generate_catalog() {
+        let xml = r#"
+        <?xml version="1.0" encoding="utf-8"?>
+        <S100FC:S100_FC_FeatureCatalogue xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base" xmlns:S100CI="http://www.iho.int/S100CI" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+            <S100FC:name>Test Feature Catalogue</S100FC:name>
+            <S100FC:versionNumber>1.0.0</S100FC:versionNumber>
+            <S100FC:S100_FC_DefinitionSources>
+                <S100FC:S100_FC_DefinitionSource id="IHOREG">
+                    <S100FC:source>
+                        <S100CI:title>IHO Registry</S100CI:title>
+                    </S100FC:source>
+                </S100FC:S100_FC_DefinitionSource>
+            </S100FC:S100_FC_DefinitionSources>
+            <S100FC:S100_FC_SimpleAttributes>
+                <S100FC:S100_FC_SimpleAttribute>
+                    <S100FC:name>Category of light</S100FC:name>
+                    <S100FC:definition>Classification of different light types.</S100FC:definition>
+                    <S100FC:code>categoryOfLight</S100FC:code>
+                    <S100FC:valueType>enumeration</S100FC:valueType>
+                    <S100FC:listedValues>
+                        <S100FC:listedValue>
+                            <S100FC:label>directional function</S100FC:label>
+                            <S100FC:definition>A light illuminating a sector of very narrow angle.</S100FC:definition>
+                            <S100FC:code>1</S100FC:code>
+                            <S100FC:definitionReference>
+                                <S100FC:sourceIdentifier>42</S100FC:sourceIdentifier>
+                                <S100FC:definitionSource ref="IHOREG"/>
+                            </S100FC:definitionReference>
+                        </S100FC:listedValue>
+                    </S100FC:listedValues>
+                </S100FC:S100_FC_SimpleAttribute>
+                <S100FC:S100_FC_SimpleAttribute>
+                    <S100FC:name>Object name</S100FC:name>
+                    <S100FC:definition>The individual name of a feature &amp; more.</S100FC:definition>
+                    <S100FC:code>objectName</S100FC:code>
+                    <S100FC:valueType>text</S100FC:valueType>
+                </S100FC:S100_FC_SimpleAttribute>
+            </S100FC:S100_FC_SimpleAttributes>
+            <S100FC:S100_FC_FeatureTypes>
+                <S100FC:S100_FC_FeatureType isAbstract="true">
+                    <S100FC:name>Aid to navigation</S100FC:name>
+                    <S100FC:definition>Any aid to navigation.</S100FC:definition>
+                    <S100FC:code>AidsToNavigation</S100FC:code>
+                    <S100FC:attributeBinding sequential="false">
+                        <S100FC:multiplicity>
+                            <S100Base:lower>0</S100Base:lower>
+                            <S100Base:upper>1</S100Base:upper>
+                        </S100FC:multiplicity>
+                        <S100FC:attribute ref="objectName"/>
+                    </S100FC:attributeBinding>
+                    <S100FC:featureUseType>geographic</S100FC:featureUseType>
+                    <S100FC:permittedPrimitives>point</S100FC:permittedPrimitives>
+                    <S100FC:subType>Light</S100FC:subType>
+                </S100FC:S100_FC_FeatureType>
+                <S100FC:S100_FC_FeatureType isAbstract="false">
+                    <S100FC:name>Light</S100FC:name>
+                    <S100FC:definition>A luminous or lighted aid to navigation.</S100FC:definition>
+                    <S100FC:code>Light</S100FC:code>
+                    <S100FC:attributeBinding sequential="false">
+                        <S100FC:multiplicity>
+                            <S100Base:lower>1</S100Base:lower>
+                            <S100Base:upper xsi:nil="true" infinite="true"/>
+                        </S100FC:multiplicity>
+                        <S100FC:attribute ref="categoryOfLight"/>
+                    </S100FC:attributeBinding>
+                    <S100FC:featureUseType>geographic</S100FC:featureUseType>
+                    <S100FC:permittedPrimitives>point</S100FC:permittedPrimitives>
+                    <S100FC:superType>AidsToNavigation</S100FC:superType>
+                </S100FC:S100_FC_FeatureType>
+            </S100FC:S100_FC_FeatureTypes>
+        </S100FC:S100_FC_FeatureCatalogue>"#;
+
+        let mut temp_file = NamedTempFile::new().expect("Unable to create temp file");
+        temp_file
+            .write_all(xml.as_bytes())
+            .expect("Unable to write XML");
+        let catalog = FeatureCatalog::open(temp_file.path()).unwrap();
+        temp_file.close().expect("Unable to close temp file");
+
+        let pages = generate(&catalog).unwrap();
+        let names: Vec<&str> = pages.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "index.html",
+                "feature-AidsToNavigation.html",
+                "feature-Light.html",
+                "attributes.html"
+            ]
+        );
+
+        let index = &pages[0].1;
+        assert!(index.contains("<title>Test Feature Catalogue</title>"));
+        assert!(index.contains("<li><a href=\"feature-Light.html\">Light</a>"));
+        assert!(index.contains("<dt id=\"source-IHOREG\">IHOREG</dt><dd>IHO Registry</dd>"));
+
+        let light = &pages[2].1;
+        assert!(light.contains(
+            "<dt>Supertypes</dt><dd><a href=\"feature-AidsToNavigation.html\">Aid to navigation</a></dd>"
+        ));
+        assert!(light.contains(
+            "<tr><td><a href=\"attributes.html#objectName\">Object name</a> <span class=\"code\">objectName</span></td><td>0..1</td><td>text</td><td></td><td><a href=\"feature-AidsToNavigation.html\">Aid to navigation</a></td></tr>"
+        ));
+        assert!(light.contains("<td>1..*</td><td>enumeration</td><td></td><td><a href=\"feature-Light.html\">Light</a></td>"));
+
+        let attributes = &pages[3].1;
+        assert!(attributes.contains("The individual name of a feature &amp; more."));
+        assert!(attributes.contains(
+            "<tr><td>1</td><td>directional function</td><td>A light illuminating a sector of very narrow angle.</td><td><a href=\"index.html#source-IHOREG\">IHOREG 42</a></td></tr>"
+        ));
+        assert!(attributes.contains(
+            "<p>Used by:</p>\n<ul>\n<li><a href=\"feature-AidsToNavigation.html\">Aid to navigation</a></li>\n<li><a href=\"feature-Light.html\">Light</a></li>\n</ul>"
+        ));
+    }
+
+    #[test]
+    fn generate_colliding_names() {
+        let feature_type = |code: &str| {
+            format!(
+                "<S100FC:S100_FC_FeatureType isAbstract=\"false\"><S100FC:name>{0}</S100FC:name><S100FC:definition>{0}</S100FC:definition><S100FC:code>{0}</S100FC:code><S100FC:featureUseType>geographic</S100FC:featureUseType><S100FC:permittedPrimitives>point</S100FC:permittedPrimitives></S100FC:S100_FC_FeatureType>",
+                code
+            )
+        };
+        let xml = format!(
+            r#"<S100FC:S100_FC_FeatureCatalogue xmlns:S100FC="http://www.iho.int/S100FC"><S100FC:S100_FC_FeatureTypes>{}{}</S100FC:S100_FC_FeatureTypes></S100FC:S100_FC_FeatureCatalogue>"#,
+            feature_type("a.b"),
+            feature_type("a_b")
+        );
+
+        match generate(&FeatureCatalog::from_str(&xml).unwrap()) {
+            Err(S100Error::DuplicateName {
+                name,
+                first,
+                second,
+            }) => {
+                assert_eq!(name, "feature-a_b.html");
+                assert_eq!(first, "a.b");
+                assert_eq!(second, "a_b");
+            }
+            val => panic!("unexpected result: {:?}", val.map(|_| ())),
+        }
+    }
+}
@@ -0,0 +1,625 @@
+use std::{fs, path::Path};
+
+use crate::{
+    feature::catalog::{
+        AttributeBinding, AttributeConstraints, AttributeValueType, FeatureCatalog, IntervalType,
+        Item, SimpleAttribute,
+    },
+    Result, S100Error,
+};
+
+const DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+const DATE_PATTERN: &str = r"^(\d{8}|\d{4}-\d{2}-\d{2})$";
+const TIME_PATTERN: &str = r"^(\d{6}|\d{2}:\d{2}:\d{2})(Z|[+-]\d{2}:?\d{2})?$";
+const DATE_TIME_PATTERN: &str =
+    r"^(\d{8}|\d{4}-\d{2}-\d{2})T(\d{6}|\d{2}:\d{2}:\d{2})(Z|[+-]\d{2}:?\d{2})?$";
+const TRUNCATED_DATE_PATTERN: &str = r"^[0-9-]{4}[0-9-]{2}[0-9-]{2}$";
+const INTERVAL_TYPES: &[IntervalType] = &[
+    IntervalType::OpenInterval,
+    IntervalType::GeLtInterval,
+    IntervalType::GtLeInterval,
+    IntervalType::ClosedInterval,
+    IntervalType::GtSemiInterval,
+    IntervalType::GeSemiInterval,
+    IntervalType::LtSemiInterval,
+    IntervalType::LeSemiInterval,
+];
+
+// Emits one definition per simple and complex attribute, and per concrete information and
+// feature type. Types only carry their effective attribute bindings; information and feature
+// bindings are associations between instances and are left to the client.
+pub fn generate(catalog: &FeatureCatalog) -> Result<String> {
+    let mut definitions: Vec<(String, Value)> = Vec::new();
+
+    for simple_attribute in catalog.simple_attributes() {
+        define(
+            &mut definitions,
+            simple_attribute,
+            simple_attribute_schema(simple_attribute)?,
+        )?;
+    }
+
+    for complex_attribute in catalog.complex_attributes() {
+        define(
+            &mut definitions,
+            complex_attribute,
+            object_schema(
+                catalog,
+                complex_attribute,
+                complex_attribute.sub_attribute_bindings(),
+            )?,
+        )?;
+    }
+
+    for information_type in catalog.information_types() {
+        if information_type.is_abstract() {
+            continue;
+        }
+        let bindings = catalog.effective_information_bindings(information_type.code())?;
+        define(
+            &mut definitions,
+            information_type,
+            object_schema(catalog, information_type, bindings.attribute_bindings())?,
+        )?;
+    }
+
+    for feature_type in catalog.feature_types() {
+        if feature_type.is_abstract() {
+            continue;
+        }
+        let bindings = catalog.effective_feature_bindings(feature_type.code())?;
+        define(
+            &mut definitions,
+            feature_type,
+            object_schema(catalog, feature_type, bindings.attribute_bindings())?,
+        )?;
+    }
+
+    let mut schema = vec![("$schema".to_string(), Value::from(DRAFT))];
+    if let Some(name) = catalog.name() {
+        let title = match catalog.version_number() {
+            Some(version) => format!("{} {}", name.trim(), version.trim()),
+            None => name.trim().to_string(),
+        };
+        schema.push(("title".to_string(), Value::from(title.as_str())));
+    }
+    schema.push(("$defs".to_string(), Value::Object(definitions)));
+
+    let mut output = String::new();
+    Value::Object(schema).write(&mut output, 0);
+    output.push('\n');
+    Ok(output)
+}
+
+pub fn write<P: AsRef<Path>>(catalog: &FeatureCatalog, path: P) -> Result<()> {
+    let output = generate(catalog)?;
+    fs::write(path, output)?;
+    Ok(())
+}
+
+// Every kind of item shares the one $defs object, keyed by code.
+fn define(definitions: &mut Vec<(String, Value)>, item: &dyn Item, schema: Value) -> Result<()> {
+    if definitions.iter().any(|(key, _)| key == item.code()) {
+        return Err(S100Error::DuplicateName {
+            name: item.code().to_string(),
+            first: item.code().to_string(),
+            second: item.code().to_string(),
+        });
+    }
+
+    definitions.push((item.code().to_string(), schema));
+    Ok(())
+}
+
+fn simple_attribute_schema(attribute: &SimpleAttribute) -> Result<Value> {
+    let mut schema = described(attribute);
+
+    match attribute.value_type() {
+        AttributeValueType::Boolean => schema.push(typed("boolean")),
+        AttributeValueType::Enumeration => {
+            schema.push(typed("integer"));
+            let values = attribute
+                .listed_values()
+                .iter()
+                .map(|val| {
+                    Value::Object(vec![
+                        ("const".to_string(), Value::Integer(val.code() as i64)),
+                        ("title".to_string(), Value::from(val.label())),
+                        ("description".to_string(), Value::from(val.definition())),
+                    ])
+                })
+                .collect();
+            schema.push(("oneOf".to_string(), Value::Array(values)));
+        }
+        AttributeValueType::Codelist => {
+            schema.push(typed("string"));
+            if !attribute.listed_values().is_empty() {
+                let labels = attribute
+                    .listed_values()
+                    .iter()
+                    .map(|val| Value::from(val.label()))
+                    .collect();
+                schema.push(("examples".to_string(), Value::Array(labels)));
+            }
+        }
+        AttributeValueType::Integer => schema.push(typed("integer")),
+        AttributeValueType::Real => schema.push(typed("number")),
+        AttributeValueType::Text => schema.push(typed("string")),
+        AttributeValueType::Date => {
+            schema.push(typed("string"));
+            schema.push(("pattern".to_string(), Value::from(DATE_PATTERN)));
+        }
+        AttributeValueType::Time => {
+            schema.push(typed("string"));
+            schema.push(("pattern".to_string(), Value::from(TIME_PATTERN)));
+        }
+        AttributeValueType::DateTime => {
+            schema.push(typed("string"));
+            schema.push(("pattern".to_string(), Value::from(DATE_TIME_PATTERN)));
+        }
+        AttributeValueType::TruncatedDate => {
+            schema.push(typed("string"));
+            schema.push(("pattern".to_string(), Value::from(TRUNCATED_DATE_PATTERN)));
+        }
+        AttributeValueType::Uri | AttributeValueType::Url => {
+            schema.push(typed("string"));
+            schema.push(("format".to_string(), Value::from("uri")));
+        }
+        AttributeValueType::Urn => {
+            schema.push(typed("string"));
+            schema.push(("pattern".to_string(), Value::from("^urn:")));
+        }
+        AttributeValueType::IntervalType => {
+            schema.push(typed("string"));
+            let names = INTERVAL_TYPES
+                .iter()
+                .map(|val| Value::from(val.to_string().as_str()))
+                .collect();
+            schema.push(("enum".to_string(), Value::Array(names)));
+        }
+        AttributeValueType::NumPlusUnit => {
+            schema.push(typed("object"));
+            schema.push((
+                "properties".to_string(),
+                Value::Object(vec![
+                    ("value".to_string(), Value::Object(vec![typed("number")])),
+                    ("unit".to_string(), Value::Object(vec![typed("string")])),
+                ]),
+            ));
+            schema.push((
+                "required".to_string(),
+                Value::Array(vec![Value::from("value"), Value::from("unit")]),
+            ));
+        }
+        AttributeValueType::UnlimitedInteger => {
+            // null stands for infinity
+            schema.push((
+                "type".to_string(),
+                Value::Array(vec![Value::from("integer"), Value::from("null")]),
+            ));
+            schema.push(("minimum".to_string(), Value::Integer(0)));
+        }
+    }
+
+    if let Some(constraints) = attribute.constraints() {
+        add_constraints(&mut schema, attribute, constraints)?;
+    }
+
+    Ok(Value::Object(schema))
+}
+
+// Precision is not mapped: `multipleOf` with a fractional divisor is unreliable across
+// validators.
+fn add_constraints(
+    schema: &mut Vec<(String, Value)>,
+    attribute: &SimpleAttribute,
+    constraints: &AttributeConstraints,
+) -> Result<()> {
+    if let Some(val) = constraints.string_length() {
+        schema.push(("maxLength".to_string(), Value::Integer(val as i64)));
+    }
+    // XSD patterns always match the whole value, ECMA-262 ones match anywhere unless anchored.
+    if let Some(val) = constraints.text_pattern() {
+        let pattern = (
+            "pattern".to_string(),
+            Value::from(format!("^(?:{})$", val).as_str()),
+        );
+        // Date, time and URN values already carry a pattern and an object cannot repeat a key.
+        match schema.iter().position(|(key, _)| key == "pattern") {
+            Some(position) => {
+                let type_pattern = schema.remove(position);
+                schema.push((
+                    "allOf".to_string(),
+                    Value::Array(vec![
+                        Value::Object(vec![type_pattern]),
+                        Value::Object(vec![pattern]),
+                    ]),
+                ));
+            }
+            None => schema.push(pattern),
+        }
+    }
+    if let Some(range) = constraints.range() {
+        let (exclusive_lower, exclusive_upper) = match range.closure() {
+            IntervalType::OpenInterval => (true, true),
+            IntervalType::GeLtInterval => (false, true),
+            IntervalType::GtLeInterval => (true, false),
+            IntervalType::GtSemiInterval => (true, false),
+            IntervalType::LtSemiInterval => (false, true),
+            _ => (false, false),
+        };
+        // JSON has no representation for NaN or infinity.
+        if [range.lower_bound(), range.upper_bound()]
+            .iter()
+            .flatten()
+            .any(|val| !val.is_finite())
+        {
+            return Err(S100Error::NonFiniteRange {
+                code: attribute.code().to_string(),
+            });
+        }
+        if let Some(val) = range.lower_bound() {
+            let keyword = if exclusive_lower {
+                "exclusiveMinimum"
+            } else {
+                "minimum"
+            };
+            schema.push((keyword.to_string(), Value::Number(val)));
+        }
+        if let Some(val) = range.upper_bound() {
+            let keyword = if exclusive_upper {
+                "exclusiveMaximum"
+            } else {
+                "maximum"
+            };
+            schema.push((keyword.to_string(), Value::Number(val)));
+        }
+    }
+
+    Ok(())
+}
+
+fn object_schema(
+    catalog: &FeatureCatalog,
+    item: &dyn Item,
+    bindings: &[AttributeBinding],
+) -> Result<Value> {
+    let mut schema = described(item);
+    schema.push(typed("object"));
+
+    let mut properties: Vec<(String, Value)> = Vec::new();
+    let mut required: Vec<Value> = Vec::new();
+    for binding in bindings {
+        let code = binding.attribute();
+        if catalog.simple_attribute(code).is_none() && catalog.complex_attribute(code).is_none() {
+            return Err(S100Error::UndefinedAttribute {
+                code: item.code().to_string(),
+                attribute: code.to_string(),
+            });
+        }
+
+        properties.push((code.to_string(), property_schema(binding)));
+        if binding.multiplicity().lower() > 0 {
+            required.push(Value::from(code));
+        }
+    }
+
+    schema.push(("properties".to_string(), Value::Object(properties)));
+    if !required.is_empty() {
+        schema.push(("required".to_string(), Value::Array(required)));
+    }
+    schema.push(("additionalProperties".to_string(), Value::Boolean(false)));
+
+    Ok(Value::Object(schema))
+}
+
+// A binding with an upper bound of one holds a single value, anything else becomes an array.
+fn property_schema(binding: &AttributeBinding) -> Value {
+    let mut reference = vec![(
+        "$ref".to_string(),
+        Value::from(format!("#/$defs/{}", binding.attribute()).as_str()),
+    )];
+    if !binding.permitted_values().is_empty() {
+        let values = binding
+            .permitted_values()
+            .iter()
+            .map(|val| Value::Integer(*val as i64))
+            .collect();
+        reference.push(("enum".to_string(), Value::Array(values)));
+    }
+
+    let multiplicity = binding.multiplicity();
+    if multiplicity.upper() == Some(1) {
+        return Value::Object(reference);
+    }
+
+    let mut schema = vec![
+        typed("array"),
+        ("items".to_string(), Value::Object(reference)),
+    ];
+    if multiplicity.lower() > 0 {
+        schema.push((
+            "minItems".to_string(),
+            Value::Integer(multiplicity.lower() as i64),
+        ));
+    }
+    if let Some(val) = multiplicity.upper() {
+        schema.push(("maxItems".to_string(), Value::Integer(val as i64)));
+    }
+    Value::Object(schema)
+}
+
+fn described(item: &dyn Item) -> Vec<(String, Value)> {
+    vec![
+        ("title".to_string(), Value::from(item.name().trim())),
+        (
+            "description".to_string(),
+            Value::from(item.definition().trim()),
+        ),
+    ]
+}
+
+fn typed(name: &str) -> (String, Value) {
+    ("type".to_string(), Value::from(name))
+}
+
+// Just enough of a JSON document model to print the schema, keeping key order stable.
+enum Value {
+    Boolean(bool),
+    Integer(i64),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl From<&str> for Value {
+    fn from(input: &str) -> Value {
+        Value::String(input.to_string())
+    }
+}
+
+impl Value {
+    fn write(&self, output: &mut String, depth: usize) {
+        match self {
+            Value::Boolean(val) => output.push_str(&val.to_string()),
+            Value::Integer(val) => output.push_str(&val.to_string()),
+            Value::Number(val) => output.push_str(&val.to_string()),
+            Value::String(val) => write_string(output, val),
+            Value::Array(values) => {
+                if values.is_empty() {
+                    output.push_str("[]");
+                    return;
+                }
+                output.push('[');
+                for (index, val) in values.iter().enumerate() {
+                    if index > 0 {
+                        output.push(',');
+                    }
+                    indent(output, depth + 1);
+                    val.write(output, depth + 1);
+                }
+                indent(output, depth);
+                output.push(']');
+            }
+            Value::Object(entries) => {
+                if entries.is_empty() {
+                    output.push_str("{}");
+                    return;
+                }
+                output.push('{');
+                for (index, (key, val)) in entries.iter().enumerate() {
+                    if index > 0 {
+                        output.push(',');
+                    }
+                    indent(output, depth + 1);
+                    write_string(output, key);
+                    output.push_str(": ");
+                    val.write(output, depth + 1);
+                }
+                indent(output, depth);
+                output.push('}');
+            }
+        }
+    }
+}
+
+fn indent(output: &mut String, depth: usize) {
+    output.push('\n');
+    for _ in 0..depth {
+        output.push_str("  ");
+    }
+}
+
+fn write_string(output: &mut String, input: &str) {
+    output.push('"');
+    for c in input.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if (c as u32) < 0x20 => output.push_str(&format!("\\u{:04x}", c as u32)),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Write, str::FromStr};
+
+    use tempfile::NamedTempFile;
+
+    use super::generate;
+    use crate::{feature::catalog::FeatureCatalog, S100Error};
+
+    #[test]
+    fn generate_catalog() {
+        let xml = r#"
+        <?xml version="1.0" encoding="utf-8"?>
+        <S100FC:S100_FC_FeatureCatalogue xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+            <S100FC:name>Test Feature Catalogue</S100FC:name>
+            <S100FC:versionNumber>1.0.0</S100FC:versionNumber>
+            <S100FC:S100_FC_SimpleAttributes>
+                <S100FC:S100_FC_SimpleAttribute>
+                    <S100FC:name>Category of light</S100FC:name>
+                    <S100FC:definition>Classification of different light types.</S100FC:definition>
+                    <S100FC:code>categoryOfLight</S100FC:code>
+                    <S100FC:valueType>enumeration</S100FC:valueType>
+                    <S100FC:listedValues>
+                        <S100FC:listedValue>
+                            <S100FC:label>directional function</S100FC:label>
+                            <S100FC:definition>A light illuminating a sector of very narrow angle.</S100FC:definition>
+                            <S100FC:code>1</S100FC:code>
+                        </S100FC:listedValue>
+                    </S100FC:listedValues>
+                </S100FC:S100_FC_SimpleAttribute>
+                <S100FC:S100_FC_SimpleAttribute>
+                    <S100FC:name>Name</S100FC:name>
+                    <S100FC:definition>The individual "name" of a feature.</S100FC:definition>
+                    <S100FC:code>name</S100FC:code>
+                    <S100FC:valueType>text</S100FC:valueType>
+                    <S100FC:constraints>
+                        <S100Base:stringLength>80</S100Base:stringLength>
+                        <S100Base:textPattern>[A-Z].*</S100Base:textPattern>
+                    </S100FC:constraints>
+                </S100FC:S100_FC_SimpleAttribute>
+                <S100FC:S100_FC_SimpleAttribute>
+                    <S100FC:name>Orientation value</S100FC:name>
+                    <S100FC:definition>The angular distance measured from true north.</S100FC:definition>
+                    <S100FC:code>orientationValue</S100FC:code>
+                    <S100FC:valueType>real</S100FC:valueType>
+                    <S100FC:constraints>
+                        <S100Base:ACRange>
+                            <S100Base:lowerBound>0</S100Base:lowerBound>
+                            <S100Base:upperBound>360</S100Base:upperBound>
+                            <S100Base:closure>geLtInterval</S100Base:closure>
+                        </S100Base:ACRange>
+                    </S100FC:constraints>
+                </S100FC:S100_FC_SimpleAttribute>
+            </S100FC:S100_FC_SimpleAttributes>
+            <S100FC:S100_FC_ComplexAttributes>
+                <S100FC:S100_FC_ComplexAttribute>
+                    <S100FC:name>Feature Name</S100FC:name>
+                    <S100FC:definition>Provides the name of an entity.</S100FC:definition>
+                    <S100FC:code>featureName</S100FC:code>
+                    <S100FC:subAttributeBinding sequential="false">
+                        <S100FC:multiplicity>
+                            <S100Base:lower>1</S100Base:lower>
+                            <S100Base:upper>1</S100Base:upper>
+                        </S100FC:multiplicity>
+                        <S100FC:attribute ref="name"/>
+                    </S100FC:subAttributeBinding>
+                </S100FC:S100_FC_ComplexAttribute>
+            </S100FC:S100_FC_ComplexAttributes>
+            <S100FC:S100_FC_FeatureTypes>
+                <S100FC:S100_FC_FeatureType isAbstract="false">
+                    <S100FC:name>Light</S100FC:name>
+                    <S100FC:definition>A luminous or lighted aid to navigation.</S100FC:definition>
+                    <S100FC:code>Light</S100FC:code>
+                    <S100FC:attributeBinding sequential="false">
+                        <S100FC:multiplicity>
+                            <S100Base:lower>1</S100Base:lower>
+                            <S100Base:upper xsi:nil="true" infinite="true"/>
+                        </S100FC:multiplicity>
+                        <S100FC:attribute ref="categoryOfLight"/>
+                    </S100FC:attributeBinding>
+                    <S100FC:attributeBinding sequential="false">
+                        <S100FC:multiplicity>
+                            <S100Base:lower>0</S100Base:lower>
+                            <S100Base:upper>1</S100Base:upper>
+                        </S100FC:multiplicity>
+                        <S100FC:attribute ref="orientationValue"/>
+                    </S100FC:attributeBinding>
+                    <S100FC:attributeBinding sequential="false">
+                        <S100FC:multiplicity>
+                            <S100Base:lower>0</S100Base:lower>
+                            <S100Base:upper>3</S100Base:upper>
+                        </S100FC:multiplicity>
+                        <S100FC:attribute ref="featureName"/>
+                    </S100FC:attributeBinding>
+                    <S100FC:featureUseType>geographic</S100FC:featureUseType>
+                    <S100FC:permittedPrimitives>point</S100FC:permittedPrimitives>
+                </S100FC:S100_FC_FeatureType>
+            </S100FC:S100_FC_FeatureTypes>
+        </S100FC:S100_FC_FeatureCatalogue>"#;
+
+        let mut temp_file = NamedTempFile::new().expect("Unable to create temp file");
+        temp_file
+            .write_all(xml.as_bytes())
+            .expect("Unable to write XML");
+        let catalog = FeatureCatalog::open(temp_file.path()).unwrap();
+        temp_file.close().expect("Unable to close temp file");
+
+        let output = generate(&catalog).unwrap();
+
+        assert!(output.starts_with(
+            "{\n  \"$schema\": \"https://json-schema.org/draft/2020-12/schema\",\n  \"title\": \"Test Feature Catalogue 1.0.0\",\n"
+        ));
+        assert!(output.contains(
+            "    \"categoryOfLight\": {\n      \"title\": \"Category of light\",\n      \"description\": \"Classification of different light types.\",\n      \"type\": \"integer\",\n      \"oneOf\": [\n        {\n          \"const\": 1,\n          \"title\": \"directional function\",\n"
+        ));
+        assert!(output.contains("\"description\": \"The individual \\\"name\\\" of a feature.\",\n      \"type\": \"string\",\n      \"maxLength\": 80,\n      \"pattern\": \"^(?:[A-Z].*)$\"\n"));
+        assert!(output.contains("\"minimum\": 0,\n      \"exclusiveMaximum\": 360\n"));
+        assert!(output.contains(
+            "\"categoryOfLight\": {\n          \"type\": \"array\",\n          \"items\": {\n            \"$ref\": \"#/$defs/categoryOfLight\"\n          },\n          \"minItems\": 1\n        },"
+        ));
+        assert!(output.contains(
+            "\"orientationValue\": {\n          \"$ref\": \"#/$defs/orientationValue\"\n        },"
+        ));
+        assert!(output.contains("\"maxItems\": 3\n"));
+        assert!(output.contains(
+            "\"required\": [\n        \"categoryOfLight\"\n      ],\n      \"additionalProperties\": false\n"
+        ));
+
+        let xml = xml.replace(
+            "<S100Base:lowerBound>0</S100Base:lowerBound>",
+            "<S100Base:lowerBound>NaN</S100Base:lowerBound>",
+        );
+        let catalog = FeatureCatalog::from_str(&xml).unwrap();
+        assert!(generate(&catalog).is_err());
+    }
+
+    #[test]
+    fn generate_conflicts() {
+        let xml = r#"
+        <S100FC:S100_FC_FeatureCatalogue xmlns:S100FC="http://www.iho.int/S100FC" xmlns:S100Base="http://www.iho.int/S100Base">
+            <S100FC:S100_FC_SimpleAttributes>
+                <S100FC:S100_FC_SimpleAttribute>
+                    <S100FC:name>Date start</S100FC:name>
+                    <S100FC:definition>The start of the active period.</S100FC:definition>
+                    <S100FC:code>dateStart</S100FC:code>
+                    <S100FC:valueType>date</S100FC:valueType>
+                    <S100FC:constraints>
+                        <S100Base:textPattern>20.*</S100Base:textPattern>
+                    </S100FC:constraints>
+                </S100FC:S100_FC_SimpleAttribute>
+            </S100FC:S100_FC_SimpleAttributes>
+        </S100FC:S100_FC_FeatureCatalogue>"#;
+
+        let output = generate(&FeatureCatalog::from_str(xml).unwrap()).unwrap();
+        assert_eq!(output.matches("\"pattern\"").count(), 2);
+        assert!(output.contains(
+            "\"allOf\": [\n        {\n          \"pattern\": \"^(\\\\d{8}|\\\\d{4}-\\\\d{2}-\\\\d{2})$\"\n        },\n        {\n          \"pattern\": \"^(?:20.*)$\"\n        }\n      ]\n"
+        ));
+
+        let xml = xml.replace(
+            "</S100FC:S100_FC_SimpleAttributes>",
+            r#"</S100FC:S100_FC_SimpleAttributes>
+            <S100FC:S100_FC_ComplexAttributes>
+                <S100FC:S100_FC_ComplexAttribute>
+                    <S100FC:name>Date start</S100FC:name>
+                    <S100FC:definition>The start of the active period.</S100FC:definition>
+                    <S100FC:code>dateStart</S100FC:code>
+                </S100FC:S100_FC_ComplexAttribute>
+            </S100FC:S100_FC_ComplexAttributes>"#,
+        );
+        assert!(matches!(
+            generate(&FeatureCatalog::from_str(&xml).unwrap()),
+            Err(S100Error::DuplicateName { .. })
+        ));
+    }
+}
@@ -1,5 +1,11 @@
 pub mod catalog;
 
+pub mod codegen;
+
 pub mod concept;
 
+pub mod html;
+
+pub mod json_schema;
+
 const QUANTITY_SPECIFICATION: &str = "quantitySpecification";
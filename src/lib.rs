@@ -1,5 +1,13 @@
+mod diagnostics;
+pub use diagnostics::ParseMode;
+
 mod error;
-pub use error::{Result, S100Error};
+pub use error::{Location, Result, S100Error};
+
+mod namespace;
+
+mod schema;
+pub use schema::{Schema, SchemaViolation};
 
 pub mod feature;
 
@@ -1,8 +1,8 @@
 use libxml::tree::Node;
 use std::str::FromStr;
 
-use super::{DataFormat, DATA_DISCOVERY_METADATA};
-use crate::{Result, S100Error};
+use super::{DataFormat, DATA_DISCOVERY_METADATA, XC_NAMESPACES};
+use crate::{diagnostics::Diagnostics, namespace::element_name, Result, S100Error};
 
 const FILE_NAME: &str = "fileName";
 const FILE_PATH: &str = "filePath";
@@ -19,8 +19,11 @@ pub struct DatasetDiscoveryMetadata {
 }
 
 impl DatasetDiscoveryMetadata {
-    pub(super) fn parse(node: Node) -> Result<DatasetDiscoveryMetadata> {
-        if node.get_name() != DATA_DISCOVERY_METADATA {
+    pub(super) fn parse(
+        node: Node,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<DatasetDiscoveryMetadata> {
+        if element_name(&node, XC_NAMESPACES) != DATA_DISCOVERY_METADATA {
             return S100Error::invalid_child(node);
         }
 
@@ -31,19 +34,16 @@ impl DatasetDiscoveryMetadata {
         let mut data_type_version: Option<String> = None;
 
         for child_node in node.get_child_elements() {
-            match child_node.get_name().as_str() {
+            match element_name(&child_node, XC_NAMESPACES).as_str() {
                 FILE_NAME => file_name = Some(child_node.get_content()),
                 FILE_PATH => file_path = Some(child_node.get_content()),
                 DESCRIPTION => description = Some(child_node.get_content()),
                 DATA_TYPE => match DataFormat::from_str(child_node.get_content().as_str()) {
                     Ok(val) => data_type = Some(val),
-                    Err(_) => return S100Error::invalid_value(child_node),
+                    Err(e) => return Err(e.at(&child_node)),
                 },
                 DATA_TYPE_VERSION => data_type_version = Some(child_node.get_content()),
-                _ => {
-                    //TODO: return error if we find unrecognized element
-                    //return Error::invalid_child(child_node);
-                }
+                _ => diagnostics.unexpected(&child_node),
             };
         }
 
@@ -95,6 +95,7 @@ mod tests {
     use libxml::parser::Parser;
 
     use super::{DataFormat, DatasetDiscoveryMetadata};
+    use crate::{diagnostics::Diagnostics, ParseMode};
 
     #[test]
     fn deserialize() {
@@ -212,7 +213,9 @@ mod tests {
         let parser = Parser::default();
         let document = parser.parse_string(xml).unwrap();
         let node = document.get_root_element().unwrap();
-        let target = DatasetDiscoveryMetadata::parse(node).unwrap();
+        let target =
+            DatasetDiscoveryMetadata::parse(node, &mut Diagnostics::new(ParseMode::Strict))
+                .unwrap();
 
         assert_eq!(target.file_name(), "101AA00AA1NPOL3.000");
         assert_eq!(target.file_path(), "101AA00AA1NPOL3");
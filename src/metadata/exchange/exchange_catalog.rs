@@ -1,8 +1,12 @@
-use libxml::parser::Parser;
-use std::path::Path;
+use libxml::{parser::Parser, tree::Document};
+use std::{fs, io::Read, path::Path, str::FromStr};
 
-use super::{DatasetDiscoveryMetadata, EXCHANGE_CATALOG};
-use crate::{Result, S100Error};
+use super::{DatasetDiscoveryMetadata, EXCHANGE_CATALOG, XC_NAMESPACES};
+use crate::{
+    diagnostics::Diagnostics,
+    namespace::{check_namespace, element_name, Namespace},
+    ParseMode, Result, S100Error, Schema,
+};
 
 const DATASET_DISCOVERY_METADATA: &str = "datasetDiscoveryMetadata";
 
@@ -12,50 +16,92 @@ pub struct ExchangeCatalog {
 
 impl ExchangeCatalog {
     pub fn open<P: AsRef<Path>>(path: P) -> Result<ExchangeCatalog> {
+        let xml = fs::read(&path)?;
+        ExchangeCatalog::from_bytes(&xml).map_err(|e| e.with_path(path.as_ref()))
+    }
+
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<ExchangeCatalog> {
+        let mut xml = Vec::new();
+        reader.read_to_end(&mut xml)?;
+        ExchangeCatalog::from_bytes(&xml)
+    }
+
+    pub fn from_bytes(xml: &[u8]) -> Result<ExchangeCatalog> {
+        let parser = Parser::default();
+        let document = parser.parse_string(xml)?;
+        ExchangeCatalog::from_document(&document, &mut Diagnostics::new(ParseMode::Strict))
+    }
+
+    pub fn from_bytes_with_mode(
+        xml: &[u8],
+        mode: ParseMode,
+    ) -> Result<(ExchangeCatalog, Vec<S100Error>)> {
+        let parser = Parser::default();
+        let document = parser.parse_string(xml)?;
+        let mut diagnostics = Diagnostics::new(mode);
+        let catalog = ExchangeCatalog::from_document(&document, &mut diagnostics)?;
+        Ok((catalog, diagnostics.into_warnings()))
+    }
+
+    pub fn open_validated<P: AsRef<Path>>(path: P, schema: &Schema) -> Result<ExchangeCatalog> {
+        let xml = fs::read(&path)?;
         let parser = Parser::default();
-        let filename_result = path.as_ref().to_str();
-        match filename_result {
-            Some(filename) => {
-                let document = parser.parse_file(filename)?;
-
-                if let Some(root) = document.get_root_element() {
-                    let mut exchange_catalog = ExchangeCatalog {
-                        dataset_discovery_metadata: Vec::new(),
-                    };
-
-                    let root_name = root.get_name();
-                    if root_name == EXCHANGE_CATALOG {
-                        for node in root.get_child_elements() {
-                            match node.get_name().as_str() {
-                                DATASET_DISCOVERY_METADATA => {
-                                    for target_node in node.get_child_elements() {
-                                        match DatasetDiscoveryMetadata::parse(target_node) {
-                                            Ok(val) => exchange_catalog
-                                                .dataset_discovery_metadata
-                                                .push(val),
-                                            Err(e) => return Err(e),
-                                        }
+        let document = parser.parse_string(&xml)?;
+        schema.check(&document)?;
+        ExchangeCatalog::from_document(&document, &mut Diagnostics::new(ParseMode::Strict))
+            .map_err(|e| e.with_path(path.as_ref()))
+    }
+
+    pub fn open_with_mode<P: AsRef<Path>>(
+        path: P,
+        mode: ParseMode,
+    ) -> Result<(ExchangeCatalog, Vec<S100Error>)> {
+        let xml = fs::read(&path)?;
+        let (catalog, warnings) = ExchangeCatalog::from_bytes_with_mode(&xml, mode)
+            .map_err(|e| e.with_path(path.as_ref()))?;
+        let warnings = warnings
+            .into_iter()
+            .map(|val| val.with_path(path.as_ref()))
+            .collect();
+        Ok((catalog, warnings))
+    }
+
+    fn from_document(
+        document: &Document,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<ExchangeCatalog> {
+        if let Some(root) = document.get_root_element() {
+            let mut exchange_catalog = ExchangeCatalog {
+                dataset_discovery_metadata: Vec::new(),
+            };
+
+            let root_name = root.get_name();
+            if root_name == EXCHANGE_CATALOG {
+                check_namespace(&root, Namespace::ExchangeCatalogue)?;
+
+                for node in root.get_child_elements() {
+                    match element_name(&node, XC_NAMESPACES).as_str() {
+                        DATASET_DISCOVERY_METADATA => {
+                            for target_node in node.get_child_elements() {
+                                match DatasetDiscoveryMetadata::parse(target_node, diagnostics) {
+                                    Ok(val) => {
+                                        exchange_catalog.dataset_discovery_metadata.push(val)
                                     }
-                                }
-                                "" => {}
-                                _ => {
-                                    //TODO: return error if we find unrecognized element
+                                    Err(e) => diagnostics.report(e)?,
                                 }
                             }
                         }
-
-                        Ok(exchange_catalog)
-                    } else {
-                        Err(S100Error::Parse(format!(
-                            "Root node is not '{}', found '{} instead",
-                            EXCHANGE_CATALOG, root_name,
-                        )))
+                        "" => {}
+                        _ => diagnostics.unexpected(&node),
                     }
-                } else {
-                    Err(S100Error::Parse("Root node does not exist".to_string()))
                 }
+
+                Ok(exchange_catalog)
+            } else {
+                S100Error::invalid_child(root)
             }
-            None => Err(S100Error::Parse("path is empty".to_string())),
+        } else {
+            S100Error::missing_root(EXCHANGE_CATALOG)
         }
     }
 
@@ -64,6 +110,14 @@ impl ExchangeCatalog {
     }
 }
 
+impl FromStr for ExchangeCatalog {
+    type Err = S100Error;
+
+    fn from_str(input: &str) -> Result<ExchangeCatalog> {
+        ExchangeCatalog::from_bytes(input.as_bytes())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::io::Write;
@@ -1,3 +1,5 @@
+use crate::namespace::Namespace;
+
 mod dataset_discovery_metadata;
 pub use dataset_discovery_metadata::DatasetDiscoveryMetadata;
 
@@ -9,3 +11,5 @@ pub use exchange_catalog::ExchangeCatalog;
 
 const EXCHANGE_CATALOG: &str = "S100_ExchangeCatalogue";
 const DATA_DISCOVERY_METADATA: &str = "S100_DatasetDiscoveryMetadata";
+
+const XC_NAMESPACES: &[Namespace] = &[Namespace::ExchangeCatalogue];
@@ -0,0 +1,128 @@
+use libxml::tree::Node;
+
+use crate::{error::Location, Result, S100Error};
+
+// The IHO schemas gained an edition suffix with S-100 5.0, e.g. http://www.iho.int/S100FC/5.0.
+const EDITION_PREFIX: &str = "/5.";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Namespace {
+    FeatureCatalogue,
+    Base,
+    Citation,
+    PortrayalCatalogue,
+    ExchangeCatalogue,
+    IsoCitation,
+    IsoMetadata,
+}
+
+impl Namespace {
+    // The first URI is the S-100 4.0 one.
+    fn uris(self) -> &'static [&'static str] {
+        match self {
+            Namespace::FeatureCatalogue => &["http://www.iho.int/S100FC"],
+            Namespace::Base => &["http://www.iho.int/S100Base"],
+            Namespace::Citation => &["http://www.iho.int/S100CI"],
+            Namespace::PortrayalCatalogue => &["http://www.iho.int/S100PC"],
+            Namespace::ExchangeCatalogue => &["http://www.iho.int/s100/xc"],
+            Namespace::IsoCitation => &[
+                "http://standards.iso.org/iso/19115/-3/cit/1.0",
+                "http://standards.iso.org/iso/19115/-3/cit/2.0",
+            ],
+            Namespace::IsoMetadata => &["http://www.isotc211.org/2005/gmd"],
+        }
+    }
+
+    fn is_edition_specific(self) -> bool {
+        !matches!(self, Namespace::IsoCitation | Namespace::IsoMetadata)
+    }
+
+    // S-100 4.0 portrayal catalogues have no target namespace.
+    fn allows_unqualified(self) -> bool {
+        self == Namespace::PortrayalCatalogue
+    }
+
+    fn matches(self, uri: Option<&str>) -> bool {
+        let uri = match uri {
+            Some(val) => val,
+            None => return self.allows_unqualified(),
+        };
+
+        self.uris().iter().any(|val| {
+            uri == *val
+                || (self.is_edition_specific()
+                    && uri.starts_with(val)
+                    && uri[val.len()..].starts_with(EDITION_PREFIX))
+        })
+    }
+}
+
+// Returns the local name of elements in one of `namespaces`, and the `{uri}name` form otherwise
+// so that elements from foreign namespaces never match a local name.
+pub(crate) fn element_name(node: &Node, namespaces: &[Namespace]) -> String {
+    let uri = node.get_namespace().map(|val| val.get_href());
+    if namespaces.iter().any(|val| val.matches(uri.as_deref())) {
+        node.get_name()
+    } else {
+        format!("{{{}}}{}", uri.unwrap_or_default(), node.get_name())
+    }
+}
+
+pub(crate) fn check_namespace(node: &Node, namespace: Namespace) -> Result<()> {
+    let uri = node.get_namespace().map(|val| val.get_href());
+    if namespace.matches(uri.as_deref()) {
+        return Ok(());
+    }
+
+    Err(S100Error::InvalidValue {
+        location: Location::of(node),
+        value: uri.unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use libxml::parser::Parser;
+
+    use super::{check_namespace, element_name, Namespace};
+
+    #[test]
+    fn editions() {
+        let xml = r#"
+            <S100FC:S100_FC_FeatureCatalogue xmlns:S100FC="http://www.iho.int/S100FC/5.2" xmlns:S100Base="http://www.iho.int/S100Base/5.0" xmlns:ext="http://example.com/S100FC">
+                <S100FC:name>Test</S100FC:name>
+                <S100Base:name>Test</S100Base:name>
+                <ext:name>Test</ext:name>
+                <name>Test</name>
+            </S100FC:S100_FC_FeatureCatalogue>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let root = document.get_root_element().unwrap();
+
+        assert!(check_namespace(&root, Namespace::FeatureCatalogue).is_ok());
+        assert!(check_namespace(&root, Namespace::ExchangeCatalogue).is_err());
+
+        let names: Vec<String> = root
+            .get_child_elements()
+            .iter()
+            .map(|val| element_name(val, &[Namespace::FeatureCatalogue]))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "name",
+                "{http://www.iho.int/S100Base/5.0}name",
+                "{http://example.com/S100FC}name",
+                "{}name"
+            ]
+        );
+
+        let names: Vec<String> = root
+            .get_child_elements()
+            .iter()
+            .map(|val| element_name(val, &[Namespace::PortrayalCatalogue]))
+            .collect();
+        assert_eq!(names[3], "name");
+    }
+}
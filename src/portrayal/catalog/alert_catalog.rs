@@ -1,8 +1,11 @@
 use libxml::tree::Node;
 use std::str::FromStr;
 
-use super::{CatalogItem, Description, ExternalFile, FileFormat, FileType, ALERT_CATALOG, XML_ID};
-use crate::{Result, S100Error};
+use super::{
+    CatalogItem, Description, ExternalFile, FileFormat, FileType, ALERT_CATALOG, PC_NAMESPACES,
+    XML_ID,
+};
+use crate::{namespace::element_name, Result, S100Error};
 
 const DESCRIPTION: &str = "description";
 const FILE_NAME: &str = "fileName";
@@ -20,7 +23,7 @@ pub struct AlertCatalog {
 
 impl AlertCatalog {
     pub(super) fn parse(node: Node) -> Result<AlertCatalog> {
-        if node.get_name() != ALERT_CATALOG {
+        if element_name(&node, PC_NAMESPACES) != ALERT_CATALOG {
             return S100Error::invalid_child(node);
         }
 
@@ -31,7 +34,7 @@ impl AlertCatalog {
         let mut file_format: Option<FileFormat> = None;
 
         for child_node in node.get_child_elements() {
-            match child_node.get_name().as_str() {
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
                 DESCRIPTION => match Description::parse(child_node) {
                     Ok(desc) => descriptions.push(desc),
                     Err(e) => return Err(e),
@@ -41,11 +44,11 @@ impl AlertCatalog {
                 }
                 FILE_TYPE => match FileType::from_str(child_node.get_content().as_str()) {
                     Ok(val) => file_type = Some(val),
-                    Err(_) => return S100Error::invalid_value(child_node),
+                    Err(e) => return Err(e.at(&child_node)),
                 },
                 FILE_FORMAT => match FileFormat::from_str(child_node.get_content().as_str()) {
                     Ok(val) => file_format = Some(val),
-                    Err(_) => return S100Error::invalid_value(child_node),
+                    Err(e) => return Err(e.at(&child_node)),
                 },
                 _ => return S100Error::invalid_child(child_node),
             };
@@ -0,0 +1,23 @@
+use std::str::FromStr;
+
+use crate::S100Error;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CapStyle {
+    Butt,
+    Square,
+    Round,
+}
+
+impl FromStr for CapStyle {
+    type Err = S100Error;
+
+    fn from_str(input: &str) -> Result<CapStyle, Self::Err> {
+        match input {
+            "Butt" => Ok(CapStyle::Butt),
+            "Square" => Ok(CapStyle::Square),
+            "Round" => Ok(CapStyle::Round),
+            _ => S100Error::invalid_enum("capStyle", input),
+        }
+    }
+}
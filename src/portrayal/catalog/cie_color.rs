@@ -0,0 +1,70 @@
+use libxml::tree::Node;
+
+use super::PC_NAMESPACES;
+use crate::{namespace::element_name, Result, S100Error};
+
+const CIE: &str = "cie";
+const X: &str = "x";
+const Y: &str = "y";
+const LUMINANCE: &str = "L";
+
+// A colour in CIE xyL coordinates, where L is the luminance in cd/m².
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CieColor {
+    x: f64,
+    y: f64,
+    luminance: f64,
+}
+
+impl CieColor {
+    pub(super) fn parse(node: Node) -> Result<CieColor> {
+        if element_name(&node, PC_NAMESPACES) != CIE {
+            return S100Error::invalid_child(node);
+        }
+
+        let mut x: Option<f64> = None;
+        let mut y: Option<f64> = None;
+        let mut luminance: Option<f64> = None;
+
+        for child_node in node.get_child_elements() {
+            let value = match child_node.get_content().trim().parse() {
+                Ok(val) => val,
+                Err(_) => return S100Error::invalid_value(child_node),
+            };
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
+                X => x = Some(value),
+                Y => y = Some(value),
+                LUMINANCE => luminance = Some(value),
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        if x.is_none() {
+            return S100Error::missing_child(node, X);
+        }
+        if y.is_none() {
+            return S100Error::missing_child(node, Y);
+        }
+        if luminance.is_none() {
+            return S100Error::missing_child(node, LUMINANCE);
+        }
+
+        Ok(CieColor {
+            x: x.unwrap(),
+            y: y.unwrap(),
+            luminance: luminance.unwrap(),
+        })
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn luminance(&self) -> f64 {
+        self.luminance
+    }
+}
@@ -0,0 +1,203 @@
+use libxml::{parser::Parser, tree::Document};
+use std::{fs, io::Read, path::Path, str::FromStr};
+
+use super::{ColorToken, Palette, PC_NAMESPACES};
+use crate::{
+    namespace::element_name,
+    portrayal::{Color, Rgba},
+    Result, S100Error,
+};
+
+const COLOR_PROFILE: &str = "colorProfile";
+const COLORS: &str = "colors";
+const PALETTE: &str = "palette";
+
+// The contents of a colour profile file: the colour tokens and their value in each palette.
+#[derive(Clone, Debug)]
+pub struct ColorPalette {
+    colors: Vec<ColorToken>,
+    palettes: Vec<Palette>,
+}
+
+impl ColorPalette {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<ColorPalette> {
+        let xml = fs::read(&path)?;
+        ColorPalette::from_bytes(&xml).map_err(|e| e.with_path(path.as_ref()))
+    }
+
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<ColorPalette> {
+        let mut xml = Vec::new();
+        reader.read_to_end(&mut xml)?;
+        ColorPalette::from_bytes(&xml)
+    }
+
+    pub fn from_bytes(xml: &[u8]) -> Result<ColorPalette> {
+        let parser = Parser::default();
+        let document = parser.parse_string(xml)?;
+        ColorPalette::from_document(&document)
+    }
+
+    fn from_document(document: &Document) -> Result<ColorPalette> {
+        let root = match document.get_root_element() {
+            Some(val) => val,
+            None => return S100Error::missing_root(COLOR_PROFILE),
+        };
+
+        if element_name(&root, PC_NAMESPACES) != COLOR_PROFILE {
+            return S100Error::invalid_child(root);
+        }
+
+        let mut color_palette = ColorPalette {
+            colors: Vec::new(),
+            palettes: Vec::new(),
+        };
+
+        for node in root.get_child_elements() {
+            match element_name(&node, PC_NAMESPACES).as_str() {
+                COLORS => {
+                    for color_node in node.get_child_elements() {
+                        match ColorToken::parse(color_node) {
+                            Ok(color) => color_palette.colors.push(color),
+                            Err(e) => return Err(e),
+                        }
+                    }
+                }
+                PALETTE => match Palette::parse(node) {
+                    Ok(palette) => color_palette.palettes.push(palette),
+                    Err(e) => return Err(e),
+                },
+                // Later editions add style sheet references which are not needed to resolve colours.
+                _ => {}
+            }
+        }
+
+        Ok(color_palette)
+    }
+
+    pub fn colors(&self) -> &[ColorToken] {
+        &self.colors
+    }
+
+    pub fn color(&self, token: &str) -> Option<&ColorToken> {
+        self.colors.iter().find(|val| val.token() == token)
+    }
+
+    pub fn palettes(&self) -> &[Palette] {
+        &self.palettes
+    }
+
+    pub fn palette(&self, name: &str) -> Option<&Palette> {
+        self.palettes.iter().find(|val| val.name() == name)
+    }
+
+    // Returns None when the palette does not exist or does not define the colour's token.
+    pub fn resolve(&self, palette: &str, color: &Color) -> Option<Rgba> {
+        self.palette(palette)
+            .and_then(|val| val.item(color.token()))
+            .map(|val| val.srgb().to_rgba(color.transparency()))
+    }
+}
+
+impl FromStr for ColorPalette {
+    type Err = S100Error;
+
+    fn from_str(s: &str) -> Result<ColorPalette> {
+        ColorPalette::from_bytes(s.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::ColorPalette;
+    use crate::{
+        portrayal::{Color, Rgba},
+        S100Error,
+    };
+
+    const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<colorProfile>
+    <colors>
+        <color token="CHBLK">
+            <name>Black</name>
+            <description>Chart black</description>
+        </color>
+        <color token="DEPDW">
+            <name>Deep water</name>
+        </color>
+    </colors>
+    <palette name="Day">
+        <item token="CHBLK">
+            <cie>
+                <x>0.28</x>
+                <y>0.31</y>
+                <L>0.00</L>
+            </cie>
+            <srgb>
+                <red>7</red>
+                <green>7</green>
+                <blue>7</blue>
+            </srgb>
+        </item>
+        <item token="DEPDW">
+            <cie>
+                <x>0.28</x>
+                <y>0.31</y>
+                <L>80.00</L>
+            </cie>
+            <srgb>
+                <red>255</red>
+                <green>255</green>
+                <blue>255</blue>
+            </srgb>
+        </item>
+    </palette>
+    <palette name="Night">
+        <item token="DEPDW">
+            <cie>
+                <x>0.28</x>
+                <y>0.31</y>
+                <L>0.00</L>
+            </cie>
+            <srgb>
+                <red>0</red>
+                <green>0</green>
+                <blue>0</blue>
+            </srgb>
+        </item>
+    </palette>
+</colorProfile>"#;
+
+    #[test]
+    fn deserialize() {
+        let target = ColorPalette::from_str(XML).unwrap();
+        assert_eq!(target.colors().len(), 2);
+        assert_eq!(target.color("CHBLK").unwrap().name(), "Black");
+        assert_eq!(target.color("DEPDW").unwrap().description(), None);
+        assert_eq!(target.palettes().len(), 2);
+
+        let item = target.palette("Day").unwrap().item("DEPDW").unwrap();
+        assert_eq!(item.cie().luminance(), 80.0);
+        assert_eq!(item.srgb().red(), 255);
+
+        let depdw = Color::new("DEPDW", 0.0);
+        assert_eq!(
+            target.resolve("Day", &depdw),
+            Some(Rgba::new(255, 255, 255, 255))
+        );
+        assert_eq!(
+            target.resolve("Night", &Color::new("DEPDW", 0.5)),
+            Some(Rgba::new(0, 0, 0, 128))
+        );
+        assert_eq!(target.resolve("Night", &Color::new("CHBLK", 0.0)), None);
+        assert_eq!(target.resolve("Dusk", &depdw), None);
+        assert_eq!(target.resolve("Day", &depdw).unwrap().to_hex(), "#ffffffff");
+
+        let xml = XML.replace("<red>7</red>", "<red>700</red>");
+        match ColorPalette::from_str(&xml) {
+            Err(S100Error::InvalidValue { value, .. }) => assert_eq!(value, "700"),
+            _ => panic!("an out of range colour component was accepted"),
+        }
+    }
+}
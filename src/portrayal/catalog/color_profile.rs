@@ -1,8 +1,11 @@
 use libxml::tree::Node;
-use std::str::FromStr;
+use std::{path::Path, str::FromStr};
 
-use super::{CatalogItem, Description, ExternalFile, FileFormat, FileType, XML_ID};
-use crate::{Result, S100Error};
+use super::{
+    CatalogItem, ColorPalette, Description, ExternalFile, FileFormat, FileType, PC_NAMESPACES,
+    XML_ID,
+};
+use crate::{namespace::element_name, Result, S100Error};
 
 const COLOR_PROFILE: &str = "colorProfile";
 const DESCRIPTION: &str = "description";
@@ -10,6 +13,8 @@ const FILE_NAME: &str = "fileName";
 const FILE_TYPE: &str = "fileType";
 const FILE_FORMAT: &str = "fileFormat";
 
+const COLOR_PROFILES_DIRECTORY: &str = "ColorProfiles";
+
 #[derive(Clone, Debug)]
 pub struct ColorProfile {
     id: String,
@@ -21,7 +26,7 @@ pub struct ColorProfile {
 
 impl ColorProfile {
     pub(super) fn parse(node: Node) -> Result<ColorProfile> {
-        if node.get_name() != COLOR_PROFILE {
+        if element_name(&node, PC_NAMESPACES) != COLOR_PROFILE {
             return S100Error::invalid_child(node);
         }
 
@@ -32,7 +37,7 @@ impl ColorProfile {
         let mut file_format: Option<FileFormat> = None;
 
         for child_node in node.get_child_elements() {
-            match child_node.get_name().as_str() {
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
                 DESCRIPTION => match Description::parse(child_node) {
                     Ok(desc) => descriptions.push(desc),
                     Err(e) => return Err(e),
@@ -42,11 +47,11 @@ impl ColorProfile {
                 }
                 FILE_TYPE => match FileType::from_str(child_node.get_content().as_str()) {
                     Ok(val) => file_type = Some(val),
-                    Err(_) => return S100Error::invalid_value(child_node),
+                    Err(e) => return Err(e.at(&child_node)),
                 },
                 FILE_FORMAT => match FileFormat::from_str(child_node.get_content().as_str()) {
                     Ok(val) => file_format = Some(val),
-                    Err(_) => return S100Error::invalid_value(child_node),
+                    Err(e) => return Err(e.at(&child_node)),
                 },
                 _ => return S100Error::invalid_child(child_node),
             };
@@ -73,6 +78,16 @@ impl ColorProfile {
             file_format: file_format.unwrap(),
         })
     }
+
+    // Colour profile files live in the ColorProfiles folder next to the portrayal catalogue file.
+    pub fn load_palette<P: AsRef<Path>>(&self, catalog_directory: P) -> Result<ColorPalette> {
+        ColorPalette::open(
+            catalog_directory
+                .as_ref()
+                .join(COLOR_PROFILES_DIRECTORY)
+                .join(&self.file_name),
+        )
+    }
 }
 
 impl CatalogItem for ColorProfile {
@@ -102,6 +117,9 @@ impl ExternalFile for ColorProfile {
 #[cfg(test)]
 mod tests {
     use libxml::parser::Parser;
+    use std::fs;
+
+    use tempfile::tempdir;
 
     use super::{CatalogItem, ColorProfile, FileFormat, FileType};
 
@@ -140,4 +158,46 @@ mod tests {
         assert_eq!(target.file_type, FileType::ColorProfile);
         assert_eq!(target.file_format, FileFormat::Xml);
     }
+
+    #[test]
+    fn load_palette() {
+        let xml = r#"
+            <colorProfile id="1">
+                <fileName>colorProfile.xml</fileName>
+                <fileType>ColorProfile</fileType>
+                <fileFormat>XML</fileFormat>
+            </colorProfile>"#;
+
+        let parser = Parser::default();
+        let document = parser.parse_string(xml).unwrap();
+        let node = document.get_root_element().unwrap();
+        let target = ColorProfile::parse(node).unwrap();
+
+        let directory = tempdir().expect("Unable to create temp dir");
+        assert!(target.load_palette(directory.path()).is_err());
+
+        fs::create_dir(directory.path().join("ColorProfiles")).expect("Unable to create dir");
+        fs::write(
+            directory
+                .path()
+                .join("ColorProfiles")
+                .join("colorProfile.xml"),
+            r#"<colorProfile>
+                <colors>
+                    <color token="CHBLK"><name>Black</name></color>
+                </colors>
+                <palette name="Day">
+                    <item token="CHBLK">
+                        <cie><x>0.28</x><y>0.31</y><L>0.00</L></cie>
+                        <srgb><red>7</red><green>7</green><blue>7</blue></srgb>
+                    </item>
+                </palette>
+            </colorProfile>"#,
+        )
+        .expect("Unable to write colour profile");
+
+        let palette = target.load_palette(directory.path()).unwrap();
+        assert_eq!(palette.colors().len(), 1);
+        assert!(palette.palette("Day").unwrap().item("CHBLK").is_some());
+    }
 }
@@ -0,0 +1,61 @@
+use libxml::tree::Node;
+
+use super::PC_NAMESPACES;
+use crate::{namespace::element_name, Result, S100Error};
+
+const COLOR: &str = "color";
+const TOKEN: &str = "token";
+const NAME: &str = "name";
+const DESCRIPTION: &str = "description";
+
+#[derive(Clone, Debug)]
+pub struct ColorToken {
+    token: String,
+    name: String,
+    description: Option<String>,
+}
+
+impl ColorToken {
+    pub(super) fn parse(node: Node) -> Result<ColorToken> {
+        if element_name(&node, PC_NAMESPACES) != COLOR {
+            return S100Error::invalid_child(node);
+        }
+
+        let token: Option<String> = node.get_attribute(TOKEN);
+        let mut name: Option<String> = None;
+        let mut description: Option<String> = None;
+
+        for child_node in node.get_child_elements() {
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
+                NAME => name = Some(child_node.get_content()),
+                DESCRIPTION => description = Some(child_node.get_content()),
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        if token.is_none() {
+            return S100Error::missing_attribute(node, TOKEN);
+        }
+        if name.is_none() {
+            return S100Error::missing_child(node, NAME);
+        }
+
+        Ok(ColorToken {
+            token: token.unwrap(),
+            name: name.unwrap(),
+            description,
+        })
+    }
+
+    pub fn token(&self) -> &str {
+        self.token.as_str()
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+}
@@ -0,0 +1,35 @@
+use libxml::tree::Node;
+
+use super::{LineStyleDefinition, PC_NAMESPACES};
+use crate::{namespace::element_name, Result, S100Error};
+
+const COMPOSITE_LINE_STYLE: &str = "compositeLineStyle";
+
+// Line styles drawn on top of each other, in document order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompositeLineStyle {
+    components: Vec<LineStyleDefinition>,
+}
+
+impl CompositeLineStyle {
+    pub(super) fn parse(node: Node) -> Result<CompositeLineStyle> {
+        if element_name(&node, PC_NAMESPACES) != COMPOSITE_LINE_STYLE {
+            return S100Error::invalid_child(node);
+        }
+
+        let mut components: Vec<LineStyleDefinition> = Vec::new();
+
+        for child_node in node.get_child_elements() {
+            match LineStyleDefinition::parse(child_node) {
+                Ok(val) => components.push(val),
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(CompositeLineStyle { components })
+    }
+
+    pub fn components(&self) -> &[LineStyleDefinition] {
+        &self.components
+    }
+}
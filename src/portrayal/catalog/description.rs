@@ -1,6 +1,7 @@
 use libxml::tree::Node;
 
-use crate::{Result, S100Error};
+use super::PC_NAMESPACES;
+use crate::{namespace::element_name, Result, S100Error};
 
 const DESCRIPTION: &str = "description";
 const NAME: &str = "name";
@@ -15,7 +16,7 @@ pub struct Description {
 
 impl Description {
     pub(super) fn parse(node: Node) -> Result<Description> {
-        if node.get_name() != DESCRIPTION {
+        if element_name(&node, PC_NAMESPACES) != DESCRIPTION {
             return S100Error::invalid_child(node);
         }
 
@@ -24,7 +25,7 @@ impl Description {
         let mut language: Option<String> = None;
 
         for child_node in node.get_child_elements() {
-            match child_node.get_name().as_str() {
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
                 NAME => name = Some(child_node.get_content()),
                 DESCRIPTION => description = Some(child_node.get_content()),
                 LANGUAGE => language = Some(child_node.get_content()),
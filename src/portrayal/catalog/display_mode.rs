@@ -1,7 +1,7 @@
 use libxml::tree::Node;
 
-use super::{CatalogItem, Description, DESCRIPTION, XML_ID};
-use crate::{Result, S100Error};
+use super::{CatalogItem, Description, DESCRIPTION, PC_NAMESPACES, XML_ID};
+use crate::{namespace::element_name, Result, S100Error};
 
 const DISPLAY_MODE: &str = "displayMode";
 const VIEWING_GROUP_LAYER: &str = "viewingGroupLayer";
@@ -15,7 +15,7 @@ pub struct DisplayMode {
 
 impl DisplayMode {
     pub(super) fn parse(node: Node) -> Result<DisplayMode> {
-        if node.get_name() != DISPLAY_MODE {
+        if element_name(&node, PC_NAMESPACES) != DISPLAY_MODE {
             return S100Error::invalid_child(node);
         }
 
@@ -24,7 +24,7 @@ impl DisplayMode {
         let mut viewing_group_layers: Vec<String> = Vec::new();
 
         for child_node in node.get_child_elements() {
-            match child_node.get_name().as_str() {
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
                 DESCRIPTION => match Description::parse(child_node) {
                     Ok(desc) => descriptions.push(desc),
                     Err(e) => return Err(e),
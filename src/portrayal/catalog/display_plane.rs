@@ -1,7 +1,7 @@
 use libxml::tree::Node;
 
-use super::{CatalogItem, Description, DESCRIPTION, XML_ID};
-use crate::{Result, S100Error};
+use super::{CatalogItem, Description, DESCRIPTION, PC_NAMESPACES, XML_ID};
+use crate::{namespace::element_name, Result, S100Error};
 
 const DISPLAY_PLANE: &str = "displayPlane";
 
@@ -13,7 +13,7 @@ pub struct DisplayPlane {
 
 impl DisplayPlane {
     pub(super) fn parse(node: Node) -> Result<DisplayPlane> {
-        if node.get_name() != DISPLAY_PLANE {
+        if element_name(&node, PC_NAMESPACES) != DISPLAY_PLANE {
             return S100Error::invalid_child(node);
         }
 
@@ -21,7 +21,7 @@ impl DisplayPlane {
         let mut descriptions: Vec<Description> = Vec::new();
 
         for child_node in node.get_child_elements() {
-            match child_node.get_name().as_str() {
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
                 DESCRIPTION => match Description::parse(child_node) {
                     Ok(desc) => descriptions.push(desc),
                     Err(e) => return Err(e),
@@ -1,8 +1,8 @@
 use libxml::tree::Node;
 
-use crate::{Result, S100Error};
+use crate::{namespace::element_name, Result, S100Error};
 
-use super::ERROR_MESSAGE;
+use super::{ERROR_MESSAGE, PC_NAMESPACES};
 
 const TEXT: &str = "text";
 const LANGUAGE: &str = "language";
@@ -33,14 +33,14 @@ impl Text {
 
 impl ErrorMessage {
     pub(super) fn parse(node: Node) -> Result<ErrorMessage> {
-        if node.get_name() != ERROR_MESSAGE {
+        if element_name(&node, PC_NAMESPACES) != ERROR_MESSAGE {
             return S100Error::invalid_child(node);
         }
 
         let mut texts: Vec<Text> = Vec::new();
 
         for child_node in node.get_child_elements() {
-            match child_node.get_name().as_str() {
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
                 TEXT => {
                     let text = child_node.get_content();
                     let language = child_node.get_attribute(LANGUAGE);
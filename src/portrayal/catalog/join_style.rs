@@ -0,0 +1,23 @@
+use std::str::FromStr;
+
+use crate::S100Error;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum JoinStyle {
+    Bevel,
+    Miter,
+    Round,
+}
+
+impl FromStr for JoinStyle {
+    type Err = S100Error;
+
+    fn from_str(input: &str) -> Result<JoinStyle, Self::Err> {
+        match input {
+            "Bevel" => Ok(JoinStyle::Bevel),
+            "Miter" => Ok(JoinStyle::Miter),
+            "Round" => Ok(JoinStyle::Round),
+            _ => S100Error::invalid_enum("joinStyle", input),
+        }
+    }
+}
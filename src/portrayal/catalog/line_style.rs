@@ -1,8 +1,11 @@
 use libxml::tree::Node;
-use std::str::FromStr;
+use std::{path::Path, str::FromStr};
 
-use super::{CatalogItem, Description, ExternalFile, FileFormat, FileType, XML_ID};
-use crate::{Result, S100Error};
+use super::{
+    CatalogItem, Description, ExternalFile, FileFormat, FileType, LineStyleDefinition,
+    PC_NAMESPACES, XML_ID,
+};
+use crate::{namespace::element_name, Result, S100Error};
 
 const SYMBOL: &str = "lineStyle";
 const DESCRIPTION: &str = "description";
@@ -10,6 +13,8 @@ const FILE_NAME: &str = "fileName";
 const FILE_TYPE: &str = "fileType";
 const FILE_FORMAT: &str = "fileFormat";
 
+const LINE_STYLES_DIRECTORY: &str = "LineStyles";
+
 #[derive(Clone, Debug)]
 pub struct LineStyle {
     id: String,
@@ -17,11 +22,12 @@ pub struct LineStyle {
     file_name: String,
     file_type: FileType,
     file_format: FileFormat,
+    definition: Option<LineStyleDefinition>,
 }
 
 impl LineStyle {
     pub(super) fn parse(node: Node) -> Result<LineStyle> {
-        if node.get_name() != SYMBOL {
+        if element_name(&node, PC_NAMESPACES) != SYMBOL {
             return S100Error::invalid_child(node);
         }
 
@@ -32,7 +38,7 @@ impl LineStyle {
         let mut file_format: Option<FileFormat> = None;
 
         for child_node in node.get_child_elements() {
-            match child_node.get_name().as_str() {
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
                 DESCRIPTION => match Description::parse(child_node) {
                     Ok(desc) => descriptions.push(desc),
                     Err(e) => return Err(e),
@@ -42,11 +48,11 @@ impl LineStyle {
                 }
                 FILE_TYPE => match FileType::from_str(child_node.get_content().as_str()) {
                     Ok(val) => file_type = Some(val),
-                    Err(_) => return S100Error::invalid_value(child_node),
+                    Err(e) => return Err(e.at(&child_node)),
                 },
                 FILE_FORMAT => match FileFormat::from_str(child_node.get_content().as_str()) {
                     Ok(val) => file_format = Some(val),
-                    Err(_) => return S100Error::invalid_value(child_node),
+                    Err(e) => return Err(e.at(&child_node)),
                 },
                 _ => return S100Error::invalid_child(child_node),
             };
@@ -71,8 +77,31 @@ impl LineStyle {
             file_name: file_name.unwrap(),
             file_type: file_type.unwrap(),
             file_format: file_format.unwrap(),
+            definition: None,
         })
     }
+
+    // Line style files live in the LineStyles folder next to the portrayal catalogue file.
+    pub fn load_definition<P: AsRef<Path>>(
+        &self,
+        catalog_directory: P,
+    ) -> Result<LineStyleDefinition> {
+        LineStyleDefinition::open(
+            catalog_directory
+                .as_ref()
+                .join(LINE_STYLES_DIRECTORY)
+                .join(&self.file_name),
+        )
+    }
+
+    // Only available once PortrayalCatalog::load_line_styles has been called.
+    pub fn definition(&self) -> Option<&LineStyleDefinition> {
+        self.definition.as_ref()
+    }
+
+    pub(super) fn set_definition(&mut self, definition: LineStyleDefinition) {
+        self.definition = Some(definition);
+    }
 }
 
 impl CatalogItem for LineStyle {
@@ -102,8 +131,12 @@ impl ExternalFile for LineStyle {
 #[cfg(test)]
 mod tests {
     use libxml::parser::Parser;
+    use std::{fs, str::FromStr};
+
+    use tempfile::tempdir;
 
     use super::{CatalogItem, FileFormat, FileType, LineStyle};
+    use crate::portrayal::catalog::{LineStyleDefinition, PortrayalCatalog};
 
     #[test]
     fn deserialize() {
@@ -140,4 +173,75 @@ mod tests {
         assert_eq!(target.file_type, FileType::LineStyle);
         assert_eq!(target.file_format, FileFormat::Xml);
     }
+
+    #[test]
+    fn load_line_styles() {
+        let xml = r#"
+            <portrayalCatalog>
+                <lineStyles>
+                    <lineStyle id="ACHARE51">
+                        <fileName>ACHARE51.xml</fileName>
+                        <fileType>LineStyle</fileType>
+                        <fileFormat>XML</fileFormat>
+                    </lineStyle>
+                    <lineStyle id="CBLARE51">
+                        <fileName>CBLARE51.xml</fileName>
+                        <fileType>LineStyle</fileType>
+                        <fileFormat>XML</fileFormat>
+                    </lineStyle>
+                </lineStyles>
+            </portrayalCatalog>"#;
+
+        let mut catalog = PortrayalCatalog::from_str(xml).unwrap();
+        assert!(catalog
+            .line_style("ACHARE51")
+            .unwrap()
+            .definition()
+            .is_none());
+
+        let directory = tempdir().expect("Unable to create temp dir");
+        assert!(catalog.load_line_styles(directory.path()).is_err());
+
+        fs::create_dir(directory.path().join("LineStyles")).expect("Unable to create dir");
+        fs::write(
+            directory.path().join("LineStyles").join("ACHARE51.xml"),
+            r#"<lineStyle>
+                <intervalLength>12.6</intervalLength>
+                <pen width="0.32"><color>CHMGF</color></pen>
+                <dash><start>0</start><length>3.6</length></dash>
+                <symbol reference="ACHARE02"><position>9</position></symbol>
+            </lineStyle>"#,
+        )
+        .expect("Unable to write line style");
+
+        // CBLARE51.xml is still missing, so nothing is attached.
+        assert!(catalog.load_line_styles(directory.path()).is_err());
+        assert!(catalog
+            .line_style("ACHARE51")
+            .unwrap()
+            .definition()
+            .is_none());
+
+        fs::write(
+            directory.path().join("LineStyles").join("CBLARE51.xml"),
+            r#"<lineStyle>
+                <intervalLength>9</intervalLength>
+                <pen width="0.32"><color>CHMGD</color></pen>
+            </lineStyle>"#,
+        )
+        .expect("Unable to write line style");
+
+        catalog.load_line_styles(directory.path()).unwrap();
+        match catalog.line_style("ACHARE51").unwrap().definition() {
+            Some(LineStyleDefinition::Simple(val)) => {
+                assert_eq!(val.symbols()[0].reference(), "ACHARE02")
+            }
+            val => panic!("unexpected line style: {:?}", val),
+        }
+        assert!(catalog
+            .line_style("CBLARE51")
+            .unwrap()
+            .definition()
+            .is_some());
+    }
 }
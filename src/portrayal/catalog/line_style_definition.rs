@@ -0,0 +1,136 @@
+use libxml::{parser::Parser, tree::Node};
+use std::{fs, io::Read, path::Path, str::FromStr};
+
+use super::{CompositeLineStyle, LineStyleReference, SimpleLineStyle, PC_NAMESPACES};
+use crate::{namespace::element_name, Result, S100Error};
+
+const LINE_STYLE: &str = "lineStyle";
+const COMPOSITE_LINE_STYLE: &str = "compositeLineStyle";
+const LINE_STYLE_REFERENCE: &str = "lineStyleReference";
+
+// The contents of a line style file as defined in S-100 Part 9.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LineStyleDefinition {
+    Simple(SimpleLineStyle),
+    Composite(CompositeLineStyle),
+    Reference(LineStyleReference),
+}
+
+impl LineStyleDefinition {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<LineStyleDefinition> {
+        let xml = fs::read(&path)?;
+        LineStyleDefinition::from_bytes(&xml).map_err(|e| e.with_path(path.as_ref()))
+    }
+
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<LineStyleDefinition> {
+        let mut xml = Vec::new();
+        reader.read_to_end(&mut xml)?;
+        LineStyleDefinition::from_bytes(&xml)
+    }
+
+    pub fn from_bytes(xml: &[u8]) -> Result<LineStyleDefinition> {
+        let parser = Parser::default();
+        let document = parser.parse_string(xml)?;
+        match document.get_root_element() {
+            Some(root) => LineStyleDefinition::parse(root),
+            None => S100Error::missing_root(LINE_STYLE),
+        }
+    }
+
+    pub(super) fn parse(node: Node) -> Result<LineStyleDefinition> {
+        match element_name(&node, PC_NAMESPACES).as_str() {
+            LINE_STYLE => match SimpleLineStyle::parse(node) {
+                Ok(val) => Ok(LineStyleDefinition::Simple(val)),
+                Err(e) => Err(e),
+            },
+            COMPOSITE_LINE_STYLE => match CompositeLineStyle::parse(node) {
+                Ok(val) => Ok(LineStyleDefinition::Composite(val)),
+                Err(e) => Err(e),
+            },
+            LINE_STYLE_REFERENCE => match LineStyleReference::parse(node) {
+                Ok(val) => Ok(LineStyleDefinition::Reference(val)),
+                Err(e) => Err(e),
+            },
+            _ => S100Error::invalid_child(node),
+        }
+    }
+}
+
+impl FromStr for LineStyleDefinition {
+    type Err = S100Error;
+
+    fn from_str(s: &str) -> Result<LineStyleDefinition> {
+        LineStyleDefinition::from_bytes(s.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::LineStyleDefinition;
+    use crate::{
+        portrayal::{catalog::CapStyle, CrsType},
+        S100Error,
+    };
+
+    #[test]
+    fn deserialize() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<lineStyle capStyle="Butt" joinStyle="Round">
+    <intervalLength>12.6</intervalLength>
+    <pen width="0.32">
+        <color transparency="0.5">CHMGF</color>
+    </pen>
+    <dash>
+        <start>0</start>
+        <length>3.6</length>
+    </dash>
+    <symbol reference="ACHARE02">
+        <position>9</position>
+    </symbol>
+</lineStyle>"#;
+
+        let line_style = match LineStyleDefinition::from_str(xml).unwrap() {
+            LineStyleDefinition::Simple(val) => val,
+            val => panic!("unexpected line style: {:?}", val),
+        };
+        assert_eq!(line_style.cap_style(), Some(CapStyle::Butt));
+        assert_eq!(line_style.offset(), None);
+        assert_eq!(line_style.interval_length(), Some(12.6));
+        assert_eq!(line_style.pen().width(), 0.32);
+        assert_eq!(line_style.pen().color().token(), "CHMGF");
+        assert_eq!(line_style.pen().color().transparency(), 0.5);
+        assert_eq!(line_style.dashes().len(), 1);
+        assert_eq!(line_style.dashes()[0].length(), 3.6);
+
+        let symbol = &line_style.symbols()[0];
+        assert_eq!(symbol.reference(), "ACHARE02");
+        assert_eq!(symbol.position(), 9.0);
+        assert_eq!(symbol.scale_factor(), 1.0);
+        assert_eq!(symbol.crs_type(), CrsType::Line);
+
+        let xml = r#"
+            <compositeLineStyle>
+                <lineStyleReference reference="CTNARE51"/>
+                <lineStyle>
+                    <pen width="0.64"><color>CHGRD</color></pen>
+                </lineStyle>
+            </compositeLineStyle>"#;
+
+        let composite = match LineStyleDefinition::from_str(xml).unwrap() {
+            LineStyleDefinition::Composite(val) => val,
+            val => panic!("unexpected line style: {:?}", val),
+        };
+        assert_eq!(composite.components().len(), 2);
+        match &composite.components()[0] {
+            LineStyleDefinition::Reference(val) => assert_eq!(val.reference(), "CTNARE51"),
+            val => panic!("unexpected line style: {:?}", val),
+        }
+
+        match LineStyleDefinition::from_str(r#"<lineStyle><pen width="wide"/></lineStyle>"#) {
+            Err(S100Error::InvalidValue { value, .. }) => assert_eq!(value, "wide"),
+            _ => panic!("an invalid pen width was accepted"),
+        }
+    }
+}
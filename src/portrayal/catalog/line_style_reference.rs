@@ -0,0 +1,30 @@
+use libxml::tree::Node;
+
+use super::PC_NAMESPACES;
+use crate::{namespace::element_name, Result, S100Error};
+
+const LINE_STYLE_REFERENCE: &str = "lineStyleReference";
+const REFERENCE: &str = "reference";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineStyleReference {
+    reference: String,
+}
+
+impl LineStyleReference {
+    pub(super) fn parse(node: Node) -> Result<LineStyleReference> {
+        if element_name(&node, PC_NAMESPACES) != LINE_STYLE_REFERENCE {
+            return S100Error::invalid_child(node);
+        }
+
+        match node.get_attribute(REFERENCE) {
+            Some(reference) => Ok(LineStyleReference { reference }),
+            None => S100Error::missing_attribute(node, REFERENCE),
+        }
+    }
+
+    // The id of a line style in the portrayal catalogue.
+    pub fn reference(&self) -> &str {
+        self.reference.as_str()
+    }
+}
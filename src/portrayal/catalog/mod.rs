@@ -1,15 +1,32 @@
+use crate::namespace::Namespace;
+
 mod alert_catalog;
 pub use alert_catalog::AlertCatalog;
 
 mod area_fill;
 pub use area_fill::AreaFill;
 
+mod cap_style;
+pub use cap_style::CapStyle;
+
 mod catalog_item;
 pub use catalog_item::CatalogItem;
 
+mod cie_color;
+pub use cie_color::CieColor;
+
+mod color_palette;
+pub use color_palette::ColorPalette;
+
 mod color_profile;
 pub use color_profile::ColorProfile;
 
+mod color_token;
+pub use color_token::ColorToken;
+
+mod composite_line_style;
+pub use composite_line_style::CompositeLineStyle;
+
 mod description;
 pub use description::Description;
 
@@ -34,9 +51,21 @@ pub use file_type::FileType;
 mod font;
 pub use font::Font;
 
+mod join_style;
+pub use join_style::JoinStyle;
+
 mod line_style;
 pub use line_style::LineStyle;
 
+mod line_style_definition;
+pub use line_style_definition::LineStyleDefinition;
+
+mod line_style_reference;
+pub use line_style_reference::LineStyleReference;
+
+mod palette;
+pub use palette::{Palette, PaletteItem};
+
 mod parameter;
 pub use parameter::Parameter;
 
@@ -55,6 +84,12 @@ pub use rule_file::RuleFile;
 mod rule_type;
 pub use rule_type::RuleType;
 
+mod simple_line_style;
+pub use simple_line_style::{Dash, LineSymbol, Pen, SimpleLineStyle};
+
+mod srgb_color;
+pub use srgb_color::SrgbColor;
+
 mod style_sheet;
 pub use style_sheet::StyleSheet;
 
@@ -79,3 +114,5 @@ const VALIDATE: &str = "validate";
 const VIEWING_GROUP: &str = "viewingGroup";
 
 const XML_ID: &str = "id";
+
+const PC_NAMESPACES: &[Namespace] = &[Namespace::PortrayalCatalogue];
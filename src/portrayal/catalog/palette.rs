@@ -0,0 +1,118 @@
+use libxml::tree::Node;
+
+use super::{CieColor, SrgbColor, PC_NAMESPACES};
+use crate::{namespace::element_name, Result, S100Error};
+
+const PALETTE: &str = "palette";
+const ITEM: &str = "item";
+const NAME: &str = "name";
+const TOKEN: &str = "token";
+const CIE: &str = "cie";
+const SRGB: &str = "srgb";
+
+#[derive(Clone, Debug)]
+pub struct Palette {
+    name: String,
+    items: Vec<PaletteItem>,
+}
+
+impl Palette {
+    pub(super) fn parse(node: Node) -> Result<Palette> {
+        if element_name(&node, PC_NAMESPACES) != PALETTE {
+            return S100Error::invalid_child(node);
+        }
+
+        let name: Option<String> = node.get_attribute(NAME);
+        let mut items: Vec<PaletteItem> = Vec::new();
+
+        for child_node in node.get_child_elements() {
+            match PaletteItem::parse(child_node) {
+                Ok(item) => items.push(item),
+                Err(e) => return Err(e),
+            }
+        }
+
+        if name.is_none() {
+            return S100Error::missing_attribute(node, NAME);
+        }
+
+        Ok(Palette {
+            name: name.unwrap(),
+            items,
+        })
+    }
+
+    // The palette name, e.g. Day, Dusk or Night.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    pub fn items(&self) -> &[PaletteItem] {
+        &self.items
+    }
+
+    pub fn item(&self, token: &str) -> Option<&PaletteItem> {
+        self.items.iter().find(|val| val.token == token)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PaletteItem {
+    token: String,
+    cie: CieColor,
+    srgb: SrgbColor,
+}
+
+impl PaletteItem {
+    fn parse(node: Node) -> Result<PaletteItem> {
+        if element_name(&node, PC_NAMESPACES) != ITEM {
+            return S100Error::invalid_child(node);
+        }
+
+        let token: Option<String> = node.get_attribute(TOKEN);
+        let mut cie: Option<CieColor> = None;
+        let mut srgb: Option<SrgbColor> = None;
+
+        for child_node in node.get_child_elements() {
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
+                CIE => match CieColor::parse(child_node) {
+                    Ok(val) => cie = Some(val),
+                    Err(e) => return Err(e),
+                },
+                SRGB => match SrgbColor::parse(child_node) {
+                    Ok(val) => srgb = Some(val),
+                    Err(e) => return Err(e),
+                },
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        if token.is_none() {
+            return S100Error::missing_attribute(node, TOKEN);
+        }
+        if cie.is_none() {
+            return S100Error::missing_child(node, CIE);
+        }
+        if srgb.is_none() {
+            return S100Error::missing_child(node, SRGB);
+        }
+
+        Ok(PaletteItem {
+            token: token.unwrap(),
+            cie: cie.unwrap(),
+            srgb: srgb.unwrap(),
+        })
+    }
+
+    pub fn token(&self) -> &str {
+        self.token.as_str()
+    }
+
+    pub fn cie(&self) -> &CieColor {
+        &self.cie
+    }
+
+    pub fn srgb(&self) -> &SrgbColor {
+        &self.srgb
+    }
+}
@@ -3,9 +3,10 @@ use std::str::FromStr;
 use libxml::tree::Node;
 
 use super::{
-    CatalogItem, Description, ParameterType, Validate, DESCRIPTION, PARAMETER, VALIDATE, XML_ID,
+    CatalogItem, Description, ParameterType, Validate, DESCRIPTION, PARAMETER, PC_NAMESPACES,
+    VALIDATE, XML_ID,
 };
-use crate::{Result, S100Error};
+use crate::{namespace::element_name, Result, S100Error};
 
 const TYPE: &str = "type";
 const DEFAULT: &str = "default";
@@ -21,7 +22,7 @@ pub struct Parameter {
 
 impl Parameter {
     pub(super) fn parse(node: Node) -> Result<Parameter> {
-        if node.get_name() != PARAMETER {
+        if element_name(&node, PC_NAMESPACES) != PARAMETER {
             return S100Error::invalid_child(node);
         }
 
@@ -32,7 +33,7 @@ impl Parameter {
         let mut validate: Option<Validate> = None;
 
         for child_node in node.get_child_elements() {
-            match child_node.get_name().as_str() {
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
                 DESCRIPTION => match Description::parse(child_node) {
                     Ok(desc) => descriptions.push(desc),
                     Err(e) => return Err(e),
@@ -42,7 +43,7 @@ impl Parameter {
                 }
                 TYPE => match ParameterType::from_str(child_node.get_content().as_str()) {
                     Ok(val) => parameter_type = Some(val),
-                    Err(_) => return S100Error::invalid_value(child_node),
+                    Err(e) => return Err(e.at(&child_node)),
                 },
                 VALIDATE => match Validate::parse(child_node) {
                     Ok(val) => validate = Some(val),
@@ -1,12 +1,16 @@
-use libxml::parser::Parser;
-use std::path::Path;
+use libxml::{parser::Parser, tree::Document};
+use std::{fs, io::Read, path::Path, str::FromStr};
 
 use super::{
-    AlertCatalog, AreaFill, ColorProfile, DisplayMode, DisplayPlane, Font, LineStyle, Parameter,
-    Pixmap, RuleFile, StyleSheet, Symbol, ViewingGroup, ViewingGroupLayer, ALERT_CATALOG,
-    PORTRAYAL_CATALOG,
+    AlertCatalog, AreaFill, CatalogItem, ColorProfile, DisplayMode, DisplayPlane, Font, LineStyle,
+    LineStyleDefinition, Parameter, Pixmap, RuleFile, StyleSheet, Symbol, ViewingGroup,
+    ViewingGroupLayer, ALERT_CATALOG, PC_NAMESPACES, PORTRAYAL_CATALOG,
+};
+use crate::{
+    diagnostics::Diagnostics,
+    namespace::{check_namespace, element_name, Namespace},
+    ParseMode, Result, S100Error, Schema,
 };
-use crate::{Result, S100Error};
 
 // Shcema defined here: https://schemas.s100dev.net/schemas/S100/5.0.0/S100PC/20220705/S100PortrayalCatalog.xsd
 #[derive(Debug)]
@@ -45,200 +49,239 @@ const VIEWING_GROUP_LAYERS: &str = "viewingGroupLayers";
 
 impl PortrayalCatalog {
     pub fn open<P: AsRef<Path>>(path: P) -> Result<PortrayalCatalog> {
+        let xml = fs::read(&path)?;
+        PortrayalCatalog::from_bytes(&xml).map_err(|e| e.with_path(path.as_ref()))
+    }
+
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<PortrayalCatalog> {
+        let mut xml = Vec::new();
+        reader.read_to_end(&mut xml)?;
+        PortrayalCatalog::from_bytes(&xml)
+    }
+
+    pub fn from_bytes(xml: &[u8]) -> Result<PortrayalCatalog> {
         let parser = Parser::default();
-        let filename_result = path.as_ref().to_str();
-        match filename_result {
-            Some(filename) => {
-                let document = parser.parse_file(filename)?;
-
-                if let Some(root) = document.get_root_element() {
-                    let mut portrayal_catalog = PortrayalCatalog {
-                        alert_catalog: None,
-                        area_fills: Vec::new(),
-                        color_profiles: Vec::new(),
-                        context: Vec::new(),
-                        display_planes: Vec::new(),
-                        display_modes: Vec::new(),
-                        fonts: Vec::new(),
-                        foundation_mode: Vec::new(),
-                        line_styles: Vec::new(),
-                        pixmaps: Vec::new(),
-                        rules: Vec::new(),
-                        style_sheets: Vec::new(),
-                        symbols: Vec::new(),
-                        viewing_groups: Vec::new(),
-                        viewing_group_layers: Vec::new(),
-                    };
-
-                    if root.get_name() == PORTRAYAL_CATALOG {
-                        for node in root.get_child_elements() {
-                            match node.get_name().as_str() {
-                                ALERT_CATALOG => match AlertCatalog::parse(node) {
-                                    Ok(alert_catalog) => {
-                                        portrayal_catalog.alert_catalog = Some(alert_catalog)
-                                    }
-                                    Err(e) => return Err(e),
-                                },
-                                AREA_FILLS => {
-                                    let area_fills_node = node.get_child_elements();
-                                    for area_fill_node in area_fills_node {
-                                        match AreaFill::parse(area_fill_node) {
-                                            Ok(area_fill) => {
-                                                portrayal_catalog.area_fills.push(area_fill)
-                                            }
-                                            Err(e) => return Err(e),
-                                        }
-                                    }
-                                }
-                                COLOR_PROFILES => {
-                                    let color_profiles_node = node.get_child_elements();
-                                    for color_profile_node in color_profiles_node {
-                                        match ColorProfile::parse(color_profile_node) {
-                                            Ok(color_profile) => {
-                                                portrayal_catalog.color_profiles.push(color_profile)
-                                            }
-                                            Err(e) => return Err(e),
-                                        }
-                                    }
+        let document = parser.parse_string(xml)?;
+        PortrayalCatalog::from_document(&document, &mut Diagnostics::new(ParseMode::Strict))
+    }
+
+    pub fn from_bytes_with_mode(
+        xml: &[u8],
+        mode: ParseMode,
+    ) -> Result<(PortrayalCatalog, Vec<S100Error>)> {
+        let parser = Parser::default();
+        let document = parser.parse_string(xml)?;
+        let mut diagnostics = Diagnostics::new(mode);
+        let catalog = PortrayalCatalog::from_document(&document, &mut diagnostics)?;
+        Ok((catalog, diagnostics.into_warnings()))
+    }
+
+    pub fn open_validated<P: AsRef<Path>>(path: P, schema: &Schema) -> Result<PortrayalCatalog> {
+        let xml = fs::read(&path)?;
+        let parser = Parser::default();
+        let document = parser.parse_string(&xml)?;
+        schema.check(&document)?;
+        PortrayalCatalog::from_document(&document, &mut Diagnostics::new(ParseMode::Strict))
+            .map_err(|e| e.with_path(path.as_ref()))
+    }
+
+    pub fn open_with_mode<P: AsRef<Path>>(
+        path: P,
+        mode: ParseMode,
+    ) -> Result<(PortrayalCatalog, Vec<S100Error>)> {
+        let xml = fs::read(&path)?;
+        let (catalog, warnings) = PortrayalCatalog::from_bytes_with_mode(&xml, mode)
+            .map_err(|e| e.with_path(path.as_ref()))?;
+        let warnings = warnings
+            .into_iter()
+            .map(|val| val.with_path(path.as_ref()))
+            .collect();
+        Ok((catalog, warnings))
+    }
+
+    fn from_document(
+        document: &Document,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<PortrayalCatalog> {
+        if let Some(root) = document.get_root_element() {
+            let mut portrayal_catalog = PortrayalCatalog {
+                alert_catalog: None,
+                area_fills: Vec::new(),
+                color_profiles: Vec::new(),
+                context: Vec::new(),
+                display_planes: Vec::new(),
+                display_modes: Vec::new(),
+                fonts: Vec::new(),
+                foundation_mode: Vec::new(),
+                line_styles: Vec::new(),
+                pixmaps: Vec::new(),
+                rules: Vec::new(),
+                style_sheets: Vec::new(),
+                symbols: Vec::new(),
+                viewing_groups: Vec::new(),
+                viewing_group_layers: Vec::new(),
+            };
+
+            if root.get_name() == PORTRAYAL_CATALOG {
+                check_namespace(&root, Namespace::PortrayalCatalogue)?;
+
+                for node in root.get_child_elements() {
+                    match element_name(&node, PC_NAMESPACES).as_str() {
+                        ALERT_CATALOG => match AlertCatalog::parse(node) {
+                            Ok(alert_catalog) => {
+                                portrayal_catalog.alert_catalog = Some(alert_catalog)
+                            }
+                            Err(e) => diagnostics.report(e)?,
+                        },
+                        AREA_FILLS => {
+                            let area_fills_node = node.get_child_elements();
+                            for area_fill_node in area_fills_node {
+                                match AreaFill::parse(area_fill_node) {
+                                    Ok(area_fill) => portrayal_catalog.area_fills.push(area_fill),
+                                    Err(e) => diagnostics.report(e)?,
                                 }
-                                CONTEXT => {
-                                    let parameters_node = node.get_child_elements();
-                                    for parameter_node in parameters_node {
-                                        match Parameter::parse(parameter_node) {
-                                            Ok(parameter) => {
-                                                portrayal_catalog.context.push(parameter)
-                                            }
-                                            Err(e) => return Err(e),
-                                        }
+                            }
+                        }
+                        COLOR_PROFILES => {
+                            let color_profiles_node = node.get_child_elements();
+                            for color_profile_node in color_profiles_node {
+                                match ColorProfile::parse(color_profile_node) {
+                                    Ok(color_profile) => {
+                                        portrayal_catalog.color_profiles.push(color_profile)
                                     }
+                                    Err(e) => diagnostics.report(e)?,
                                 }
-                                DISPLAY_MODES => {
-                                    let display_modes_node = node.get_child_elements();
-                                    for display_mode_node in display_modes_node {
-                                        match DisplayMode::parse(display_mode_node) {
-                                            Ok(display_mode) => {
-                                                portrayal_catalog.display_modes.push(display_mode)
-                                            }
-                                            Err(e) => return Err(e),
-                                        }
-                                    }
+                            }
+                        }
+                        CONTEXT => {
+                            let parameters_node = node.get_child_elements();
+                            for parameter_node in parameters_node {
+                                match Parameter::parse(parameter_node) {
+                                    Ok(parameter) => portrayal_catalog.context.push(parameter),
+                                    Err(e) => diagnostics.report(e)?,
                                 }
-                                DISPLAY_PLANES => {
-                                    let display_planes_node = node.get_child_elements();
-                                    for display_plane_node in display_planes_node {
-                                        match DisplayPlane::parse(display_plane_node) {
-                                            Ok(display_plane) => {
-                                                portrayal_catalog.display_planes.push(display_plane)
-                                            }
-                                            Err(e) => return Err(e),
-                                        }
+                            }
+                        }
+                        DISPLAY_MODES => {
+                            let display_modes_node = node.get_child_elements();
+                            for display_mode_node in display_modes_node {
+                                match DisplayMode::parse(display_mode_node) {
+                                    Ok(display_mode) => {
+                                        portrayal_catalog.display_modes.push(display_mode)
                                     }
+                                    Err(e) => diagnostics.report(e)?,
                                 }
-                                FONTS => {
-                                    let fonts_node = node.get_child_elements();
-                                    for font_node in fonts_node {
-                                        match Font::parse(font_node) {
-                                            Ok(font) => portrayal_catalog.fonts.push(font),
-                                            Err(e) => return Err(e),
-                                        }
+                            }
+                        }
+                        DISPLAY_PLANES => {
+                            let display_planes_node = node.get_child_elements();
+                            for display_plane_node in display_planes_node {
+                                match DisplayPlane::parse(display_plane_node) {
+                                    Ok(display_plane) => {
+                                        portrayal_catalog.display_planes.push(display_plane)
                                     }
+                                    Err(e) => diagnostics.report(e)?,
                                 }
-                                FOUNDATION_MODE => {
-                                    let viewing_group_nodes = node.get_child_elements();
-                                    for viewing_group in viewing_group_nodes {
-                                        portrayal_catalog
-                                            .foundation_mode
-                                            .push(viewing_group.get_content())
-                                    }
+                            }
+                        }
+                        FONTS => {
+                            let fonts_node = node.get_child_elements();
+                            for font_node in fonts_node {
+                                match Font::parse(font_node) {
+                                    Ok(font) => portrayal_catalog.fonts.push(font),
+                                    Err(e) => diagnostics.report(e)?,
                                 }
-                                LINE_STYLES => {
-                                    let line_styles_node = node.get_child_elements();
-                                    for line_style_node in line_styles_node {
-                                        match LineStyle::parse(line_style_node) {
-                                            Ok(line_style) => {
-                                                portrayal_catalog.line_styles.push(line_style)
-                                            }
-                                            Err(e) => return Err(e),
-                                        }
+                            }
+                        }
+                        FOUNDATION_MODE => {
+                            let viewing_group_nodes = node.get_child_elements();
+                            for viewing_group in viewing_group_nodes {
+                                portrayal_catalog
+                                    .foundation_mode
+                                    .push(viewing_group.get_content())
+                            }
+                        }
+                        LINE_STYLES => {
+                            let line_styles_node = node.get_child_elements();
+                            for line_style_node in line_styles_node {
+                                match LineStyle::parse(line_style_node) {
+                                    Ok(line_style) => {
+                                        portrayal_catalog.line_styles.push(line_style)
                                     }
+                                    Err(e) => diagnostics.report(e)?,
                                 }
-                                PIXMAPS => {
-                                    let pixmaps_node = node.get_child_elements();
-                                    for pixmap_node in pixmaps_node {
-                                        match Pixmap::parse(pixmap_node) {
-                                            Ok(pixmap) => portrayal_catalog.pixmaps.push(pixmap),
-                                            Err(e) => return Err(e),
-                                        }
-                                    }
+                            }
+                        }
+                        PIXMAPS => {
+                            let pixmaps_node = node.get_child_elements();
+                            for pixmap_node in pixmaps_node {
+                                match Pixmap::parse(pixmap_node) {
+                                    Ok(pixmap) => portrayal_catalog.pixmaps.push(pixmap),
+                                    Err(e) => diagnostics.report(e)?,
                                 }
-                                STYLE_SHEETS => {
-                                    let style_sheets_node = node.get_child_elements();
-                                    for style_sheet_node in style_sheets_node {
-                                        match StyleSheet::parse(style_sheet_node) {
-                                            Ok(style_sheet) => {
-                                                portrayal_catalog.style_sheets.push(style_sheet)
-                                            }
-                                            Err(e) => return Err(e),
-                                        }
+                            }
+                        }
+                        STYLE_SHEETS => {
+                            let style_sheets_node = node.get_child_elements();
+                            for style_sheet_node in style_sheets_node {
+                                match StyleSheet::parse(style_sheet_node) {
+                                    Ok(style_sheet) => {
+                                        portrayal_catalog.style_sheets.push(style_sheet)
                                     }
+                                    Err(e) => diagnostics.report(e)?,
                                 }
-                                SYMBOLS => {
-                                    let symbols_node = node.get_child_elements();
-                                    for symbol_node in symbols_node {
-                                        match Symbol::parse(symbol_node) {
-                                            Ok(symbol) => portrayal_catalog.symbols.push(symbol),
-                                            Err(e) => return Err(e),
-                                        }
-                                    }
+                            }
+                        }
+                        SYMBOLS => {
+                            let symbols_node = node.get_child_elements();
+                            for symbol_node in symbols_node {
+                                match Symbol::parse(symbol_node) {
+                                    Ok(symbol) => portrayal_catalog.symbols.push(symbol),
+                                    Err(e) => diagnostics.report(e)?,
                                 }
-                                RULES => {
-                                    let rules_node = node.get_child_elements();
-                                    for rule_node in rules_node {
-                                        match RuleFile::parse(rule_node) {
-                                            Ok(rule) => portrayal_catalog.rules.push(rule),
-                                            Err(e) => return Err(e),
-                                        }
-                                    }
+                            }
+                        }
+                        RULES => {
+                            let rules_node = node.get_child_elements();
+                            for rule_node in rules_node {
+                                match RuleFile::parse(rule_node) {
+                                    Ok(rule) => portrayal_catalog.rules.push(rule),
+                                    Err(e) => diagnostics.report(e)?,
                                 }
-                                VIEWING_GROUPS => {
-                                    let viewing_groups_node = node.get_child_elements();
-                                    for viewing_group_node in viewing_groups_node {
-                                        match ViewingGroup::parse(viewing_group_node) {
-                                            Ok(viewing_group) => {
-                                                portrayal_catalog.viewing_groups.push(viewing_group)
-                                            }
-                                            Err(e) => return Err(e),
-                                        }
+                            }
+                        }
+                        VIEWING_GROUPS => {
+                            let viewing_groups_node = node.get_child_elements();
+                            for viewing_group_node in viewing_groups_node {
+                                match ViewingGroup::parse(viewing_group_node) {
+                                    Ok(viewing_group) => {
+                                        portrayal_catalog.viewing_groups.push(viewing_group)
                                     }
+                                    Err(e) => diagnostics.report(e)?,
                                 }
-                                VIEWING_GROUP_LAYERS => {
-                                    let viewing_group_layers_node = node.get_child_elements();
-                                    for viewing_group_layer_node in viewing_group_layers_node {
-                                        match ViewingGroupLayer::parse(viewing_group_layer_node) {
-                                            Ok(viewing_group_layer) => portrayal_catalog
-                                                .viewing_group_layers
-                                                .push(viewing_group_layer),
-                                            Err(e) => return Err(e),
-                                        }
-                                    }
+                            }
+                        }
+                        VIEWING_GROUP_LAYERS => {
+                            let viewing_group_layers_node = node.get_child_elements();
+                            for viewing_group_layer_node in viewing_group_layers_node {
+                                match ViewingGroupLayer::parse(viewing_group_layer_node) {
+                                    Ok(viewing_group_layer) => portrayal_catalog
+                                        .viewing_group_layers
+                                        .push(viewing_group_layer),
+                                    Err(e) => diagnostics.report(e)?,
                                 }
-                                _ => return S100Error::invalid_child(node),
                             }
                         }
-
-                        Ok(portrayal_catalog)
-                    } else {
-                        Err(S100Error::Parse(format!(
-                            "Root node is not {}, found '{} instead",
-                            PORTRAYAL_CATALOG,
-                            root.get_name(),
-                        )))
+                        _ => S100Error::invalid_child(node).or_else(|e| diagnostics.report(e))?,
                     }
-                } else {
-                    Err(S100Error::Parse("Root node does not exist".to_string()))
                 }
+
+                Ok(portrayal_catalog)
+            } else {
+                S100Error::invalid_child(root)
             }
-            None => Err(S100Error::Parse("path is empty".to_string())),
+        } else {
+            S100Error::missing_root(PORTRAYAL_CATALOG)
         }
     }
 
@@ -281,6 +324,27 @@ impl PortrayalCatalog {
         &self.line_styles
     }
 
+    pub fn line_style(&self, id: &str) -> Option<&LineStyle> {
+        self.line_styles.iter().find(|val| val.id() == id)
+    }
+
+    // Reads the definition of every line style from the catalogue directory, usually the
+    // directory containing the portrayal catalogue file. The definitions are only attached once
+    // all of them have been read, so a failure leaves the catalogue unchanged.
+    pub fn load_line_styles<P: AsRef<Path>>(&mut self, catalog_directory: P) -> Result<()> {
+        let definitions = self
+            .line_styles
+            .iter()
+            .map(|val| val.load_definition(catalog_directory.as_ref()))
+            .collect::<Result<Vec<LineStyleDefinition>>>()?;
+
+        for (line_style, definition) in self.line_styles.iter_mut().zip(definitions) {
+            line_style.set_definition(definition);
+        }
+
+        Ok(())
+    }
+
     pub fn pixmaps(&self) -> &[Pixmap] {
         &self.pixmaps
     }
@@ -305,3 +369,11 @@ impl PortrayalCatalog {
         &self.viewing_group_layers
     }
 }
+
+impl FromStr for PortrayalCatalog {
+    type Err = S100Error;
+
+    fn from_str(input: &str) -> Result<PortrayalCatalog> {
+        PortrayalCatalog::from_bytes(input.as_bytes())
+    }
+}
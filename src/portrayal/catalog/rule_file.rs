@@ -2,9 +2,10 @@ use libxml::tree::Node;
 use std::str::FromStr;
 
 use super::{
-    CatalogItem, Description, ExternalFile, FileFormat, FileType, RuleType, DESCRIPTION, XML_ID,
+    CatalogItem, Description, ExternalFile, FileFormat, FileType, RuleType, DESCRIPTION,
+    PC_NAMESPACES, XML_ID,
 };
-use crate::{Result, S100Error};
+use crate::{namespace::element_name, Result, S100Error};
 
 const RULE_FILE: &str = "ruleFile";
 const FILE_NAME: &str = "fileName";
@@ -24,7 +25,7 @@ pub struct RuleFile {
 
 impl RuleFile {
     pub(super) fn parse(node: Node) -> Result<RuleFile> {
-        if node.get_name() != RULE_FILE {
+        if element_name(&node, PC_NAMESPACES) != RULE_FILE {
             return S100Error::invalid_child(node);
         }
 
@@ -36,7 +37,7 @@ impl RuleFile {
         let mut rule_type: Option<RuleType> = None;
 
         for child_node in node.get_child_elements() {
-            match child_node.get_name().as_str() {
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
                 DESCRIPTION => match Description::parse(child_node) {
                     Ok(desc) => descriptions.push(desc),
                     Err(e) => return Err(e),
@@ -46,15 +47,15 @@ impl RuleFile {
                 }
                 FILE_TYPE => match FileType::from_str(child_node.get_content().as_str()) {
                     Ok(val) => file_type = Some(val),
-                    Err(_) => return S100Error::invalid_value(child_node),
+                    Err(e) => return Err(e.at(&child_node)),
                 },
                 FILE_FORMAT => match FileFormat::from_str(child_node.get_content().as_str()) {
                     Ok(val) => file_format = Some(val),
-                    Err(_) => return S100Error::invalid_value(child_node),
+                    Err(e) => return Err(e.at(&child_node)),
                 },
                 RULE_TYPE => match RuleType::from_str(child_node.get_content().as_str()) {
                     Ok(val) => rule_type = Some(val),
-                    Err(_) => return S100Error::invalid_value(child_node),
+                    Err(e) => return Err(e.at(&child_node)),
                 },
                 _ => return S100Error::invalid_child(child_node),
             };
@@ -0,0 +1,317 @@
+use libxml::tree::Node;
+use std::str::FromStr;
+
+use super::{CapStyle, JoinStyle, PC_NAMESPACES};
+use crate::{
+    namespace::element_name,
+    portrayal::{Color, CrsType},
+    Result, S100Error,
+};
+
+const LINE_STYLE: &str = "lineStyle";
+const CAP_STYLE: &str = "capStyle";
+const JOIN_STYLE: &str = "joinStyle";
+const OFFSET: &str = "offset";
+const INTERVAL_LENGTH: &str = "intervalLength";
+const PEN: &str = "pen";
+const WIDTH: &str = "width";
+const COLOR: &str = "color";
+const TRANSPARENCY: &str = "transparency";
+const DASH: &str = "dash";
+const START: &str = "start";
+const LENGTH: &str = "length";
+const SYMBOL: &str = "symbol";
+const REFERENCE: &str = "reference";
+const ROTATION: &str = "rotation";
+const SCALE_FACTOR: &str = "scaleFactor";
+const CRS_TYPE: &str = "crsType";
+const POSITION: &str = "position";
+
+// Parses an optional numeric attribute, failing when it is present but not a number.
+fn parse_attribute(node: &Node, name: &str) -> Result<Option<f64>> {
+    match node.get_attribute(name) {
+        Some(value) => match value.trim().parse() {
+            Ok(val) => Ok(Some(val)),
+            Err(_) => S100Error::invalid_attribute_value(node.clone(), &value),
+        },
+        None => Ok(None),
+    }
+}
+
+fn parse_number(node: Node) -> Result<f64> {
+    match node.get_content().trim().parse() {
+        Ok(val) => Ok(val),
+        Err(_) => S100Error::invalid_value(node),
+    }
+}
+
+// A line drawn with a single pen, optionally dashed and decorated with symbols. Lengths and
+// widths are in millimetres; dashes and symbols repeat every interval length.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimpleLineStyle {
+    cap_style: Option<CapStyle>,
+    join_style: Option<JoinStyle>,
+    offset: Option<f64>,
+    interval_length: Option<f64>,
+    pen: Pen,
+    dashes: Vec<Dash>,
+    symbols: Vec<LineSymbol>,
+}
+
+impl SimpleLineStyle {
+    pub(super) fn parse(node: Node) -> Result<SimpleLineStyle> {
+        if element_name(&node, PC_NAMESPACES) != LINE_STYLE {
+            return S100Error::invalid_child(node);
+        }
+
+        let cap_style = match node.get_attribute(CAP_STYLE) {
+            Some(value) => match CapStyle::from_str(value.trim()) {
+                Ok(val) => Some(val),
+                Err(e) => return Err(e.at(&node)),
+            },
+            None => None,
+        };
+        let join_style = match node.get_attribute(JOIN_STYLE) {
+            Some(value) => match JoinStyle::from_str(value.trim()) {
+                Ok(val) => Some(val),
+                Err(e) => return Err(e.at(&node)),
+            },
+            None => None,
+        };
+        let offset = parse_attribute(&node, OFFSET)?;
+        let mut interval_length: Option<f64> = None;
+        let mut pen: Option<Pen> = None;
+        let mut dashes: Vec<Dash> = Vec::new();
+        let mut symbols: Vec<LineSymbol> = Vec::new();
+
+        for child_node in node.get_child_elements() {
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
+                INTERVAL_LENGTH => match parse_number(child_node) {
+                    Ok(val) => interval_length = Some(val),
+                    Err(e) => return Err(e),
+                },
+                PEN => match Pen::parse(child_node) {
+                    Ok(val) => pen = Some(val),
+                    Err(e) => return Err(e),
+                },
+                DASH => match Dash::parse(child_node) {
+                    Ok(val) => dashes.push(val),
+                    Err(e) => return Err(e),
+                },
+                SYMBOL => match LineSymbol::parse(child_node) {
+                    Ok(val) => symbols.push(val),
+                    Err(e) => return Err(e),
+                },
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        if pen.is_none() {
+            return S100Error::missing_child(node, PEN);
+        }
+
+        Ok(SimpleLineStyle {
+            cap_style,
+            join_style,
+            offset,
+            interval_length,
+            pen: pen.unwrap(),
+            dashes,
+            symbols,
+        })
+    }
+
+    pub fn cap_style(&self) -> Option<CapStyle> {
+        self.cap_style
+    }
+
+    pub fn join_style(&self) -> Option<JoinStyle> {
+        self.join_style
+    }
+
+    pub fn offset(&self) -> Option<f64> {
+        self.offset
+    }
+
+    pub fn interval_length(&self) -> Option<f64> {
+        self.interval_length
+    }
+
+    pub fn pen(&self) -> &Pen {
+        &self.pen
+    }
+
+    pub fn dashes(&self) -> &[Dash] {
+        &self.dashes
+    }
+
+    pub fn symbols(&self) -> &[LineSymbol] {
+        &self.symbols
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pen {
+    width: f64,
+    color: Color,
+}
+
+impl Pen {
+    fn parse(node: Node) -> Result<Pen> {
+        let width = parse_attribute(&node, WIDTH)?;
+        let mut color: Option<Color> = None;
+
+        for child_node in node.get_child_elements() {
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
+                COLOR => {
+                    let transparency = parse_attribute(&child_node, TRANSPARENCY)?;
+                    color = Some(Color::new(
+                        child_node.get_content().trim(),
+                        transparency.unwrap_or(0.0),
+                    ));
+                }
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        if width.is_none() {
+            return S100Error::missing_attribute(node, WIDTH);
+        }
+        if color.is_none() {
+            return S100Error::missing_child(node, COLOR);
+        }
+
+        Ok(Pen {
+            width: width.unwrap(),
+            color: color.unwrap(),
+        })
+    }
+
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    pub fn color(&self) -> &Color {
+        &self.color
+    }
+}
+
+// A visible segment of each interval; the line is not drawn outside of the dashes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Dash {
+    start: f64,
+    length: f64,
+}
+
+impl Dash {
+    fn parse(node: Node) -> Result<Dash> {
+        let mut start: Option<f64> = None;
+        let mut length: Option<f64> = None;
+
+        for child_node in node.get_child_elements() {
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
+                START => match parse_number(child_node) {
+                    Ok(val) => start = Some(val),
+                    Err(e) => return Err(e),
+                },
+                LENGTH => match parse_number(child_node) {
+                    Ok(val) => length = Some(val),
+                    Err(e) => return Err(e),
+                },
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        if start.is_none() {
+            return S100Error::missing_child(node, START);
+        }
+        if length.is_none() {
+            return S100Error::missing_child(node, LENGTH);
+        }
+
+        Ok(Dash {
+            start: start.unwrap(),
+            length: length.unwrap(),
+        })
+    }
+
+    pub fn start(&self) -> f64 {
+        self.start
+    }
+
+    pub fn length(&self) -> f64 {
+        self.length
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineSymbol {
+    reference: String,
+    rotation: f64,
+    scale_factor: f64,
+    crs_type: CrsType,
+    position: f64,
+}
+
+impl LineSymbol {
+    fn parse(node: Node) -> Result<LineSymbol> {
+        let reference: Option<String> = node.get_attribute(REFERENCE);
+        let rotation = parse_attribute(&node, ROTATION)?;
+        let scale_factor = parse_attribute(&node, SCALE_FACTOR)?;
+        let crs_type = match node.get_attribute(CRS_TYPE) {
+            Some(value) => match CrsType::from_str(value.trim()) {
+                Ok(val) => val,
+                Err(e) => return Err(e.at(&node)),
+            },
+            None => CrsType::Line,
+        };
+        let mut position: Option<f64> = None;
+
+        for child_node in node.get_child_elements() {
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
+                POSITION => match parse_number(child_node) {
+                    Ok(val) => position = Some(val),
+                    Err(e) => return Err(e),
+                },
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        if reference.is_none() {
+            return S100Error::missing_attribute(node, REFERENCE);
+        }
+        if position.is_none() {
+            return S100Error::missing_child(node, POSITION);
+        }
+
+        Ok(LineSymbol {
+            reference: reference.unwrap(),
+            rotation: rotation.unwrap_or(0.0),
+            scale_factor: scale_factor.unwrap_or(1.0),
+            crs_type,
+            position: position.unwrap(),
+        })
+    }
+
+    // The id of a symbol in the portrayal catalogue.
+    pub fn reference(&self) -> &str {
+        self.reference.as_str()
+    }
+
+    pub fn rotation(&self) -> f64 {
+        self.rotation
+    }
+
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    pub fn crs_type(&self) -> CrsType {
+        self.crs_type
+    }
+
+    // The distance of the symbol from the start of each interval.
+    pub fn position(&self) -> f64 {
+        self.position
+    }
+}
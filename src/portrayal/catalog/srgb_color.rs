@@ -0,0 +1,75 @@
+use libxml::tree::Node;
+
+use super::PC_NAMESPACES;
+use crate::{namespace::element_name, portrayal::Rgba, Result, S100Error};
+
+const SRGB: &str = "srgb";
+const RED: &str = "red";
+const GREEN: &str = "green";
+const BLUE: &str = "blue";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SrgbColor {
+    red: u8,
+    green: u8,
+    blue: u8,
+}
+
+impl SrgbColor {
+    pub(super) fn parse(node: Node) -> Result<SrgbColor> {
+        if element_name(&node, PC_NAMESPACES) != SRGB {
+            return S100Error::invalid_child(node);
+        }
+
+        let mut red: Option<u8> = None;
+        let mut green: Option<u8> = None;
+        let mut blue: Option<u8> = None;
+
+        for child_node in node.get_child_elements() {
+            let value = match child_node.get_content().trim().parse() {
+                Ok(val) => val,
+                Err(_) => return S100Error::invalid_value(child_node),
+            };
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
+                RED => red = Some(value),
+                GREEN => green = Some(value),
+                BLUE => blue = Some(value),
+                _ => return S100Error::invalid_child(child_node),
+            };
+        }
+
+        if red.is_none() {
+            return S100Error::missing_child(node, RED);
+        }
+        if green.is_none() {
+            return S100Error::missing_child(node, GREEN);
+        }
+        if blue.is_none() {
+            return S100Error::missing_child(node, BLUE);
+        }
+
+        Ok(SrgbColor {
+            red: red.unwrap(),
+            green: green.unwrap(),
+            blue: blue.unwrap(),
+        })
+    }
+
+    pub fn red(&self) -> u8 {
+        self.red
+    }
+
+    pub fn green(&self) -> u8 {
+        self.green
+    }
+
+    pub fn blue(&self) -> u8 {
+        self.blue
+    }
+
+    // Transparency runs from 0 (opaque) to 1 (fully transparent) as in portrayal instructions.
+    pub fn to_rgba(&self, transparency: f64) -> Rgba {
+        let alpha = ((1.0 - transparency.clamp(0.0, 1.0)) * 255.0).round() as u8;
+        Rgba::new(self.red, self.green, self.blue, alpha)
+    }
+}
@@ -1,8 +1,8 @@
 use libxml::tree::Node;
 use std::str::FromStr;
 
-use super::{CatalogItem, Description, ExternalFile, FileFormat, FileType, XML_ID};
-use crate::{Result, S100Error};
+use super::{CatalogItem, Description, ExternalFile, FileFormat, FileType, PC_NAMESPACES, XML_ID};
+use crate::{namespace::element_name, Result, S100Error};
 
 const STYLE_SHEET: &str = "styleSheet";
 const DESCRIPTION: &str = "description";
@@ -21,7 +21,7 @@ pub struct StyleSheet {
 
 impl StyleSheet {
     pub(super) fn parse(node: Node) -> Result<StyleSheet> {
-        if node.get_name() != STYLE_SHEET {
+        if element_name(&node, PC_NAMESPACES) != STYLE_SHEET {
             return S100Error::invalid_child(node);
         }
 
@@ -32,7 +32,7 @@ impl StyleSheet {
         let mut file_format: Option<FileFormat> = None;
 
         for child_node in node.get_child_elements() {
-            match child_node.get_name().as_str() {
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
                 DESCRIPTION => match Description::parse(child_node) {
                     Ok(desc) => descriptions.push(desc),
                     Err(e) => return Err(e),
@@ -42,11 +42,11 @@ impl StyleSheet {
                 }
                 FILE_TYPE => match FileType::from_str(child_node.get_content().as_str()) {
                     Ok(val) => file_type = Some(val),
-                    Err(_) => return S100Error::invalid_value(child_node),
+                    Err(e) => return Err(e.at(&child_node)),
                 },
                 FILE_FORMAT => match FileFormat::from_str(child_node.get_content().as_str()) {
                     Ok(val) => file_format = Some(val),
-                    Err(_) => return S100Error::invalid_value(child_node),
+                    Err(e) => return Err(e.at(&child_node)),
                 },
                 _ => return S100Error::invalid_child(child_node),
             };
@@ -1,7 +1,7 @@
 use libxml::tree::Node;
 
-use super::{ErrorMessage, ERROR_MESSAGE, VALIDATE};
-use crate::{Result, S100Error};
+use super::{ErrorMessage, ERROR_MESSAGE, PC_NAMESPACES, VALIDATE};
+use crate::{namespace::element_name, Result, S100Error};
 
 const REGEX: &str = "regex";
 const XPATH: &str = "xpath";
@@ -15,7 +15,7 @@ pub struct Validate {
 
 impl Validate {
     pub(super) fn parse(node: Node) -> Result<Validate> {
-        if node.get_name() != VALIDATE {
+        if element_name(&node, PC_NAMESPACES) != VALIDATE {
             return S100Error::invalid_child(node);
         }
 
@@ -24,7 +24,7 @@ impl Validate {
         let mut error_message: Option<ErrorMessage> = None;
 
         for child_node in node.get_child_elements() {
-            match child_node.get_name().as_str() {
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
                 REGEX => {
                     regex = Some(child_node.get_content());
                 }
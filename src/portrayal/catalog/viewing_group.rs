@@ -1,7 +1,7 @@
 use libxml::tree::Node;
 
-use super::{CatalogItem, Description, DESCRIPTION, VIEWING_GROUP, XML_ID};
-use crate::{Result, S100Error};
+use super::{CatalogItem, Description, DESCRIPTION, PC_NAMESPACES, VIEWING_GROUP, XML_ID};
+use crate::{namespace::element_name, Result, S100Error};
 #[derive(Clone, Debug)]
 pub struct ViewingGroup {
     id: String,
@@ -10,7 +10,7 @@ pub struct ViewingGroup {
 
 impl ViewingGroup {
     pub(super) fn parse(node: Node) -> Result<ViewingGroup> {
-        if node.get_name() != VIEWING_GROUP {
+        if element_name(&node, PC_NAMESPACES) != VIEWING_GROUP {
             return S100Error::invalid_child(node);
         }
 
@@ -18,7 +18,7 @@ impl ViewingGroup {
         let mut descriptions: Vec<Description> = Vec::new();
 
         for child_node in node.get_child_elements() {
-            match child_node.get_name().as_str() {
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
                 DESCRIPTION => match Description::parse(child_node) {
                     Ok(desc) => descriptions.push(desc),
                     Err(e) => return Err(e),
@@ -1,7 +1,7 @@
 use libxml::tree::Node;
 
-use super::{CatalogItem, Description, DESCRIPTION, VIEWING_GROUP, XML_ID};
-use crate::{Result, S100Error};
+use super::{CatalogItem, Description, DESCRIPTION, PC_NAMESPACES, VIEWING_GROUP, XML_ID};
+use crate::{namespace::element_name, Result, S100Error};
 
 const VIEWING_GROUP_LAYER: &str = "viewingGroupLayer";
 
@@ -14,7 +14,7 @@ pub struct ViewingGroupLayer {
 
 impl ViewingGroupLayer {
     pub(super) fn parse(node: Node) -> Result<ViewingGroupLayer> {
-        if node.get_name() != VIEWING_GROUP_LAYER {
+        if element_name(&node, PC_NAMESPACES) != VIEWING_GROUP_LAYER {
             return S100Error::invalid_child(node);
         }
 
@@ -23,7 +23,7 @@ impl ViewingGroupLayer {
         let mut viewing_groups: Vec<String> = Vec::new();
 
         for child_node in node.get_child_elements() {
-            match child_node.get_name().as_str() {
+            match element_name(&child_node, PC_NAMESPACES).as_str() {
                 DESCRIPTION => match Description::parse(child_node) {
                     Ok(desc) => descriptions.push(desc),
                     Err(e) => return Err(e),
@@ -5,6 +5,13 @@ pub struct Color {
 }
 
 impl Color {
+    pub fn new(token: &str, transparency: f64) -> Color {
+        Color {
+            token: token.to_string(),
+            transparency,
+        }
+    }
+
     pub fn token(&self) -> &str {
         self.token.as_str()
     }
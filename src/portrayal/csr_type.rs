@@ -1,3 +1,7 @@
+use std::str::FromStr;
+
+use crate::S100Error;
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum CrsType {
     Geographic,
@@ -5,3 +9,17 @@ pub enum CrsType {
     Local,
     Line,
 }
+
+impl FromStr for CrsType {
+    type Err = S100Error;
+
+    fn from_str(input: &str) -> Result<CrsType, Self::Err> {
+        match input {
+            "GeographicCRS" => Ok(CrsType::Geographic),
+            "PortrayalCRS" => Ok(CrsType::Portrayal),
+            "LocalCRS" => Ok(CrsType::Local),
+            "LineCRS" => Ok(CrsType::Line),
+            _ => S100Error::invalid_enum("crsType", input),
+        }
+    }
+}
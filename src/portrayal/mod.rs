@@ -13,5 +13,8 @@ pub use csr_type::CrsType;
 mod point;
 pub use point::Point;
 
+mod rgba;
+pub use rgba::Rgba;
+
 mod vector;
 pub use vector::Vector;
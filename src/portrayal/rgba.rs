@@ -0,0 +1,42 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Rgba {
+    red: u8,
+    green: u8,
+    blue: u8,
+    alpha: u8,
+}
+
+impl Rgba {
+    pub fn new(red: u8, green: u8, blue: u8, alpha: u8) -> Rgba {
+        Rgba {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    pub fn red(&self) -> u8 {
+        self.red
+    }
+
+    pub fn green(&self) -> u8 {
+        self.green
+    }
+
+    pub fn blue(&self) -> u8 {
+        self.blue
+    }
+
+    pub fn alpha(&self) -> u8 {
+        self.alpha
+    }
+
+    // Formats the colour as #rrggbbaa.
+    pub fn to_hex(&self) -> String {
+        format!(
+            "#{:02x}{:02x}{:02x}{:02x}",
+            self.red, self.green, self.blue, self.alpha
+        )
+    }
+}
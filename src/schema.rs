@@ -0,0 +1,197 @@
+use libxml::{
+    error::StructuredError,
+    parser::Parser,
+    schemas::{SchemaParserContext, SchemaValidationContext},
+    tree::Document,
+};
+use std::{
+    cell::RefCell,
+    fmt,
+    io::{Error as IoError, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use crate::{Result, S100Error};
+
+const FEATURE_CATALOGUE_SCHEMA: &str = "S100FC.xsd";
+const PORTRAYAL_CATALOGUE_SCHEMA: &str = "S100PC.xsd";
+const EXCHANGE_CATALOGUE_SCHEMA: &str = "S100XC.xsd";
+
+// The schema is parsed once when it is loaded, so a broken schema is reported straight away.
+pub struct Schema {
+    path: PathBuf,
+    // libxml needs exclusive access to the context while validating.
+    context: RefCell<SchemaValidationContext>,
+}
+
+impl Schema {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Schema> {
+        let path = path.as_ref().to_path_buf();
+        // libxml only accepts UTF-8 file names. Parsing the schema from memory instead would
+        // resolve its relative xs:include and xs:import locations against the wrong directory.
+        let mut parser = match path.to_str() {
+            Some(val) => SchemaParserContext::from_file(val),
+            None => {
+                return Err(S100Error::Io(IoError::new(
+                    ErrorKind::InvalidInput,
+                    format!("the schema path '{}' is not valid UTF-8", path.display()),
+                )))
+            }
+        };
+
+        match SchemaValidationContext::from_parser(&mut parser) {
+            Ok(context) => Ok(Schema {
+                path,
+                context: RefCell::new(context),
+            }),
+            Err(errors) => Err(S100Error::SchemaLoad {
+                path,
+                violations: errors.iter().map(SchemaViolation::from).collect(),
+            }),
+        }
+    }
+
+    pub fn feature_catalogue<P: AsRef<Path>>(directory: P) -> Result<Schema> {
+        Schema::from_file(directory.as_ref().join(FEATURE_CATALOGUE_SCHEMA))
+    }
+
+    pub fn portrayal_catalogue<P: AsRef<Path>>(directory: P) -> Result<Schema> {
+        Schema::from_file(directory.as_ref().join(PORTRAYAL_CATALOGUE_SCHEMA))
+    }
+
+    pub fn exchange_catalogue<P: AsRef<Path>>(directory: P) -> Result<Schema> {
+        Schema::from_file(directory.as_ref().join(EXCHANGE_CATALOGUE_SCHEMA))
+    }
+
+    pub fn path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    pub fn validate(&self, xml: &[u8]) -> Result<Vec<SchemaViolation>> {
+        let parser = Parser::default();
+        let document = parser.parse_string(xml)?;
+        Ok(self.validate_document(&document))
+    }
+
+    // Fails with every violation found rather than only the first one.
+    pub(crate) fn check(&self, document: &Document) -> Result<()> {
+        let violations = self.validate_document(document);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(S100Error::Schema(violations))
+        }
+    }
+
+    fn validate_document(&self, document: &Document) -> Vec<SchemaViolation> {
+        match self.context.borrow_mut().validate_document(document) {
+            Ok(_) => Vec::new(),
+            Err(errors) => errors.iter().map(SchemaViolation::from).collect(),
+        }
+    }
+}
+
+impl fmt::Debug for Schema {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Schema").field("path", &self.path).finish()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaViolation {
+    message: String,
+    line: Option<u32>,
+    column: Option<u32>,
+}
+
+impl SchemaViolation {
+    pub fn message(&self) -> &str {
+        self.message.as_str()
+    }
+
+    pub fn line(&self) -> Option<u32> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<u32> {
+        self.column
+    }
+}
+
+impl From<&StructuredError> for SchemaViolation {
+    fn from(error: &StructuredError) -> SchemaViolation {
+        SchemaViolation {
+            message: match error.message.as_ref() {
+                Some(val) => val.trim().to_string(),
+                None => "unknown schema error".to_string(),
+            },
+            line: error.line.and_then(|val| u32::try_from(val).ok()),
+            column: error.col.and_then(|val| u32::try_from(val).ok()),
+        }
+    }
+}
+
+impl fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}:{}: {}", line, column, self.message),
+            (Some(line), None) => write!(f, "line {}: {}", line, self.message),
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::tempdir;
+
+    use super::Schema;
+    use crate::S100Error;
+
+    #[test]
+    fn validate() {
+        let directory = tempdir().expect("Unable to create temp dir");
+        fs::write(
+            directory.path().join("S100XC.xsd"),
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema" targetNamespace="http://www.iho.int/s100/xc" elementFormDefault="qualified">
+    <xs:element name="S100_ExchangeCatalogue">
+        <xs:complexType>
+            <xs:sequence>
+                <xs:any minOccurs="0" maxOccurs="unbounded" processContents="skip"/>
+            </xs:sequence>
+        </xs:complexType>
+    </xs:element>
+</xs:schema>"#,
+        )
+        .expect("Unable to write schema");
+
+        let schema = Schema::exchange_catalogue(directory.path()).unwrap();
+        let violations = schema
+            .validate(
+                br#"<S100XC:S100_ExchangeCatalogue xmlns:S100XC="http://www.iho.int/s100/xc"/>"#,
+            )
+            .unwrap();
+        assert!(violations.is_empty());
+
+        assert!(matches!(
+            Schema::feature_catalogue(directory.path()),
+            Err(S100Error::SchemaLoad { .. })
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_path() {
+        use std::{ffi::OsStr, io::ErrorKind, os::unix::ffi::OsStrExt};
+
+        let directory = tempdir().expect("Unable to create temp dir");
+        let path = directory.path().join(OsStr::from_bytes(b"S100FC\xff.xsd"));
+        match Schema::from_file(path) {
+            Err(S100Error::Io(e)) => assert_eq!(e.kind(), ErrorKind::InvalidInput),
+            val => panic!("unexpected result: {:?}", val.map(|_| ())),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use std::env;
 
-use s100::feature::catalog::FeatureCatalog;
+use s100::feature::catalog::{FeatureCatalog, Item};
 
 #[test]
 #[allow(non_snake_case)]
@@ -15,6 +15,11 @@ fn read_S_101_FC_main() {
     match FeatureCatalog::open(path) {
         Ok(catalog) => {
             assert_eq!(220, catalog.simple_attributes().len());
+
+            let value_of_sounding = catalog.simple_attribute("valueOfSounding").unwrap();
+            assert_eq!(value_of_sounding.alias(), Some("VALSOU"));
+            let value_of_sounding = catalog.simple_attribute_by_alias("VALSOU").unwrap();
+            assert_eq!(value_of_sounding.code(), "valueOfSounding");
         }
         Err(err) => panic!("open returned an unexpected error: {}", err),
     }